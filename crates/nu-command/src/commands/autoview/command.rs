@@ -302,6 +302,7 @@ fn create_default_command_args(context: &RunnableContextWithoutInput) -> RawComm
                 external_redirection: ExternalRedirection::Stdout,
             },
             name_tag: context.name.clone(),
+            defaults: IndexMap::new(),
         },
         scope: Scope::new(),
     }
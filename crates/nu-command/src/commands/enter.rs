@@ -141,6 +141,7 @@ async fn enter(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                                     external_redirection: ExternalRedirection::Stdout,
                                 },
                                 name_tag: tag.clone(),
+                                defaults: IndexMap::new(),
                             },
                             scope: scope.clone(),
                         };
@@ -226,6 +226,7 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                                 external_redirection: ExternalRedirection::Stdout,
                             },
                             name_tag: name_tag.clone(),
+                            defaults: IndexMap::new(),
                         },
                         scope,
                     };
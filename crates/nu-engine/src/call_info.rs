@@ -1,23 +1,28 @@
 use crate::evaluate::evaluate_args::evaluate_args;
 use crate::evaluation_context::EvaluationContext;
+use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::hir;
-use nu_protocol::CallInfo;
+use nu_protocol::{CallInfo, Value};
 use nu_source::Tag;
 
 #[derive(Debug, Clone)]
 pub struct UnevaluatedCallInfo {
     pub args: hir::Call,
     pub name_tag: Tag,
+    /// The values of the named arguments that are filled in when they are not given
+    pub defaults: IndexMap<String, Value>,
 }
 
 impl UnevaluatedCallInfo {
     pub async fn evaluate(self, ctx: &EvaluationContext) -> Result<CallInfo, ShellError> {
-        let args = evaluate_args(&self.args, ctx).await?;
+        let mut args = evaluate_args(&self.args, ctx).await?;
+        args.insert_defaults(&self.defaults);
 
         Ok(CallInfo {
             args,
             name_tag: self.name_tag,
+            env: IndexMap::new(),
         })
     }
 
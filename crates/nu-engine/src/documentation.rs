@@ -204,22 +204,26 @@ pub fn get_documentation(
     }
 
     let palette = crate::shell::palette::DefaultPalette {};
+    let paint = |example: &str| {
+        if config.no_color {
+            example.to_string()
+        } else {
+            crate::shell::painter::Painter::paint_string(example, scope, &palette).to_string()
+        }
+    };
+
     let examples = cmd.examples();
-    if !examples.is_empty() {
+    if !examples.is_empty() || !signature.examples.is_empty() {
         long_desc.push_str("\nExamples:");
     }
     for example in examples {
         long_desc.push('\n');
         long_desc.push_str("  ");
         long_desc.push_str(example.description);
-
-        if config.no_color {
-            long_desc.push_str(&format!("\n  > {}\n", example.example));
-        } else {
-            let colored_example =
-                crate::shell::painter::Painter::paint_string(example.example, scope, &palette);
-            long_desc.push_str(&format!("\n  > {}\n", colored_example));
-        }
+        long_desc.push_str(&format!("\n  > {}\n", paint(example.example)));
+    }
+    for example in &signature.examples {
+        long_desc.push_str(&format!("\n  > {}\n", paint(example)));
     }
 
     long_desc.push('\n');
@@ -5,7 +5,8 @@ use crate::filesystem::filesystem_shell::FilesystemShell;
 use crate::shell::help_shell::HelpShell;
 use crate::shell::value_shell::ValueShell;
 use futures::StreamExt;
-use log::{log_enabled, trace};
+use indexmap::IndexMap;
+use log::{debug, log_enabled, trace};
 use nu_errors::ShellError;
 use nu_protocol::hir::{ExternalRedirection, InternalCommand};
 use nu_protocol::{CommandAction, Primitive, ReturnSuccess, UntaggedValue, Value};
@@ -86,6 +87,7 @@ pub(crate) async fn run_internal_command(
                                                 external_redirection: ExternalRedirection::Stdout,
                                             },
                                             name_tag: Tag::unknown_anchor(command.name_span),
+                                            defaults: IndexMap::new(),
                                         },
                                         scope: context.scope.clone(),
                                     };
@@ -246,6 +248,16 @@ pub(crate) async fn run_internal_command(
                             InputStream::one(UntaggedValue::string(value).into_untagged_value())
                         }
 
+                        Ok(ReturnSuccess::Warning(message)) => {
+                            context.with_host(|host| host.stderr(&format!("warning: {}", message)));
+                            InputStream::empty()
+                        }
+
+                        Ok(ReturnSuccess::DebugRecord(record)) => {
+                            debug!("{}", record.display());
+                            InputStream::empty()
+                        }
+
                         Err(err) => {
                             context.error(err);
                             InputStream::empty()
@@ -98,7 +98,11 @@ impl EvaluationContext {
     }
 
     fn call_info(&self, args: hir::Call, name_tag: Tag) -> UnevaluatedCallInfo {
-        UnevaluatedCallInfo { args, name_tag }
+        UnevaluatedCallInfo {
+            args,
+            name_tag,
+            defaults: IndexMap::new(),
+        }
     }
 
     fn command_args(&self, args: hir::Call, input: InputStream, name_tag: Tag) -> CommandArgs {
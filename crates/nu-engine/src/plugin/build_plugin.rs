@@ -59,7 +59,10 @@ pub fn build_plugin_command(
 
             let response = serde_json::from_str::<JsonRpc<Result<Signature, ShellError>>>(&input);
             match response {
-                Ok(jrpc) => match jrpc.params {
+                Ok(jrpc) => match jrpc.params.and_then(|params| {
+                    params.check_protocol_version()?;
+                    Ok(params)
+                }) {
                     Ok(params) => {
                         let fname = path.to_string_lossy();
 
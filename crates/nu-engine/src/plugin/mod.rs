@@ -1,2 +1,4 @@
 pub mod build_plugin;
 pub(crate) mod run_plugin;
+
+pub use run_plugin::PluginCommandBuilder;
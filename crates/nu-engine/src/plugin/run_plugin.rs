@@ -5,14 +5,16 @@ use derive_new::new;
 use futures::StreamExt;
 use log::trace;
 use nu_errors::ShellError;
-use nu_plugin::jsonrpc::JsonRpc;
+use nu_plugin::jsonrpc::{read_frame, write_binary_frame, JsonRpc};
+use nu_plugin::POOL_SIZE;
 use nu_protocol::{Primitive, ReturnValue, Signature, UntaggedValue, Value};
+use nu_source::Tag;
 use nu_stream::{OutputStream, ToOutputStream};
 use serde::{self, Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::io::prelude::*;
-use std::io::BufReader;
 use std::io::Write;
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 use std::process::{Child, Command, Stdio};
 
@@ -109,11 +111,26 @@ impl WholeStreamCommand for PluginFilter {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        run_filter(self.path.clone(), (args)).await
+        let binary_frames = self.config.protocol_version.is_some();
+        run_filter(
+            self.path.clone(),
+            binary_frames,
+            self.config.is_stateless,
+            args,
+        )
+        .await
     }
 }
 
-async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
+/// Plugins built on `nu_plugin` report a protocol version and understand binary frames,
+/// script plugins only ever receive JSON. Stateless plugins are sent values ahead of reading
+/// their responses.
+async fn run_filter(
+    path: String,
+    binary_frames: bool,
+    stateless: bool,
+    args: CommandArgs,
+) -> Result<OutputStream, ShellError> {
     trace!("filter_plugin :: {}", path);
 
     let bos = futures::stream::iter(vec![
@@ -154,212 +171,180 @@ async fn run_filter(path: String, args: CommandArgs) -> Result<OutputStream, She
             .expect("Failed to spawn child process")
     };
 
-    let call_info = args.call_info.clone();
+    let mut call_info = args.call_info.clone();
+    call_info.env = args.scope.get_env_vars();
 
     trace!("filtering :: {:?}", call_info);
 
+    // stateless plugins filter several values at once, so they are sent the values that are
+    // ready before reading the responses, which the plugin writes in the same order
+    let batch_size = if stateless { POOL_SIZE } else { 1 };
+
+    // one reader for the whole run, since it may buffer the start of the next response
+    let mut responses = BufReader::new(child.stdout.take().expect("Failed to open stdout"));
+
     Ok(bos
         .chain(args.input)
         .chain(eos)
-        .map(move |item| {
-            match item {
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::BeginningOfStream),
-                    ..
-                } => {
-                    // Beginning of the stream
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
-
-                    let mut reader = BufReader::new(stdout);
-
-                    let request = JsonRpc::new("begin_filter", call_info.clone());
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("begin_filter:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Err(_) => {
-                            return OutputStream::one(Err(ShellError::labeled_error(
-                                "Could not load json from plugin",
-                                "could not load json from plugin",
-                                &call_info.name_tag,
-                            )));
-                        }
-                        Ok(request_raw) => {
-                            match stdin.write(format!("{}\n", request_raw).as_bytes()) {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    return OutputStream::one(Err(ShellError::unexpected(
-                                        format!("{}", err),
-                                    )));
-                                }
-                            }
-                        }
+        .ready_chunks(batch_size)
+        .map(move |items| {
+            let mut outputs = vec![];
+            let mut pending = 0;
+
+            for item in items {
+                match item {
+                    Value {
+                        value: UntaggedValue::Primitive(Primitive::BeginningOfStream),
+                        ..
+                    } => {
+                        outputs.push(call(
+                            &mut child,
+                            &mut responses,
+                            "begin_filter",
+                            call_info.clone(),
+                            &call_info.name_tag,
+                        ));
                     }
-
-                    let mut input = String::new();
-                    match reader.read_line(&mut input) {
-                        Ok(_) => {
-                            let response = serde_json::from_str::<NuResult>(&input);
-                            trace!("begin_filter:response {:?}", &response);
-
-                            match response {
-                                Ok(NuResult::response { params }) => match params {
-                                    Ok(params) => futures::stream::iter(params).to_output_stream(),
-                                    Err(e) => futures::stream::iter(vec![ReturnValue::Err(e)])
-                                        .to_output_stream(),
-                                },
-
-                                Err(e) => OutputStream::one(Err(
-                                    ShellError::untagged_runtime_error(format!(
-                                        "Error while processing begin_filter response: {:?} {}",
-                                        e, input
-                                    )),
-                                )),
-                            }
-                        }
-                        Err(e) => OutputStream::one(Err(ShellError::untagged_runtime_error(
-                            format!("Error while reading begin_filter response: {:?}", e),
-                        ))),
+                    Value {
+                        value: UntaggedValue::Primitive(Primitive::EndOfStream),
+                        ..
+                    } => {
+                        outputs.extend(read_responses(&mut responses, "filter", pending));
+                        pending = 0;
+
+                        // post stream contents
+                        outputs.push(call(
+                            &mut child,
+                            &mut responses,
+                            "end_filter",
+                            Vec::<Value>::new(),
+                            &call_info.name_tag,
+                        ));
+
+                        // TODO: Handle error
+                        let _ = send_request(
+                            &mut child,
+                            "quit",
+                            Vec::<Value>::new(),
+                            &call_info.name_tag,
+                        );
+                        let _ = child.wait();
                     }
-                }
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::EndOfStream),
-                    ..
-                } => {
-                    // post stream contents
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
-
-                    let mut reader = BufReader::new(stdout);
-
-                    let request: JsonRpc<std::vec::Vec<Value>> = JsonRpc::new("end_filter", vec![]);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("end_filter:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Err(_) => {
-                            return OutputStream::one(Err(ShellError::labeled_error(
-                                "Could not load json from plugin",
-                                "could not load json from plugin",
-                                &call_info.name_tag,
-                            )));
-                        }
-                        Ok(request_raw) => {
-                            match stdin.write(format!("{}\n", request_raw).as_bytes()) {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    return OutputStream::one(Err(ShellError::unexpected(
-                                        format!("{}", err),
-                                    )));
-                                }
+                    value => {
+                        match send_value(&mut child, value, binary_frames, &call_info.name_tag) {
+                            Ok(()) => pending += 1,
+                            Err(e) => {
+                                outputs.extend(read_responses(&mut responses, "filter", pending));
+                                pending = 0;
+                                outputs.push(OutputStream::one(Err(e)));
                             }
                         }
                     }
+                }
+            }
 
-                    let mut input = String::new();
-                    let stream = match reader.read_line(&mut input) {
-                        Ok(_) => {
-                            let response = serde_json::from_str::<NuResult>(&input);
-                            trace!("end_filter:response {:?}", &response);
-
-                            match response {
-                                Ok(NuResult::response { params }) => match params {
-                                    Ok(params) => futures::stream::iter(params).to_output_stream(),
-                                    Err(e) => futures::stream::iter(vec![ReturnValue::Err(e)])
-                                        .to_output_stream(),
-                                },
-                                Err(e) => futures::stream::iter(vec![Err(
-                                    ShellError::untagged_runtime_error(format!(
-                                        "Error while processing end_filter response: {:?} {}",
-                                        e, input
-                                    )),
-                                )])
-                                .to_output_stream(),
-                            }
-                        }
-                        Err(e) => {
-                            futures::stream::iter(vec![Err(ShellError::untagged_runtime_error(
-                                format!("Error while reading end_filter response: {:?}", e),
-                            ))])
-                            .to_output_stream()
-                        }
-                    };
-
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-
-                    let request: JsonRpc<std::vec::Vec<Value>> = JsonRpc::new("quit", vec![]);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("quit:request {:?}", &request_raw);
-
-                    match request_raw {
-                        Ok(request_raw) => {
-                            let _ = stdin.write(format!("{}\n", request_raw).as_bytes());
-                            // TODO: Handle error
-                        }
-                        Err(e) => {
-                            return OutputStream::one(Err(ShellError::untagged_runtime_error(
-                                format!("Error while processing quit response: {:?}", e),
-                            )));
-                        }
-                    }
-                    let _ = child.wait();
+            outputs.extend(read_responses(&mut responses, "filter", pending));
 
-                    stream
-                }
+            futures::stream::iter(outputs).flatten().to_output_stream()
+        })
+        .flatten()
+        .to_output_stream())
+}
 
-                v => {
-                    // Stream contents
-                    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
-                    let stdout = child.stdout.as_mut().expect("Failed to open stdout");
+/// Sends a request to the plugin and reads its response
+fn call<T: Serialize>(
+    child: &mut Child,
+    responses: &mut dyn BufRead,
+    method: &str,
+    params: T,
+    name_tag: &Tag,
+) -> OutputStream {
+    if let Err(e) = send_request(child, method, params, name_tag) {
+        return OutputStream::one(Err(e));
+    }
 
-                    let mut reader = BufReader::new(stdout);
+    futures::stream::iter(read_responses(responses, method, 1))
+        .flatten()
+        .to_output_stream()
+}
 
-                    let request = JsonRpc::new("filter", v);
-                    let request_raw = serde_json::to_string(&request);
-                    trace!("filter:request {:?}", &request_raw);
+fn send_request<T: Serialize>(
+    child: &mut Child,
+    method: &str,
+    params: T,
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    let stdin = child.stdin.as_mut().expect("Failed to open stdin");
+
+    let request = JsonRpc::new(method, params);
+    let request_raw = serde_json::to_string(&request).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not load json from plugin",
+            "could not load json from plugin",
+            name_tag,
+        )
+    })?;
+    trace!("{}:request {:?}", method, &request_raw);
+
+    stdin
+        .write(format!("{}\n", request_raw).as_bytes())
+        .map_err(|err| ShellError::unexpected(format!("{}", err)))?;
+
+    Ok(())
+}
 
-                    match request_raw {
-                        Ok(request_raw) => {
-                            let _ = stdin.write(format!("{}\n", request_raw).as_bytes());
-                            // TODO: Handle error
-                        }
-                        Err(e) => {
-                            return OutputStream::one(Err(ShellError::untagged_runtime_error(
-                                format!("Error while processing filter response: {:?}", e),
-                            )));
-                        }
-                    }
+/// Sends a value of the stream to filter, binary values as a binary frame when the plugin
+/// understands them
+fn send_value(
+    child: &mut Child,
+    value: Value,
+    binary_frames: bool,
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    match value {
+        Value {
+            value: UntaggedValue::Primitive(Primitive::Binary(bytes)),
+            tag,
+        } if binary_frames => {
+            trace!("filter:binary frame of {} bytes", bytes.len());
+
+            let stdin = child.stdin.as_mut().expect("Failed to open stdin");
+            write_binary_frame(stdin, &bytes, tag).map_err(|e| {
+                ShellError::untagged_runtime_error(format!(
+                    "Error while sending binary frame: {:?}",
+                    e
+                ))
+            })
+        }
+        value => send_request(child, "filter", value, name_tag),
+    }
+}
 
-                    let mut input = String::new();
-                    match reader.read_line(&mut input) {
-                        Ok(_) => {
-                            let response = serde_json::from_str::<NuResult>(&input);
-                            trace!("filter:response {:?}", &response);
-
-                            match response {
-                                Ok(NuResult::response { params }) => match params {
-                                    Ok(params) => futures::stream::iter(params).to_output_stream(),
-                                    Err(e) => futures::stream::iter(vec![ReturnValue::Err(e)])
-                                        .to_output_stream(),
-                                },
-                                Err(e) => OutputStream::one(Err(
-                                    ShellError::untagged_runtime_error(format!(
-                                    "Error while processing filter response: {:?}\n== input ==\n{}",
-                                    e, input
-                                )),
-                                )),
-                            }
-                        }
-                        Err(e) => OutputStream::one(Err(ShellError::untagged_runtime_error(
-                            format!("Error while reading filter response: {:?}", e),
-                        ))),
-                    }
+/// Reads the responses to the last `count` requests, in the order they were sent
+fn read_responses(responses: &mut dyn BufRead, method: &str, count: usize) -> Vec<OutputStream> {
+    (0..count)
+        .map(|_| match read_frame(responses) {
+            Ok(input) => {
+                let response = serde_json::from_str::<NuResult>(&input);
+                trace!("{}:response {:?}", method, &response);
+
+                match response {
+                    Ok(NuResult::response { params }) => match params {
+                        Ok(params) => futures::stream::iter(params).to_output_stream(),
+                        Err(e) => OutputStream::one(Err(e)),
+                    },
+                    Err(e) => OutputStream::one(Err(ShellError::untagged_runtime_error(format!(
+                        "Error while processing {} response: {:?}\n== input ==\n{}",
+                        method, e, input
+                    )))),
                 }
             }
+            Err(e) => OutputStream::one(Err(ShellError::untagged_runtime_error(format!(
+                "Error while reading {} response: {:?}",
+                method, e
+            )))),
         })
-        .flatten()
-        .to_output_stream())
+        .collect()
 }
 
 #[derive(new)]
@@ -390,7 +375,8 @@ impl WholeStreamCommand for PluginSink {
 
 async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once().await?;
-    let call_info = args.call_info.clone();
+    let mut call_info = args.call_info.clone();
+    call_info.env = args.scope.get_env_vars();
 
     let input: Vec<Value> = args.input.collect().await;
 
@@ -449,3 +435,60 @@ async fn run_sink(path: String, args: CommandArgs) -> Result<OutputStream, Shell
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::read_responses;
+    use futures::executor::block_on;
+    use futures::StreamExt;
+    use nu_errors::ShellError;
+    use nu_plugin::jsonrpc::{send_response_as, FrameFormat};
+    use nu_protocol::{ReturnSuccess, ReturnValue, UntaggedValue, Value};
+
+    fn responses(format: FrameFormat) -> Vec<u8> {
+        let mut stream = vec![];
+
+        for n in 1..=2 {
+            let result: Result<Vec<ReturnValue>, ShellError> = Ok(vec![ReturnSuccess::value(
+                UntaggedValue::int(n).into_untagged_value(),
+            )]);
+            send_response_as(&mut stream, format, result).expect("a written frame");
+        }
+
+        stream
+    }
+
+    fn read(stream: &[u8]) -> Vec<Value> {
+        let outputs = read_responses(&mut &stream[..], "filter", 2);
+
+        block_on(futures::stream::iter(outputs).flatten().collect::<Vec<_>>())
+            .into_iter()
+            .map(|returned| match returned {
+                Ok(ReturnSuccess::Value(value)) => value,
+                other => panic!("expected a value, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_compact_responses() {
+        assert_eq!(
+            read(&responses(FrameFormat::Compact)),
+            vec![
+                UntaggedValue::int(1).into_untagged_value(),
+                UntaggedValue::int(2).into_untagged_value()
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_pretty_responses() {
+        assert_eq!(
+            read(&responses(FrameFormat::Pretty)),
+            vec![
+                UntaggedValue::int(1).into_untagged_value(),
+                UntaggedValue::int(2).into_untagged_value()
+            ]
+        );
+    }
+}
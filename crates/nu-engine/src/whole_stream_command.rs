@@ -206,13 +206,14 @@ impl Command {
         self.0.examples()
     }
 
-    pub async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+    pub async fn run(&self, mut args: CommandArgs) -> Result<OutputStream, ShellError> {
         if args.call_info.switch_present("help") {
             let cl = self.0.clone();
             Ok(OutputStream::one(Ok(ReturnSuccess::Value(
                 UntaggedValue::string(get_help(&*cl, &args.scope)).into_value(Tag::unknown()),
             ))))
         } else {
+            args.call_info.defaults = self.signature().defaults;
             self.0.run(args).await
         }
     }
@@ -140,6 +140,8 @@ pub enum ArgumentError {
     /// A sequence of characters was found that was not syntactically valid (but would have
     /// been valid if the command was an external command)
     InvalidExternalWord,
+    /// Flags were found together that the command declared as mutually exclusive
+    ConflictingFlags(Vec<Spanned<String>>),
 }
 
 impl PrettyDebug for ArgumentError {
@@ -171,6 +173,17 @@ impl PrettyDebug for ArgumentError {
                     + DbgDocBldr::description("`")
             }
             ArgumentError::InvalidExternalWord => DbgDocBldr::description("invalid word"),
+            ArgumentError::ConflictingFlags(flags) => {
+                DbgDocBldr::description("flags `")
+                    + DbgDocBldr::description(
+                        flags
+                            .iter()
+                            .map(|flag| flag.item.clone())
+                            .collect::<Vec<_>>()
+                            .join("`, `"),
+                    )
+                    + DbgDocBldr::description("` can not be used together")
+            }
         }
     }
 }
@@ -187,6 +200,14 @@ pub struct ShellError {
 /// is used, which prints an error, highlighting spans.
 impl PrettyDebug for ShellError {
     fn pretty(&self) -> DebugDocBuilder {
+        if let ShellError {
+            error: ProximateShellError::UntaggedRuntimeError { reason },
+            cause: Some(cause),
+        } = self
+        {
+            return cause.pretty() + DbgDocBldr::space() + DbgDocBldr::description(reason);
+        }
+
         match &self.error {
             ProximateShellError::SyntaxError { problem } => {
                 DbgDocBldr::error("Syntax Error")
@@ -437,7 +458,30 @@ impl ShellError {
         ProximateShellError::ExternalPlaceholderError.start()
     }
 
+    /// Wraps the error in a breadcrumb describing what was being done when it happened, such as
+    /// "while incrementing field 'package.version'". The wrapped error becomes the cause and keeps
+    /// its labels, the context is rendered as a note below it.
+    pub fn with_context(self, context: impl Into<String>) -> ShellError {
+        ShellError {
+            error: ProximateShellError::UntaggedRuntimeError {
+                reason: context.into(),
+            },
+            cause: Some(Box::new(self)),
+        }
+    }
+
     pub fn into_diagnostic(self) -> Option<Diagnostic<usize>> {
+        if let ShellError {
+            error: ProximateShellError::UntaggedRuntimeError { reason },
+            cause: Some(cause),
+        } = self
+        {
+            return cause.into_diagnostic().map(|mut diagnostic| {
+                diagnostic.notes.push(reason);
+                diagnostic
+            });
+        }
+
         match self.error {
             ProximateShellError::MissingValue { span, reason } => {
                 let mut d = Diagnostic::bug().with_message(format!("Internal Error (missing value) :: {}", reason));
@@ -503,6 +547,23 @@ impl ShellError {
                     ),
                 )
                 .with_labels(vec![Label::primary(0, command.span)]),
+                ArgumentError::ConflictingFlags(flags) => Diagnostic::error().with_message(
+                    format!(
+                        "{} can not combine {}",
+                        Color::Cyan.paint(&command.item),
+                        flags
+                            .iter()
+                            .map(|flag| Color::Green.bold().paint(format!("--{}", flag.item)).to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+                .with_labels(
+                    flags
+                        .iter()
+                        .map(|flag| Label::primary(0, flag.span).with_message("conflicting flag"))
+                        .collect(),
+                ),
             }),
             ProximateShellError::TypeError {
                 expected,
@@ -761,7 +822,9 @@ impl ProximateShellError {
 
 impl HasFallibleSpan for ShellError {
     fn maybe_span(&self) -> Option<Span> {
-        self.error.maybe_span()
+        self.error
+            .maybe_span()
+            .or_else(|| self.cause.as_ref().and_then(|cause| cause.maybe_span()))
     }
 }
 
@@ -975,3 +1038,51 @@ macro_rules! ranged_decimal {
 
 ranged_decimal!(f32 -> to_f32 -> F32);
 ranged_decimal!(f64 -> to_f64 -> F64);
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use nu_source::{Span, SpannedItem};
+
+    #[test]
+    fn context_is_rendered_after_the_error() {
+        let error = ShellError::untagged_runtime_error("not a number")
+            .with_context("while incrementing field 'package.version'");
+
+        assert_eq!(
+            format!("{}", error),
+            "Unknown Error(not a number) while incrementing field 'package.version'"
+        );
+    }
+
+    #[test]
+    fn context_is_added_as_a_note_keeping_the_labels() {
+        let error = ShellError::labeled_error("not a number", "here", Span::new(3, 5))
+            .with_context("while incrementing field 'version'")
+            .with_context("while incrementing field 'package'");
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(diagnostic.message, "not a number");
+        assert_eq!(diagnostic.labels[0].range, 3..5);
+        assert_eq!(
+            diagnostic.notes,
+            vec![
+                "while incrementing field 'version'".to_string(),
+                "while incrementing field 'package'".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn type_error_names_the_expected_and_actual_types() {
+        let error = ShellError::type_error("integer", "string".spanned(Span::new(2, 7)));
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(diagnostic.message, "Type Error");
+        assert_eq!(
+            diagnostic.labels[0].message,
+            "Expected integer, found string"
+        );
+        assert_eq!(diagnostic.labels[0].range, 2..7);
+    }
+}
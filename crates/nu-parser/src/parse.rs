@@ -1362,6 +1362,7 @@ fn parse_internal_command(
 
     let mut current_positional = 0;
     let mut named = NamedArguments::new();
+    let mut flags_given: Vec<Spanned<String>> = vec![];
     let mut positional = vec![];
     let mut error = None;
     idx += 1; // Start where the arguments begin
@@ -1372,7 +1373,13 @@ fn parse_internal_command(
                 get_flags_from_flag(&signature, &lite_cmd.parts[0], &lite_cmd.parts[idx]);
 
             if err.is_none() {
+                let flag_span = lite_cmd.parts[idx].span;
+
                 for (full_name, named_type) in &named_types {
+                    if !flags_given.iter().any(|flag| &flag.item == full_name) {
+                        flags_given.push(full_name.clone().spanned(flag_span));
+                    }
+
                     match named_type {
                         NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape) => {
                             if idx == lite_cmd.parts.len() {
@@ -1459,6 +1466,22 @@ fn parse_internal_command(
         idx += 1;
     }
 
+    // Reject flags the signature declared as mutually exclusive
+    for group in &signature.exclusive_groups {
+        let conflicting: Vec<Spanned<String>> = flags_given
+            .iter()
+            .filter(|flag| group.contains(&flag.item))
+            .cloned()
+            .collect();
+
+        if conflicting.len() > 1 && error.is_none() {
+            error = Some(ParseError::argument_error(
+                lite_cmd.parts[0].clone(),
+                ArgumentError::ConflictingFlags(conflicting),
+            ));
+        }
+    }
+
     // Count the required positional arguments and ensure these have been met
     let mut required_arg_count = 0;
     for positional_arg in &signature.positional {
@@ -2179,3 +2202,216 @@ fn unit_parse_byte_units() {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::hir::Block;
+    use std::str::FromStr;
+
+    #[derive(Debug)]
+    struct EmptyScope;
+
+    impl ParserScope for EmptyScope {
+        fn get_signature(&self, _name: &str) -> Option<Signature> {
+            None
+        }
+
+        fn has_signature(&self, _name: &str) -> bool {
+            false
+        }
+
+        fn add_definition(&self, _block: Block) {}
+
+        fn get_definitions(&self) -> Vec<Block> {
+            vec![]
+        }
+
+        fn get_alias(&self, _name: &str) -> Option<Vec<Spanned<String>>> {
+            None
+        }
+
+        fn add_alias(&self, _name: &str, _replacement: Vec<Spanned<String>>) {}
+
+        fn enter_scope(&self) {}
+
+        fn exit_scope(&self) {}
+    }
+
+    #[test]
+    fn number_shape_accepts_fractional_numbers() {
+        let (expr, err) = parse_arg(
+            SyntaxShape::Number,
+            &EmptyScope,
+            &"1.5".to_string().spanned_unknown(),
+        );
+
+        assert!(err.is_none());
+        assert_eq!(
+            expr.expr,
+            Expression::decimal(BigDecimal::from_str("1.5").expect("a decimal"))
+        );
+    }
+
+    #[test]
+    fn number_shape_rejects_non_numeric_words() {
+        let (_, err) = parse_arg(
+            SyntaxShape::Number,
+            &EmptyScope,
+            &"abc".to_string().spanned_unknown(),
+        );
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn int_shape_rejects_fractional_numbers() {
+        let (_, err) = parse_arg(
+            SyntaxShape::Int,
+            &EmptyScope,
+            &"1.5".to_string().spanned_unknown(),
+        );
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn file_path_shape_expands_tilde() {
+        let (expr, err) = parse_arg(
+            SyntaxShape::FilePath,
+            &EmptyScope,
+            &"~/notes.txt".to_string().spanned_unknown(),
+        );
+
+        let home = shellexpand::tilde("~").to_string();
+
+        assert!(err.is_none());
+        assert_eq!(
+            expr.expr,
+            Expression::FilePath(Path::new(&home).join("notes.txt"))
+        );
+    }
+
+    #[test]
+    fn file_path_shape_keeps_spaces_inside_quotes() {
+        let (expr, err) = parse_arg(
+            SyntaxShape::FilePath,
+            &EmptyScope,
+            &"\"my notes/today.txt\"".to_string().spanned_unknown(),
+        );
+
+        assert!(err.is_none());
+        assert_eq!(
+            expr.expr,
+            Expression::FilePath(Path::new("my notes/today.txt").to_path_buf())
+        );
+    }
+
+    fn parts(line: &str) -> LiteCommand {
+        let mut start = 0;
+
+        LiteCommand {
+            parts: line
+                .split(' ')
+                .map(|part| {
+                    let span = Span::new(start, start + part.len());
+                    start += part.len() + 1;
+                    part.to_string().spanned(span)
+                })
+                .collect(),
+            comments: None,
+        }
+    }
+
+    fn versioned() -> Signature {
+        Signature::build("bump")
+            .switch("major", "bump the major version", Some('M'))
+            .switch("minor", "bump the minor version", Some('m'))
+            .switch("verbose", "report what changed", Some('v'))
+            .exclusive_group(&["major", "minor"])
+    }
+
+    #[test]
+    fn exclusive_flags_are_rejected_together() {
+        let (_, err) =
+            parse_internal_command(&parts("bump --major --minor"), &EmptyScope, &versioned(), 0);
+
+        match err.as_ref().map(|err| err.reason()) {
+            Some(nu_errors::ParseErrorReason::ArgumentError {
+                error: ArgumentError::ConflictingFlags(flags),
+                ..
+            }) => assert_eq!(
+                flags
+                    .iter()
+                    .map(|flag| flag.item.clone())
+                    .collect::<Vec<_>>(),
+                vec!["major".to_string(), "minor".to_string()]
+            ),
+            other => panic!("expected conflicting flags, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn exclusive_short_flags_are_rejected_together() {
+        let (_, err) = parse_internal_command(&parts("bump -Mm"), &EmptyScope, &versioned(), 0);
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn one_flag_of_an_exclusive_group_is_accepted() {
+        let (_, err) = parse_internal_command(
+            &parts("bump --major --verbose"),
+            &EmptyScope,
+            &versioned(),
+            0,
+        );
+
+        assert!(err.is_none());
+    }
+
+    #[test]
+    fn short_and_long_flags_set_the_same_named_argument() {
+        for line in &["bump -M", "bump --major"] {
+            let (command, err) = parse_internal_command(&parts(line), &EmptyScope, &versioned(), 0);
+
+            assert!(err.is_none());
+            let major = command
+                .args
+                .named
+                .and_then(|mut named| named.named.remove("major"));
+
+            assert!(matches!(major, Some(hir::NamedValue::PresentSwitch(_))));
+        }
+    }
+
+    fn embedding() -> Signature {
+        Signature::build("embed").required(
+            "field",
+            SyntaxShape::String,
+            "the column to embed under",
+        )
+    }
+
+    #[test]
+    fn missing_required_positional_is_named_in_the_error() {
+        let (_, err) = parse_internal_command(&parts("embed"), &EmptyScope, &embedding(), 0);
+
+        match err.as_ref().map(|err| err.reason()) {
+            Some(nu_errors::ParseErrorReason::ArgumentError {
+                error: ArgumentError::MissingMandatoryPositional(name),
+                ..
+            }) => assert_eq!(name, "field"),
+            other => panic!("expected a missing positional, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn present_required_positional_is_accepted() {
+        let (command, err) =
+            parse_internal_command(&parts("embed name"), &EmptyScope, &embedding(), 0);
+
+        assert!(err.is_none());
+        assert_eq!(command.args.positional.map(|args| args.len()), Some(1));
+    }
+}
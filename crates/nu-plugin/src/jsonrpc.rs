@@ -1,6 +1,8 @@
-use nu_protocol::{CallInfo, Value};
+use nu_errors::ShellError;
+use nu_protocol::{CallInfo, UntaggedValue, Value};
+use nu_source::{Span, Tag};
 use serde::{Deserialize, Serialize};
-use std::io::Write;
+use std::io::{self, BufRead, Write};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonRpc<T> {
@@ -19,20 +21,110 @@ impl<T> JsonRpc<T> {
     }
 }
 
+/// The environment variable that switches plugins over to pretty frames when it is set
+pub const PRETTY_FRAMES_VAR: &str = "NU_PLUGIN_PRETTY_FRAMES";
+
+/// How responses are written. A compact frame is a single line of JSON. A pretty frame spreads
+/// the JSON over indented lines and ends with an empty line, so the output of a plugin run by
+/// hand can be read while debugging it. The host reads either with `read_frame`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FrameFormat {
+    Compact,
+    Pretty,
+}
+
+impl Default for FrameFormat {
+    fn default() -> Self {
+        FrameFormat::Compact
+    }
+}
+
+impl FrameFormat {
+    /// Pretty frames when `NU_PLUGIN_PRETTY_FRAMES` is set, compact ones otherwise
+    pub fn from_env() -> FrameFormat {
+        match std::env::var_os(PRETTY_FRAMES_VAR) {
+            Some(_) => FrameFormat::Pretty,
+            None => FrameFormat::Compact,
+        }
+    }
+}
+
 pub fn send_response<T: Serialize>(result: T) {
+    let _ = send_response_as(&mut std::io::stdout(), FrameFormat::from_env(), result);
+}
+
+/// Writes a single response frame in the given format and flushes it right away.
+/// Fails when the frame could not be written, for example because the host stopped reading.
+pub fn send_response_as<T: Serialize>(
+    writer: &mut dyn Write,
+    format: FrameFormat,
+    result: T,
+) -> io::Result<()> {
     let response = JsonRpc::new("response", result);
-    let response_raw = serde_json::to_string(&response);
+    let response_raw = match format {
+        FrameFormat::Compact => serde_json::to_string(&response),
+        FrameFormat::Pretty => serde_json::to_string_pretty(&response),
+    };
 
-    let mut stdout = std::io::stdout();
+    match (response_raw, format) {
+        (Ok(response), FrameFormat::Compact) => writeln!(writer, "{}", response)?,
+        (Ok(response), FrameFormat::Pretty) => writeln!(writer, "{}\n", response)?,
+        (Err(err), _) => writeln!(writer, "{}", err)?,
+    };
 
-    match response_raw {
-        Ok(response) => {
-            let _ = writeln!(stdout, "{}", response);
-        }
-        Err(err) => {
-            let _ = writeln!(stdout, "{}", err);
+    writer.flush()
+}
+
+/// Reads a single response frame: a line when it is compact, or the lines up to the empty one
+/// that ends it when it is pretty. The frame is empty once the stream has ended.
+pub fn read_frame(reader: &mut dyn BufRead) -> io::Result<String> {
+    let mut frame = String::new();
+    reader.read_line(&mut frame)?;
+
+    // a pretty frame opens its object on a line of its own, and its JSON has no empty lines
+    if frame.trim_end() == "{" {
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            frame.push_str(&line);
         }
-    };
+    }
+
+    Ok(frame)
+}
+
+/// The header of a binary frame. The `length` raw bytes of the value follow the header line
+/// directly, so binary values do not have to be spelled out as a JSON array of numbers.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BinaryFrame {
+    pub length: usize,
+    pub tag: Tag,
+}
+
+/// Sends a binary value to a plugin as a `filter_binary` header followed by its raw bytes.
+pub fn write_binary_frame(writer: &mut dyn Write, bytes: &[u8], tag: Tag) -> io::Result<()> {
+    let header = JsonRpc::new(
+        "filter_binary",
+        BinaryFrame {
+            length: bytes.len(),
+            tag,
+        },
+    );
+    let header_raw = serde_json::to_string(&header)?;
+
+    writeln!(writer, "{}", header_raw)?;
+    writer.write_all(bytes)?;
+    writer.flush()
+}
+
+/// Reads the raw bytes announced by a binary frame header back into a binary value.
+pub fn read_binary_frame(reader: &mut dyn BufRead, frame: BinaryFrame) -> io::Result<Value> {
+    let mut bytes = vec![0; frame.length];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(UntaggedValue::binary(bytes).into_value(frame.tag))
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -42,7 +134,145 @@ pub enum NuCommand {
     config,
     begin_filter { params: CallInfo },
     filter { params: Value },
+    filter_binary { params: BinaryFrame },
     end_filter,
     sink { params: (CallInfo, Vec<Value>) },
     quit,
 }
+
+/// Decodes a single command sent by the host. A message that ends before it is complete
+/// is reported as an unexpected EOF, so a host that crashed mid-write can be told apart
+/// from one that sent malformed input.
+pub fn decode_command(input: &str) -> Result<NuCommand, ShellError> {
+    serde_json::from_str::<NuCommand>(input).map_err(|err| {
+        if err.is_eof() {
+            ShellError::unexpected_eof("the rest of the plugin message", Span::unknown())
+        } else {
+            ShellError::untagged_runtime_error(format!(
+                "Could not handle plugin message: {} {:?}",
+                input, err
+            ))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode_command, read_binary_frame, read_frame, send_response_as, write_binary_frame,
+        FrameFormat, JsonRpc, NuCommand,
+    };
+    use nu_errors::ShellError;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::{Span, Tag};
+    use std::io::BufRead;
+
+    fn package() -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_untagged("name", UntaggedValue::string("nu"));
+            row.insert_untagged("version", UntaggedValue::string("0.27.2"));
+        })
+    }
+
+    fn frame(format: FrameFormat, value: &Value) -> String {
+        let mut output = vec![];
+        send_response_as(&mut output, format, value).expect("a written frame");
+        String::from_utf8(output).expect("a UTF-8 frame")
+    }
+
+    #[test]
+    fn compact_frames_fit_on_a_single_line() {
+        let frame = frame(FrameFormat::Compact, &package());
+
+        assert_eq!(frame.lines().count(), 1);
+        assert!(frame.ends_with('\n'));
+    }
+
+    #[test]
+    fn pretty_frames_span_several_lines_and_end_with_an_empty_one() {
+        let frame = frame(FrameFormat::Pretty, &package());
+
+        assert!(frame.lines().count() > 1);
+        assert!(frame.ends_with("}\n\n"));
+    }
+
+    #[test]
+    fn pretty_frames_round_trip() {
+        let frame = frame(FrameFormat::Pretty, &package());
+
+        let response = serde_json::from_str::<JsonRpc<Value>>(&frame).expect("a parseable frame");
+
+        assert_eq!(response.method, "response");
+        assert_eq!(response.params, package());
+    }
+
+    #[test]
+    fn reads_compact_and_pretty_frames_one_after_the_other() {
+        let stream = format!(
+            "{}{}{}",
+            frame(FrameFormat::Pretty, &package()),
+            frame(FrameFormat::Compact, &package()),
+            frame(FrameFormat::Pretty, &package())
+        );
+        let mut reader = stream.as_bytes();
+
+        for _ in 0..3 {
+            let frame = read_frame(&mut reader).expect("a frame");
+            let response =
+                serde_json::from_str::<JsonRpc<Value>>(&frame).expect("a parseable frame");
+
+            assert_eq!(response.params, package());
+        }
+
+        assert_eq!(read_frame(&mut reader).expect("the end"), "");
+    }
+
+    #[test]
+    fn binary_frames_round_trip_non_utf8_bytes() {
+        let bytes = vec![0xff, 0xfe, 0x00, 0x0a, 0xc3, 0x28];
+        let mut stream = vec![];
+
+        write_binary_frame(&mut stream, &bytes, Tag::unknown()).expect("a written frame");
+
+        let mut reader = &stream[..];
+        let mut header = String::new();
+        reader.read_line(&mut header).expect("a header line");
+
+        let frame = match decode_command(&header) {
+            Ok(NuCommand::filter_binary { params }) => params,
+            other => panic!("expected a binary frame, got {:?}", other),
+        };
+        let value = read_binary_frame(&mut reader, frame).expect("the framed bytes");
+
+        assert_eq!(value, UntaggedValue::binary(bytes).into_untagged_value());
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn decodes_a_complete_message() {
+        assert!(matches!(
+            decode_command(r#"{"method":"end_filter"}"#),
+            Ok(NuCommand::end_filter)
+        ));
+    }
+
+    #[test]
+    fn half_written_message_is_an_unexpected_eof() {
+        let actual = decode_command(r#"{"method":"filter","params":{"val"#).unwrap_err();
+
+        assert_eq!(
+            actual,
+            ShellError::unexpected_eof("the rest of the plugin message", Span::unknown())
+        );
+    }
+
+    #[test]
+    fn malformed_message_is_not_an_unexpected_eof() {
+        let actual = decode_command(r#"{"method":"frobnicate"}"#).unwrap_err();
+
+        assert_ne!(
+            actual,
+            ShellError::unexpected_eof("the rest of the plugin message", Span::unknown())
+        );
+    }
+}
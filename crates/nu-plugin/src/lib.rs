@@ -1,6 +1,12 @@
 pub mod jsonrpc;
 mod plugin;
+mod pool;
 
 pub mod test_helpers;
+mod timed_writer;
 
-pub use crate::plugin::{serve_plugin, Plugin};
+pub use crate::plugin::{
+    serve_plugin, serve_plugin_from, serve_plugin_logging_to, serve_plugin_pooled, Plugin,
+};
+pub use crate::pool::POOL_SIZE;
+pub use crate::timed_writer::{write_timeout_from_env, TimedWriter, WRITE_TIMEOUT_VAR};
@@ -1,9 +1,21 @@
-use crate::jsonrpc::{send_response, NuCommand};
+use crate::jsonrpc::{
+    decode_command, read_binary_frame, send_response, send_response_as, FrameFormat, NuCommand,
+};
+use crate::pool::{OrderedOutput, Pool, POOL_SIZE};
+use crate::timed_writer::{write_timeout_from_env, TimedWriter};
 use nu_errors::ShellError;
-use nu_protocol::{CallInfo, ReturnValue, Signature, Value};
-use std::io;
+use nu_protocol::{
+    CallInfo, ReturnSuccess, ReturnValue, Signature, Value, PLUGIN_PROTOCOL_VERSION,
+};
+use nu_source::Span;
+use serde::Serialize;
+use std::io::{self, BufRead, Write};
 
 /// The `Plugin` trait defines the API which plugins may use to "hook" into nushell.
+///
+/// Filter plugins are driven in this order: `begin_filter` once with the arguments, `filter` once for every
+/// `Value` in the input stream, then `end_filter` once after the last `Value`. Values returned from `end_filter`
+/// are emitted after everything returned by `filter`, which lets plugins flush any state they accumulated.
 pub trait Plugin {
     /// The `config` method is used to configure a plugin's user interface / signature.
     ///
@@ -25,16 +37,27 @@ pub trait Plugin {
     }
 
     /// `end_filter` is the last method to be called by the plugin after all `Value`s are processed by the plugin.
+    /// Aggregating plugins (eg. a running sum) can emit their final values here.
     /// This method requires the plugin `Signature` to be configured as filterable.
     fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
         Ok(vec![])
     }
 
     /// `sink` consumes the `Value`s that are passed in, preventing further processing.
-    /// This method requires the plugin `Signature` to be configured without filtering.
+    /// The whole input is collected before `sink` is called once with it.
+    /// This method requires the plugin `Signature` to be configured as a sink (the default, or with `.sink()`).
     fn sink(&mut self, _call_info: CallInfo, _input: Vec<Value>) {}
 
     fn quit(&mut self) {}
+
+    /// `worker` copies the plugin for a thread filtering values alongside the others.
+    /// It is called after `begin_filter`, so the copy carries the parsed arguments along.
+    /// Plugins that return a copy are served by a pool of workers, but values are only filtered
+    /// concurrently when the `Signature` is also marked as `.stateless()`, since only then does the
+    /// host send several values before reading their responses.
+    fn worker(&self) -> Option<Box<dyn Plugin + Send>> {
+        None
+    }
 }
 
 pub fn serve_plugin(plugin: &mut dyn Plugin) {
@@ -51,20 +74,28 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
         };
 
         if let Ok(input) = input {
-            let command = serde_json::from_str::<NuCommand>(&input);
-            match command {
+            match decode_command(&input) {
                 Ok(NuCommand::config) => {
-                    send_response(plugin.config());
+                    send_response(validated_config(plugin));
                     return;
                 }
                 Ok(NuCommand::begin_filter { params }) => {
-                    send_response(plugin.begin_filter(params));
+                    send_response(log_debug_records(
+                        plugin.begin_filter(params),
+                        &mut io::stderr(),
+                    ));
                 }
                 Ok(NuCommand::filter { params }) => {
-                    send_response(plugin.filter(params));
+                    send_response(log_debug_records(plugin.filter(params), &mut io::stderr()));
+                }
+                Ok(NuCommand::filter_binary { .. }) => {
+                    send_response(ShellError::untagged_runtime_error(
+                        "Binary frames can only be sent over stdin",
+                    ));
+                    return;
                 }
                 Ok(NuCommand::end_filter) => {
-                    send_response(plugin.end_filter());
+                    send_response(log_debug_records(plugin.end_filter(), &mut io::stderr()));
                     return;
                 }
 
@@ -76,61 +107,813 @@ pub fn serve_plugin(plugin: &mut dyn Plugin) {
                     plugin.quit();
                     return;
                 }
-                e => {
-                    send_response(ShellError::untagged_runtime_error(format!(
-                        "Could not handle plugin message: {} {:?}",
-                        input, e
-                    )));
+                Err(err) => {
+                    send_response(err);
                     return;
                 }
             }
         }
     } else {
-        loop {
-            let mut input = String::new();
-            match io::stdin().read_line(&mut input) {
-                Ok(_) => {
-                    let command = serde_json::from_str::<NuCommand>(&input);
-                    match command {
-                        Ok(NuCommand::config) => {
-                            send_response(plugin.config());
-                            break;
-                        }
-                        Ok(NuCommand::begin_filter { params }) => {
-                            send_response(plugin.begin_filter(params));
-                        }
-                        Ok(NuCommand::filter { params }) => {
-                            send_response(plugin.filter(params));
-                        }
-                        Ok(NuCommand::end_filter) => {
-                            send_response(plugin.end_filter());
-                            break;
-                        }
-                        Ok(NuCommand::sink { params }) => {
-                            plugin.sink(params.0, params.1);
-                            break;
-                        }
-                        Ok(NuCommand::quit) => {
-                            plugin.quit();
-                            break;
+        // only plugins that can be copied are served by the pool
+        let pooled = plugin.worker().is_some();
+
+        match (write_timeout_from_env(), pooled) {
+            (Some(timeout), true) => serve_plugin_pooled(
+                plugin,
+                &mut io::stdin().lock(),
+                TimedWriter::new(io::stdout(), timeout),
+                POOL_SIZE,
+            ),
+            (None, true) => {
+                serve_plugin_pooled(plugin, &mut io::stdin().lock(), io::stdout(), POOL_SIZE)
+            }
+            (Some(timeout), false) => serve_plugin_from(
+                plugin,
+                &mut io::stdin().lock(),
+                &mut TimedWriter::new(io::stdout(), timeout),
+            ),
+            (None, false) => serve_plugin_from(plugin, &mut io::stdin().lock(), &mut io::stdout()),
+        }
+    }
+}
+
+/// Asks the plugin for its signature, rejecting signatures whose flags collide.
+/// The signature is stamped with the protocol version the plugin was built against.
+fn validated_config(plugin: &mut dyn Plugin) -> Result<Signature, ShellError> {
+    let mut signature = plugin.config()?;
+    signature.validate()?;
+    signature.protocol_version = Some(PLUGIN_PROTOCOL_VERSION);
+    Ok(signature)
+}
+
+/// Reads commands line by line from `reader` until the plugin is done or the stream ends.
+/// Every command is answered on `writer` before the next one is read, so values stream through
+/// one at a time and the responses keep the order of the inputs.
+/// A clean EOF (the host closing the pipe) is treated as a request to quit.
+/// Responses are compact frames unless `NU_PLUGIN_PRETTY_FRAMES` is set. When a response can not
+/// be written in time (see `TimedWriter`), the plugin quits instead of waiting on the host.
+/// Debug records the plugin returns are written to stderr.
+pub fn serve_plugin_from(
+    plugin: &mut dyn Plugin,
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+) {
+    serve_plugin_logging_to(plugin, reader, writer, &mut io::stderr())
+}
+
+/// Like `serve_plugin_from`, but writes the debug records the plugin returns to `log`, one JSON
+/// value per line, instead of stderr
+pub fn serve_plugin_logging_to(
+    plugin: &mut dyn Plugin,
+    reader: &mut dyn BufRead,
+    writer: &mut dyn Write,
+    log: &mut dyn Write,
+) {
+    let format = FrameFormat::from_env();
+
+    loop {
+        let mut input = String::new();
+        match reader.read_line(&mut input) {
+            Ok(0) => {
+                plugin.quit();
+                break;
+            }
+            Ok(_) => match decode_command(&input) {
+                Ok(NuCommand::config) => {
+                    respond(writer, format, validated_config(plugin));
+                    break;
+                }
+                Ok(NuCommand::begin_filter { params }) => {
+                    let result = log_debug_records(plugin.begin_filter(params), log);
+                    if !respond(writer, format, result) {
+                        plugin.quit();
+                        break;
+                    }
+                }
+                Ok(NuCommand::filter { params }) => {
+                    let result = log_debug_records(plugin.filter(params), log);
+                    if !respond(writer, format, result) {
+                        plugin.quit();
+                        break;
+                    }
+                }
+                Ok(NuCommand::filter_binary { params }) => {
+                    match read_binary_frame(reader, params) {
+                        Ok(value) => {
+                            let result = log_debug_records(plugin.filter(value), log);
+                            if !respond(writer, format, result) {
+                                plugin.quit();
+                                break;
+                            }
                         }
-                        e => {
-                            send_response(ShellError::untagged_runtime_error(format!(
-                                "Could not handle plugin message: {} {:?}",
-                                input, e
-                            )));
+                        Err(_) => {
+                            respond(
+                                writer,
+                                format,
+                                ShellError::unexpected_eof(
+                                    "the rest of the binary frame",
+                                    Span::unknown(),
+                                ),
+                            );
                             break;
                         }
                     }
                 }
-                e => {
-                    send_response(ShellError::untagged_runtime_error(format!(
+                Ok(NuCommand::end_filter) => {
+                    respond(writer, format, log_debug_records(plugin.end_filter(), log));
+                    break;
+                }
+                Ok(NuCommand::sink { params }) => {
+                    plugin.sink(params.0, params.1);
+                    break;
+                }
+                Ok(NuCommand::quit) => {
+                    plugin.quit();
+                    break;
+                }
+                Err(err) => {
+                    respond(writer, format, err);
+                    break;
+                }
+            },
+            e => {
+                respond(
+                    writer,
+                    format,
+                    ShellError::untagged_runtime_error(format!(
                         "Could not handle plugin message: {:?}",
                         e,
-                    )));
+                    )),
+                );
+                break;
+            }
+        }
+    }
+}
+
+/// Like `serve_plugin_from`, but filters values on up to `workers` copies of the plugin at once.
+/// Every response gets a sequence number and they are written in that order, so the output keeps
+/// the order of the inputs even when later values are done first. The copies come from
+/// `Plugin::worker` once `begin_filter` is done; a plugin that gives none filters serially.
+/// The host sends a stateless plugin up to `POOL_SIZE` values before reading their responses.
+pub fn serve_plugin_pooled<W: Write + Send + 'static>(
+    plugin: &mut dyn Plugin,
+    reader: &mut dyn BufRead,
+    writer: W,
+    workers: usize,
+) {
+    let mut output = OrderedOutput::new(writer, FrameFormat::from_env());
+    let mut pool: Option<Pool> = None;
+
+    loop {
+        if output.is_stalled() {
+            plugin.quit();
+            break;
+        }
+
+        let mut input = String::new();
+        match reader.read_line(&mut input) {
+            Ok(0) => {
+                plugin.quit();
+                break;
+            }
+            Ok(_) => match decode_command(&input) {
+                Ok(NuCommand::config) => {
+                    output.respond(validated_config(plugin));
                     break;
                 }
+                Ok(NuCommand::begin_filter { params }) => {
+                    let result = log_debug_records(plugin.begin_filter(params), &mut io::stderr());
+                    output.respond(result);
+
+                    if pool.is_none() {
+                        pool = (0..workers)
+                            .map(|_| plugin.worker())
+                            .collect::<Option<Vec<_>>>()
+                            .map(|copies| Pool::start(copies, &output));
+                    }
+                }
+                Ok(NuCommand::filter { params }) => match &pool {
+                    Some(pool) => pool.filter(output.reserve(), params),
+                    None => {
+                        output.respond(log_debug_records(plugin.filter(params), &mut io::stderr()))
+                    }
+                },
+                Ok(NuCommand::filter_binary { params }) => {
+                    match (read_binary_frame(reader, params), &pool) {
+                        (Ok(value), Some(pool)) => pool.filter(output.reserve(), value),
+                        (Ok(value), None) => output
+                            .respond(log_debug_records(plugin.filter(value), &mut io::stderr())),
+                        (Err(_), _) => {
+                            output.respond(ShellError::unexpected_eof(
+                                "the rest of the binary frame",
+                                Span::unknown(),
+                            ));
+                            break;
+                        }
+                    }
+                }
+                Ok(NuCommand::end_filter) => {
+                    output.respond(log_debug_records(plugin.end_filter(), &mut io::stderr()));
+                    break;
+                }
+                Ok(NuCommand::sink { params }) => {
+                    plugin.sink(params.0, params.1);
+                    break;
+                }
+                Ok(NuCommand::quit) => {
+                    plugin.quit();
+                    break;
+                }
+                Err(err) => {
+                    output.respond(err);
+                    break;
+                }
+            },
+            e => {
+                output.respond(ShellError::untagged_runtime_error(format!(
+                    "Could not handle plugin message: {:?}",
+                    e,
+                )));
+                break;
+            }
+        }
+    }
+
+    if let Some(pool) = pool {
+        pool.stop();
+    }
+    output.finish();
+}
+
+/// Sends the response, telling whether the loop can go on. A host that stopped reading is
+/// reported on stderr, so the plugin can exit instead of hanging on it.
+fn respond<T: Serialize>(writer: &mut dyn Write, format: FrameFormat, result: T) -> bool {
+    match send_response_as(writer, format, result) {
+        Err(err) if err.kind() == io::ErrorKind::TimedOut => {
+            eprintln!("Giving up on the host, which stopped reading: {}", err);
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Writes the debug records among the returned values to `log` as JSON lines, keeping them out
+/// of the data sent to the host
+pub(crate) fn log_debug_records(
+    result: Result<Vec<ReturnValue>, ShellError>,
+    log: &mut dyn Write,
+) -> Result<Vec<ReturnValue>, ShellError> {
+    result.map(|values| {
+        values
+            .into_iter()
+            .filter(|value| match value {
+                Ok(ReturnSuccess::DebugRecord(record)) => {
+                    if let Ok(line) = serde_json::to_string(record) {
+                        let _ = writeln!(log, "{}", line);
+                    }
+                    false
+                }
+                _ => true,
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{serve_plugin_from, serve_plugin_logging_to, serve_plugin_pooled, Plugin};
+    use crate::jsonrpc::{write_binary_frame, JsonRpc, NuCommand};
+    use crate::test_helpers::plugin;
+    use crate::TimedWriter;
+    use nu_errors::ShellError;
+    use nu_protocol::{
+        CallInfo, NamedType, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue,
+        Value,
+    };
+    use nu_source::Tag;
+    use std::io::{self, Write};
+    use std::sync::mpsc::{self, Receiver};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[derive(Default)]
+    struct Quitter {
+        quit: bool,
+    }
+
+    impl Plugin for Quitter {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("quitter").filter())
+        }
+
+        fn quit(&mut self) {
+            self.quit = true;
+        }
+    }
+
+    #[derive(Default)]
+    struct Summer {
+        total: i64,
+        emitted: Option<i64>,
+    }
+
+    impl Plugin for Summer {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("summer").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            self.total += input.as_i64()?;
+            Ok(vec![])
+        }
+
+        fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+            self.emitted = Some(self.total);
+            Ok(vec![ReturnSuccess::value(
+                UntaggedValue::int(self.total).into_untagged_value(),
+            )])
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: Vec<Value>,
+    }
+
+    impl Plugin for Recorder {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("recorder").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            self.seen.push(input);
+            Ok(vec![])
+        }
+    }
+
+    struct Warner;
+
+    impl Plugin for Warner {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("warner").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            Ok(vec![
+                ReturnSuccess::warning("version had no prerelease, starting at alpha.1"),
+                ReturnSuccess::value(input),
+            ])
+        }
+    }
+
+    struct Explainer;
+
+    impl Plugin for Explainer {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("explainer").filter())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            Ok(vec![
+                ReturnSuccess::debug_record(
+                    UntaggedValue::string("not a version, passed through").into_untagged_value(),
+                ),
+                ReturnSuccess::value(input),
+            ])
+        }
+    }
+
+    /// Doubles integers, taking longer for the smaller ones so that later values are done first
+    #[derive(Clone)]
+    struct Doubler;
+
+    impl Plugin for Doubler {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("doubler").filter().stateless())
+        }
+
+        fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+            let n = input.as_i64()?;
+            thread::sleep(Duration::from_millis(10 * (8 - n as u64)));
+            Ok(vec![ReturnSuccess::value(
+                UntaggedValue::int(n * 2).into_untagged_value(),
+            )])
+        }
+
+        fn worker(&self) -> Option<Box<dyn Plugin + Send>> {
+            Some(Box::new(self.clone()))
+        }
+    }
+
+    #[derive(Default)]
+    struct Collector {
+        collected: Vec<Value>,
+    }
+
+    impl Plugin for Collector {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("collector").sink())
+        }
+
+        fn sink(&mut self, _call_info: CallInfo, input: Vec<Value>) {
+            self.collected = input;
+        }
+    }
+
+    struct Clasher;
+
+    impl Plugin for Clasher {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            let mut signature = Signature::build("clasher").switch("major", "major", Some('M'));
+            signature.named.insert(
+                "massive".to_string(),
+                (NamedType::Switch(Some('M')), "massive".to_string()),
+            );
+            Ok(signature.filter())
+        }
+    }
+
+    struct Documented;
+
+    impl Plugin for Documented {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("documented")
+                .desc("Increment a value")
+                .switch("major", "major", Some('M'))
+                .switch_desc("major", "bump the major version")
+                .switch_desc("minor", "bump the minor version")
+                .example("inc --patch version")
+                .filter())
+        }
+    }
+
+    struct Stepper;
+
+    impl Plugin for Stepper {
+        fn config(&mut self) -> Result<Signature, ShellError> {
+            Ok(Signature::build("stepper")
+                .named_with_default(
+                    "by",
+                    SyntaxShape::Int,
+                    "the step",
+                    Some('b'),
+                    UntaggedValue::string("one").into_untagged_value(),
+                )
+                .filter())
+        }
+    }
+
+    fn call_info() -> CallInfo {
+        CallInfo {
+            args: Default::default(),
+            name_tag: Tag::unknown(),
+            env: Default::default(),
+        }
+    }
+
+    fn line(command: &NuCommand) -> String {
+        format!("{}\n", serde_json::to_string(command).unwrap())
+    }
+
+    #[test]
+    fn emits_accumulated_value_on_end_filter() {
+        let mut plugin = Summer::default();
+
+        let mut input = line(&NuCommand::begin_filter {
+            params: call_info(),
+        });
+        for n in 1..=3 {
+            input.push_str(&line(&NuCommand::filter {
+                params: UntaggedValue::int(n).into_untagged_value(),
+            }));
+        }
+        input.push_str(&line(&NuCommand::end_filter));
+
+        serve_plugin_from(&mut plugin, &mut input.as_bytes(), &mut io::sink());
+
+        assert_eq!(plugin.emitted, Some(6));
+    }
+
+    #[test]
+    fn sink_signature_is_not_a_filter() -> Result<(), ShellError> {
+        assert!(!Collector::default().config()?.is_filter);
+        Ok(())
+    }
+
+    #[test]
+    fn sinks_all_collected_input_at_once() {
+        let mut plugin = Collector::default();
+        let values: Vec<Value> = (1..=3)
+            .map(|n| UntaggedValue::int(n).into_untagged_value())
+            .collect();
+
+        let input = line(&NuCommand::sink {
+            params: (call_info(), values.clone()),
+        });
+
+        serve_plugin_from(&mut plugin, &mut input.as_bytes(), &mut io::sink());
+
+        assert_eq!(plugin.collected, values);
+    }
+
+    #[test]
+    fn sinks_through_the_test_harness() {
+        let mut collector = Collector::default();
+        let values = vec![UntaggedValue::int(1).into_untagged_value()];
+
+        plugin(&mut collector).sink(values.clone());
+
+        assert_eq!(collector.collected, values);
+    }
+
+    #[test]
+    fn filters_binary_frames_as_binary_values() {
+        let bytes = vec![0x00, 0xff, 0x0a, 0x80];
+        let mut input = vec![];
+        write_binary_frame(&mut input, &bytes, Tag::unknown()).expect("a written frame");
+        input.extend(line(&NuCommand::end_filter).into_bytes());
+
+        let mut recorder = Recorder::default();
+        serve_plugin_from(&mut recorder, &mut &input[..], &mut io::sink());
+
+        assert_eq!(
+            recorder.seen,
+            vec![UntaggedValue::binary(bytes).into_untagged_value()]
+        );
+    }
+
+    #[test]
+    fn returns_a_warning_alongside_a_value() {
+        let input = line(&NuCommand::filter {
+            params: UntaggedValue::string("1.2.3").into_untagged_value(),
+        });
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Warner, &mut input.as_bytes(), &mut output);
+
+        let response =
+            serde_json::from_slice::<JsonRpc<Result<Vec<ReturnValue>, ShellError>>>(&output)
+                .expect("a filter response");
+        let returned = response.params.expect("returned values");
+
+        match returned.as_slice() {
+            [Ok(ReturnSuccess::Warning(message)), Ok(ReturnSuccess::Value(value))] => {
+                assert_eq!(message, "version had no prerelease, starting at alpha.1");
+                assert_eq!(value, &UntaggedValue::string("1.2.3").into_untagged_value());
             }
+            other => panic!("expected a warning and a value, got {:?}", other),
         }
     }
+
+    #[test]
+    fn logs_debug_records_apart_from_the_values() {
+        let input = line(&NuCommand::filter {
+            params: UntaggedValue::string("latest").into_untagged_value(),
+        });
+        let mut output = vec![];
+        let mut log = vec![];
+
+        serve_plugin_logging_to(&mut Explainer, &mut input.as_bytes(), &mut output, &mut log);
+
+        let response =
+            serde_json::from_slice::<JsonRpc<Result<Vec<ReturnValue>, ShellError>>>(&output)
+                .expect("a filter response");
+        let returned = response.params.expect("returned values");
+
+        match returned.as_slice() {
+            [Ok(ReturnSuccess::Value(value))] => {
+                assert_eq!(
+                    value,
+                    &UntaggedValue::string("latest").into_untagged_value()
+                );
+            }
+            other => panic!("expected only the value, got {:?}", other),
+        }
+
+        let logged = String::from_utf8(log).expect("utf-8 log");
+        let record = serde_json::from_str::<Value>(logged.trim_end()).expect("a logged record");
+
+        assert_eq!(logged.lines().count(), 1);
+        assert_eq!(
+            record,
+            UntaggedValue::string("not a version, passed through").into_untagged_value()
+        );
+    }
+
+    /// Output that can still be read after the writer moved to another thread
+    #[derive(Clone, Default)]
+    struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().expect("the output").extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn keeps_the_order_of_the_inputs_when_filtering_concurrently() {
+        let mut input = line(&NuCommand::begin_filter {
+            params: call_info(),
+        });
+        for n in 1..=8 {
+            input.push_str(&line(&NuCommand::filter {
+                params: UntaggedValue::int(n).into_untagged_value(),
+            }));
+        }
+        input.push_str(&line(&NuCommand::end_filter));
+        let output = SharedOutput::default();
+
+        serve_plugin_pooled(&mut Doubler, &mut input.as_bytes(), output.clone(), 4);
+
+        let written = output.0.lock().expect("the output").clone();
+        let responses = serde_json::Deserializer::from_slice(&written)
+            .into_iter::<JsonRpc<Result<Vec<ReturnValue>, ShellError>>>()
+            .map(|response| {
+                response
+                    .expect("a response")
+                    .params
+                    .expect("returned values")
+            })
+            .collect::<Vec<_>>();
+
+        let doubled = responses[1..9]
+            .iter()
+            .map(|returned| match returned.as_slice() {
+                [Ok(ReturnSuccess::Value(value))] => value.as_i64().expect("an int"),
+                other => panic!("expected a single value, got {:?}", other),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(responses.len(), 10);
+        assert_eq!(doubled, vec![2, 4, 6, 8, 10, 12, 14, 16]);
+    }
+
+    #[test]
+    fn answers_a_truncated_message_with_an_unexpected_eof() {
+        let input = r#"{"method":"filter","params":{"val"#;
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Quitter::default(), &mut input.as_bytes(), &mut output);
+
+        let response = String::from_utf8(output).expect("utf-8 response");
+        assert!(response.contains("UnexpectedEof"));
+    }
+
+    #[test]
+    fn reports_the_protocol_version_with_the_signature() {
+        let input = line(&NuCommand::config);
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Quitter::default(), &mut input.as_bytes(), &mut output);
+
+        let response = String::from_utf8(output).expect("utf-8 response");
+        assert!(response.contains(&format!(
+            "\"protocol_version\":{}",
+            nu_protocol::PLUGIN_PROTOCOL_VERSION
+        )));
+    }
+
+    #[test]
+    fn usage_and_examples_survive_the_config_round_trip() {
+        let input = line(&NuCommand::config);
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Documented, &mut input.as_bytes(), &mut output);
+
+        let response = serde_json::from_slice::<JsonRpc<Result<Signature, ShellError>>>(&output)
+            .expect("a config response");
+        let signature = response.params.expect("a signature");
+
+        assert_eq!(signature.usage, "Increment a value");
+        assert_eq!(signature.examples, vec!["inc --patch version".to_string()]);
+    }
+
+    #[test]
+    fn switch_descriptions_survive_the_config_round_trip() {
+        let input = line(&NuCommand::config);
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Documented, &mut input.as_bytes(), &mut output);
+
+        let response = serde_json::from_slice::<JsonRpc<Result<Signature, ShellError>>>(&output)
+            .expect("a config response");
+        let signature = response.params.expect("a signature");
+
+        assert_eq!(
+            signature.named.get("major"),
+            Some(&(
+                NamedType::Switch(Some('M')),
+                "bump the major version".to_string()
+            ))
+        );
+        assert_eq!(
+            signature.named.get("minor"),
+            Some(&(
+                NamedType::Switch(None),
+                "bump the minor version".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_signatures_with_colliding_short_flags() {
+        let input = line(&NuCommand::config);
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Clasher, &mut input.as_bytes(), &mut output);
+
+        let response = String::from_utf8(output).expect("utf-8 response");
+        assert!(response.contains("the flags --major and --massive both use the short flag -M"));
+    }
+
+    #[test]
+    fn rejects_signatures_with_defaults_not_fitting_their_flag() {
+        let input = line(&NuCommand::config);
+        let mut output = vec![];
+
+        serve_plugin_from(&mut Stepper, &mut input.as_bytes(), &mut output);
+
+        let response = String::from_utf8(output).expect("utf-8 response");
+        assert!(
+            response.contains("stepper: expected integer for the default of --by, found string")
+        );
+    }
+
+    #[test]
+    fn quits_on_closed_stream() {
+        let mut plugin = Quitter::default();
+
+        serve_plugin_from(&mut plugin, &mut "".as_bytes(), &mut io::sink());
+
+        assert!(plugin.quit);
+    }
+
+    #[test]
+    fn quits_when_stream_closes_after_commands() {
+        let mut plugin = Quitter::default();
+        let input = line(&NuCommand::filter {
+            params: UntaggedValue::nothing().into_untagged_value(),
+        });
+
+        serve_plugin_from(&mut plugin, &mut input.as_bytes(), &mut io::sink());
+
+        assert!(plugin.quit);
+    }
+
+    #[test]
+    fn does_not_panic_on_truncated_stream() {
+        let mut plugin = Quitter::default();
+
+        serve_plugin_from(
+            &mut plugin,
+            &mut "{\"method\":\"begin_fil".as_bytes(),
+            &mut vec![],
+        );
+
+        assert!(!plugin.quit);
+    }
+
+    /// A host that never reads what the plugin writes, until the sender is dropped
+    struct StalledHost(Receiver<()>);
+
+    impl Write for StalledHost {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let _ = self.0.recv();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn quits_within_the_write_timeout_when_the_host_stops_reading() {
+        let mut plugin = Quitter::default();
+        let (_unstall, stalled) = mpsc::channel();
+        let mut writer = TimedWriter::new(StalledHost(stalled), Duration::from_millis(50));
+
+        let mut input = line(&NuCommand::begin_filter {
+            params: call_info(),
+        });
+        for _ in 0..3 {
+            input.push_str(&line(&NuCommand::filter {
+                params: UntaggedValue::nothing().into_untagged_value(),
+            }));
+        }
+        input.push_str(&line(&NuCommand::end_filter));
+
+        let started = Instant::now();
+        serve_plugin_from(&mut plugin, &mut input.as_bytes(), &mut writer);
+
+        assert!(plugin.quit);
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
 }
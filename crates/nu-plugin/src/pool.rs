@@ -0,0 +1,153 @@
+use crate::jsonrpc::{send_response_as, FrameFormat};
+use crate::plugin::{log_debug_records, Plugin};
+use nu_protocol::Value;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// The number of threads filtering values for a stateless plugin
+pub const POOL_SIZE: usize = 4;
+
+/// Hands out sequence numbers to the responses of a session and writes them in that order from
+/// a thread of its own, no matter in which order they are done.
+pub(crate) struct OrderedOutput {
+    format: FrameFormat,
+    next: u64,
+    frames: Sender<(u64, Vec<u8>)>,
+    stalled: Arc<AtomicBool>,
+    writing: JoinHandle<()>,
+}
+
+impl OrderedOutput {
+    pub(crate) fn new<W: Write + Send + 'static>(writer: W, format: FrameFormat) -> OrderedOutput {
+        let (frames, ordered) = mpsc::channel();
+        let stalled = Arc::new(AtomicBool::new(false));
+        let writing = {
+            let stalled = Arc::clone(&stalled);
+            thread::spawn(move || write_in_order(writer, ordered, &stalled))
+        };
+
+        OrderedOutput {
+            format,
+            next: 0,
+            frames,
+            stalled,
+            writing,
+        }
+    }
+
+    /// The sequence number of the next response
+    pub(crate) fn reserve(&mut self) -> u64 {
+        let seq = self.next;
+        self.next += 1;
+        seq
+    }
+
+    /// Queues a response right away, taking the next sequence number
+    pub(crate) fn respond<T: Serialize>(&mut self, result: T) {
+        let seq = self.reserve();
+        let _ = self.frames.send((seq, frame(self.format, result)));
+    }
+
+    /// If the host stopped reading, so that nothing more can be sent to it
+    pub(crate) fn is_stalled(&self) -> bool {
+        self.stalled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until every queued response is written
+    pub(crate) fn finish(self) {
+        drop(self.frames);
+        let _ = self.writing.join();
+    }
+}
+
+/// Worker threads filtering values with copies of a stateless plugin
+pub(crate) struct Pool {
+    jobs: Option<Sender<(u64, Value)>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Pool {
+    /// Starts a worker for each copy of the plugin. Their responses go to `output`.
+    pub(crate) fn start(copies: Vec<Box<dyn Plugin + Send>>, output: &OrderedOutput) -> Pool {
+        let (jobs, queue) = mpsc::channel::<(u64, Value)>();
+        let queue = Arc::new(Mutex::new(queue));
+
+        let workers = copies
+            .into_iter()
+            .map(|mut plugin| {
+                let queue = Arc::clone(&queue);
+                let frames = output.frames.clone();
+                let format = output.format;
+
+                thread::spawn(move || loop {
+                    let job = match queue.lock() {
+                        Ok(queue) => queue.recv(),
+                        Err(_) => break,
+                    };
+                    let (seq, value) = match job {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    };
+
+                    let result = log_debug_records(plugin.filter(value), &mut io::stderr());
+                    if frames.send((seq, frame(format, result))).is_err() {
+                        break;
+                    }
+                })
+            })
+            .collect();
+
+        Pool {
+            jobs: Some(jobs),
+            workers,
+        }
+    }
+
+    /// Queues a value to be filtered by the next free worker
+    pub(crate) fn filter(&self, seq: u64, value: Value) {
+        if let Some(jobs) = &self.jobs {
+            let _ = jobs.send((seq, value));
+        }
+    }
+
+    /// Waits until the workers filtered every queued value
+    pub(crate) fn stop(mut self) {
+        drop(self.jobs.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// A whole response frame, ready to be written
+fn frame<T: Serialize>(format: FrameFormat, result: T) -> Vec<u8> {
+    let mut frame = vec![];
+    let _ = send_response_as(&mut frame, format, result);
+    frame
+}
+
+/// Writes the frames ordered by their sequence numbers, holding back the ones that are done early
+fn write_in_order<W: Write>(mut writer: W, frames: Receiver<(u64, Vec<u8>)>, stalled: &AtomicBool) {
+    let mut pending = BTreeMap::new();
+    let mut next = 0;
+
+    for (seq, frame) in frames {
+        pending.insert(seq, frame);
+
+        while let Some(frame) = pending.remove(&next) {
+            if let Err(err) = writer.write_all(&frame).and_then(|_| writer.flush()) {
+                if err.kind() == io::ErrorKind::TimedOut {
+                    eprintln!("Giving up on the host, which stopped reading: {}", err);
+                }
+                stalled.store(true, Ordering::SeqCst);
+                return;
+            }
+            next += 1;
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub struct PluginTest<'a, T: Plugin> {
     plugin: &'a mut T,
     call_info: CallInfo,
     input: Value,
+    finish: bool,
 }
 
 impl<'a, T: Plugin> PluginTest<'a, T> {
@@ -20,6 +21,7 @@ impl<'a, T: Plugin> PluginTest<'a, T> {
             plugin,
             call_info: CallStub::new().create(),
             input: UntaggedValue::nothing().into_value(Tag::unknown()),
+            finish: false,
         }
     }
 
@@ -42,14 +44,30 @@ impl<'a, T: Plugin> PluginTest<'a, T> {
         self
     }
 
+    pub fn sink(&mut self, input: Vec<Value>) -> &mut PluginTest<'a, T> {
+        self.plugin.sink(self.call_info.clone(), input);
+        self.plugin.quit();
+        self
+    }
+
+    /// Ends the stream after the input, so `test` also returns what `end_filter` emits
+    pub fn finish(&mut self) -> &mut PluginTest<'a, T> {
+        self.finish = true;
+        self
+    }
+
     pub fn test(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
         let return_values = self.plugin.filter(self.input.clone());
         let mut return_values = return_values?;
-        let end = self.plugin.end_filter();
 
-        return_values.extend(end?);
+        if self.finish {
+            let end = self.plugin.end_filter();
+
+            return_values.extend(end?);
+
+            self.plugin.quit();
+        }
 
-        self.plugin.quit();
         Ok(return_values)
     }
 
@@ -80,6 +98,12 @@ impl<'a, T: Plugin> PluginTest<'a, T> {
             }
         });
 
+        // Nu fills in the defaults of the signature before the plugin is called
+        let mut call_stub = call_stub;
+        if let Ok(signature) = self.plugin.config() {
+            call_stub.args.insert_defaults(&signature.defaults);
+        }
+
         let return_values = self.plugin.begin_filter(call_stub);
 
         callback(self.plugin, return_values);
@@ -95,6 +119,7 @@ pub fn plugin<T: Plugin>(plugin: &mut T) -> PluginTest<T> {
 pub struct CallStub {
     positionals: Vec<Value>,
     flags: IndexMap<String, Value>,
+    env: IndexMap<String, String>,
 }
 
 impl CallStub {
@@ -115,6 +140,16 @@ impl CallStub {
         self
     }
 
+    pub fn with_env(&mut self, name: &str, value: &str) -> &mut Self {
+        self.env.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_positional(&mut self, value: Value) -> &mut Self {
+        self.positionals.push(value);
+        self
+    }
+
     pub fn with_parameter(&mut self, name: &str) -> Result<&mut Self, ShellError> {
         let cp = column_path(&name)
             .as_column_path()
@@ -129,6 +164,7 @@ impl CallStub {
         CallInfo {
             args: EvaluatedArgs::new(Some(self.positionals.clone()), Some(self.flags.clone())),
             name_tag: Tag::unknown(),
+            env: self.env.clone(),
         }
     }
 }
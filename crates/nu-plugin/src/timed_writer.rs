@@ -0,0 +1,166 @@
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// The environment variable holding the milliseconds a plugin waits on the host to read a response
+pub const WRITE_TIMEOUT_VAR: &str = "NU_PLUGIN_WRITE_TIMEOUT";
+
+/// The write timeout set through `NU_PLUGIN_WRITE_TIMEOUT`, if any
+pub fn write_timeout_from_env() -> Option<Duration> {
+    std::env::var(WRITE_TIMEOUT_VAR)
+        .ok()
+        .and_then(|millis| millis.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+}
+
+/// A writer that gives up on writes that do not finish in time.
+///
+/// Writes are buffered until `flush`, which hands them to a thread doing the actual writing and
+/// waits at most `timeout` for it. When the other end stops reading, that thread stays blocked
+/// and the flush fails with `io::ErrorKind::TimedOut`, as does every flush after it.
+pub struct TimedWriter {
+    buffer: Vec<u8>,
+    requests: Sender<Vec<u8>>,
+    written: Receiver<io::Result<()>>,
+    timeout: Duration,
+    stalled: bool,
+}
+
+impl TimedWriter {
+    pub fn new<W: Write + Send + 'static>(mut inner: W, timeout: Duration) -> TimedWriter {
+        let (requests, pending) = mpsc::channel::<Vec<u8>>();
+        let (done, written) = mpsc::channel();
+
+        thread::spawn(move || {
+            for bytes in pending {
+                let result = inner.write_all(&bytes).and_then(|_| inner.flush());
+
+                if done.send(result).is_err() {
+                    break;
+                }
+            }
+        });
+
+        TimedWriter {
+            buffer: vec![],
+            requests,
+            written,
+            timeout,
+            stalled: false,
+        }
+    }
+
+    fn timed_out(&self) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("the reader did not keep up for {:?}", self.timeout),
+        )
+    }
+}
+
+impl Write for TimedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.stalled {
+            return Err(self.timed_out());
+        }
+
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let gone = || io::Error::new(io::ErrorKind::BrokenPipe, "the writing thread is gone");
+
+        self.requests
+            .send(std::mem::take(&mut self.buffer))
+            .map_err(|_| gone())?;
+
+        match self.written.recv_timeout(self.timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                self.stalled = true;
+                Err(self.timed_out())
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(gone()),
+        }
+    }
+}
+
+impl Drop for TimedWriter {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimedWriter;
+    use std::io::{self, Write};
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::time::{Duration, Instant};
+
+    /// A reader that never gets around to reading, until the sender is dropped
+    struct Stalled(Receiver<()>);
+
+    impl Write for Stalled {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let _ = self.0.recv();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct Forward(Sender<Vec<u8>>);
+
+    impl Write for Forward {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let _ = self.0.send(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_through_to_a_reader_that_keeps_up() {
+        let (sender, received) = mpsc::channel();
+        let mut writer = TimedWriter::new(Forward(sender), Duration::from_secs(5));
+
+        write!(writer, "ping").expect("buffered");
+        writer.flush().expect("flushed in time");
+
+        assert_eq!(
+            received.recv().expect("the written bytes"),
+            b"ping".to_vec()
+        );
+    }
+
+    #[test]
+    fn gives_up_on_a_stalled_reader() {
+        let (_unstall, stalled) = mpsc::channel();
+        let mut writer = TimedWriter::new(Stalled(stalled), Duration::from_millis(50));
+
+        let started = Instant::now();
+        write!(writer, "ping").expect("buffered");
+        let actual = writer.flush().expect_err("a timed out flush");
+
+        assert_eq!(actual.kind(), io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        write!(writer, "pong").expect("buffered");
+        assert_eq!(
+            writer.flush().expect_err("still stalled").kind(),
+            io::ErrorKind::TimedOut
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::value::Value;
+use crate::type_name::ShellTypeName;
+use crate::value::primitive::Primitive;
+use crate::value::{UntaggedValue, Value};
 use derive_new::new;
 use indexmap::IndexMap;
 use nu_errors::ShellError;
@@ -12,6 +14,23 @@ pub struct CallInfo {
     pub args: EvaluatedArgs,
     /// The tag (underline-able position) of the name of the call itself
     pub name_tag: Tag,
+    /// The environment variables of the shell, filled in by the host for plugins. Empty for calls
+    /// of internal commands, and when talking to hosts that do not send it.
+    #[serde(default)]
+    pub env: IndexMap<String, String>,
+}
+
+impl CallInfo {
+    /// Return true if the switch was given, unless it was explicitly set to false
+    pub fn switch_present(&self, name: &str) -> bool {
+        match self.args.get(name) {
+            Some(value) => !matches!(
+                value.value,
+                UntaggedValue::Primitive(Primitive::Boolean(false))
+            ),
+            None => false,
+        }
+    }
 }
 
 /// The set of positional and named arguments, after their values have been evaluated.
@@ -67,6 +86,19 @@ impl EvaluatedArgs {
         self.len() == 0
     }
 
+    /// Fill in the default value of every named argument that was not given
+    pub fn insert_defaults(&mut self, defaults: &IndexMap<String, Value>) {
+        if defaults.is_empty() {
+            return;
+        }
+
+        let named = self.named.get_or_insert_with(IndexMap::new);
+
+        for (name, default) in defaults {
+            named.entry(name.clone()).or_insert_with(|| default.clone());
+        }
+    }
+
     /// Return true if the set of named arguments contains the name provided
     pub fn has(&self, name: &str) -> bool {
         matches!(&self.named, Some(named) if named.contains_key(name))
@@ -80,6 +112,30 @@ impl EvaluatedArgs {
         }
     }
 
+    /// Gets the named argument given as an integer, erroring if it holds anything else
+    pub fn get_int(&self, name: &str) -> Result<Option<i64>, ShellError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(value) => match &value.value {
+                UntaggedValue::Primitive(primitive @ Primitive::Int(_)) => {
+                    Ok(Some(primitive.as_i64(value.tag.span)?))
+                }
+                _ => Err(mismatched_named("integer", name, value)),
+            },
+        }
+    }
+
+    /// Gets the named argument given as a string, erroring if it holds anything else
+    pub fn get_string(&self, name: &str) -> Result<Option<String>, ShellError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(value) => match &value.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => Ok(Some(s.clone())),
+                _ => Err(mismatched_named("string", name, value)),
+            },
+        }
+    }
+
     /// Iterates over the positional arguments
     pub fn positional_iter(&self) -> PositionalIter<'_> {
         match &self.positional {
@@ -92,6 +148,19 @@ impl EvaluatedArgs {
     }
 }
 
+fn mismatched_named(expected: &str, name: &str, value: &Value) -> ShellError {
+    ShellError::labeled_error(
+        format!(
+            "expected {} for --{}, found {}",
+            expected,
+            name,
+            value.type_name()
+        ),
+        format!("expected {}", expected),
+        value.tag.span,
+    )
+}
+
 /// An iterator to help iterate over positional arguments
 pub enum PositionalIter<'a> {
     Empty,
@@ -109,3 +178,135 @@ impl<'a> Iterator for PositionalIter<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CallInfo, EvaluatedArgs};
+    use crate::value::{UntaggedValue, Value};
+    use indexmap::{indexmap, IndexMap};
+    use nu_source::Tag;
+
+    fn called_with(name: &str, value: Value) -> CallInfo {
+        CallInfo {
+            args: EvaluatedArgs::new(None, Some(indexmap! { name.to_string() => value })),
+            name_tag: Tag::unknown(),
+            env: IndexMap::new(),
+        }
+    }
+
+    #[test]
+    fn gets_an_integer_named_argument() {
+        let call_info = called_with("by", UntaggedValue::int(3).into_untagged_value());
+
+        assert_eq!(call_info.args.get_int("by"), Ok(Some(3)));
+    }
+
+    #[test]
+    fn errors_when_an_integer_named_argument_holds_a_string() {
+        let call_info = called_with("by", UntaggedValue::string("three").into_untagged_value());
+
+        let actual = format!("{:?}", call_info.args.get_int("by").unwrap_err());
+
+        assert!(actual.contains("expected integer for --by, found string"));
+    }
+
+    #[test]
+    fn missing_named_arguments_are_none() {
+        let call_info = called_with("field", UntaggedValue::string("name").into_untagged_value());
+
+        assert_eq!(call_info.args.get_int("by"), Ok(None));
+        assert_eq!(call_info.args.get_string("by"), Ok(None));
+    }
+
+    #[test]
+    fn gets_a_string_named_argument() {
+        let call_info = called_with("field", UntaggedValue::string("name").into_untagged_value());
+
+        assert_eq!(
+            call_info.args.get_string("field"),
+            Ok(Some("name".to_string()))
+        );
+    }
+
+    #[test]
+    fn errors_when_a_string_named_argument_holds_an_integer() {
+        let call_info = called_with("field", UntaggedValue::int(3).into_untagged_value());
+
+        let actual = format!("{:?}", call_info.args.get_string("field").unwrap_err());
+
+        assert!(actual.contains("expected string for --field, found integer"));
+    }
+
+    #[test]
+    fn switch_is_present_when_given() {
+        let call_info = called_with("major", UntaggedValue::boolean(true).into_untagged_value());
+
+        assert!(call_info.switch_present("major"));
+    }
+
+    #[test]
+    fn switch_is_not_present_when_set_to_false() {
+        let call_info = called_with("major", UntaggedValue::boolean(false).into_untagged_value());
+
+        assert!(!call_info.switch_present("major"));
+    }
+
+    #[test]
+    fn switch_is_not_present_when_absent() {
+        let call_info = called_with("minor", UntaggedValue::boolean(true).into_untagged_value());
+
+        assert!(!call_info.switch_present("major"));
+    }
+
+    #[test]
+    fn carries_the_environment_given_by_the_host() {
+        let mut call_info = called_with("by", UntaggedValue::int(3).into_untagged_value());
+        call_info
+            .env
+            .insert("REGISTRY".to_string(), "crates.io".to_string());
+
+        let serialized = serde_json::to_string(&call_info).expect("a serialized call");
+        let call_info = serde_json::from_str::<CallInfo>(&serialized).expect("a call");
+
+        assert_eq!(
+            call_info.env.get("REGISTRY").map(String::as_str),
+            Some("crates.io")
+        );
+    }
+
+    #[test]
+    fn has_an_empty_environment_when_the_host_sends_none() {
+        let mut call_info = called_with("by", UntaggedValue::int(3).into_untagged_value());
+        call_info
+            .env
+            .insert("REGISTRY".to_string(), "crates.io".to_string());
+
+        let mut serialized = serde_json::to_value(&call_info).expect("a serialized call");
+        serialized.as_object_mut().expect("an object").remove("env");
+        let call_info = serde_json::from_value::<CallInfo>(serialized).expect("a call");
+
+        assert!(call_info.env.is_empty());
+    }
+
+    #[test]
+    fn fills_in_defaults_of_absent_named_arguments() {
+        let mut args = EvaluatedArgs::new(None, None);
+
+        args.insert_defaults(&indexmap! {
+            "by".to_string() => UntaggedValue::int(1).into_untagged_value(),
+        });
+
+        assert_eq!(args.get_int("by"), Ok(Some(1)));
+    }
+
+    #[test]
+    fn given_named_arguments_override_defaults() {
+        let mut call_info = called_with("by", UntaggedValue::int(5).into_untagged_value());
+
+        call_info.args.insert_defaults(&indexmap! {
+            "by".to_string() => UntaggedValue::int(1).into_untagged_value(),
+        });
+
+        assert_eq!(call_info.args.get_int("by"), Ok(Some(5)));
+    }
+}
@@ -14,7 +14,7 @@ pub mod value;
 pub use crate::call_info::{CallInfo, EvaluatedArgs};
 pub use crate::maybe_owned::MaybeOwned;
 pub use crate::return_value::{CommandAction, ReturnSuccess, ReturnValue};
-pub use crate::signature::{NamedType, PositionalType, Signature};
+pub use crate::signature::{NamedType, PositionalType, Signature, PLUGIN_PROTOCOL_VERSION};
 pub use crate::syntax_shape::SyntaxShape;
 pub use crate::type_name::{PrettyType, ShellTypeName, SpannedTypeName};
 pub use crate::type_shape::{Row as RowType, Type};
@@ -22,7 +22,10 @@ pub use crate::value::column_path::{ColumnPath, PathMember, UnspannedPathMember}
 pub use crate::value::dict::{Dictionary, TaggedDictBuilder};
 pub use crate::value::did_you_mean::did_you_mean;
 pub use crate::value::primitive::Primitive;
-pub use crate::value::primitive::{format_date, format_duration, format_primitive};
+pub use crate::value::primitive::{
+    format_date, format_duration, format_filesize, format_primitive, FilesizeUnits,
+};
 pub use crate::value::range::{Range, RangeInclusion};
+pub use crate::value::render::{JsonRender, PlainRender, ValueRender};
 pub use crate::value::value_structure::{ValueResource, ValueStructure};
 pub use crate::value::{merge_descriptors, UntaggedValue, Value};
@@ -64,6 +64,10 @@ pub enum ReturnSuccess {
     DebugValue(Value),
     /// An action to be performed as values pass out of the command. These are performed rather than passed to the next command in the pipeline
     Action(CommandAction),
+    /// A non-fatal note for the user. It is shown on stderr and the stream keeps flowing
+    Warning(String),
+    /// A record for diagnosing the command, which goes to the logs and never into the stream
+    DebugRecord(Value),
 }
 
 impl PrettyDebug for ReturnSuccess {
@@ -73,6 +77,12 @@ impl PrettyDebug for ReturnSuccess {
             ReturnSuccess::Value(value) => DbgDocBldr::typed("value", value.pretty()),
             ReturnSuccess::DebugValue(value) => DbgDocBldr::typed("debug value", value.pretty()),
             ReturnSuccess::Action(action) => DbgDocBldr::typed("action", action.pretty()),
+            ReturnSuccess::Warning(message) => {
+                DbgDocBldr::typed("warning", DbgDocBldr::description(message))
+            }
+            ReturnSuccess::DebugRecord(record) => {
+                DbgDocBldr::typed("debug record", record.pretty())
+            }
         }
     }
 }
@@ -92,7 +102,9 @@ impl ReturnSuccess {
         match self {
             ReturnSuccess::Value(raw) => Some(raw.clone()),
             ReturnSuccess::DebugValue(raw) => Some(raw.clone()),
-            ReturnSuccess::Action(_) => None,
+            ReturnSuccess::Action(_)
+            | ReturnSuccess::Warning(_)
+            | ReturnSuccess::DebugRecord(_) => None,
         }
     }
 
@@ -111,6 +123,16 @@ impl ReturnSuccess {
         Ok(ReturnSuccess::DebugValue(input.into()))
     }
 
+    /// Helper function to create warnings for returning alongside values
+    pub fn warning(message: impl Into<String>) -> ReturnValue {
+        Ok(ReturnSuccess::Warning(message.into()))
+    }
+
+    /// Helper function to create debug records for returning alongside values
+    pub fn debug_record(input: impl Into<Value>) -> ReturnValue {
+        Ok(ReturnSuccess::DebugRecord(input.into()))
+    }
+
     /// Helper function for creating actions
     pub fn action(input: CommandAction) -> ReturnValue {
         Ok(ReturnSuccess::Action(input))
@@ -1,6 +1,10 @@
 use crate::syntax_shape::SyntaxShape;
+use crate::type_name::ShellTypeName;
 use crate::type_shape::Type;
+use crate::value::primitive::Primitive;
+use crate::value::{UntaggedValue, Value};
 use indexmap::IndexMap;
+use nu_errors::ShellError;
 use nu_source::{DbgDocBldr, DebugDocBuilder, PrettyDebug, PrettyDebugWithSource};
 use serde::{Deserialize, Serialize};
 
@@ -100,6 +104,10 @@ impl PositionalType {
 
 type Description = String;
 
+/// The version of the plugin protocol spoken by this build. Bump it whenever a change to the messages
+/// exchanged between Nu and its plugins would make an older plugin misbehave.
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 1;
+
 /// The full signature of a command. All commands have a signature similar to a function signature.
 /// Commands will use this information to register themselves with Nu's core engine so that the command
 /// can be invoked, help can be displayed, and calls to the command can be error-checked.
@@ -121,6 +129,22 @@ pub struct Signature {
     pub input: Option<Type>,
     /// If the command is expected to filter data, or to consume it (as a sink)
     pub is_filter: bool,
+    /// Groups of flags of which at most one may be given in the same call
+    #[serde(default)]
+    pub exclusive_groups: Vec<Vec<String>>,
+    /// The values optional named flags take when they are not given
+    #[serde(default)]
+    pub defaults: IndexMap<String, Value>,
+    /// Example invocations of the command, shown by the help system
+    #[serde(default)]
+    pub examples: Vec<String>,
+    /// The plugin protocol version the command was built against. Only plugins report one
+    #[serde(default)]
+    pub protocol_version: Option<u32>,
+    /// If the command filters every value independently of the others, so that values can be
+    /// filtered concurrently
+    #[serde(default)]
+    pub is_stateless: bool,
 }
 
 impl PartialEq for Signature {
@@ -196,6 +220,11 @@ impl Signature {
             rest_positional: None,
             named: indexmap::indexmap! {"help".into() => (NamedType::Switch(Some('h')), "Display this help message".into())},
             is_filter: false,
+            exclusive_groups: vec![],
+            defaults: IndexMap::new(),
+            examples: vec![],
+            protocol_version: None,
+            is_stateless: false,
             yields: None,
             input: None,
         }
@@ -212,6 +241,12 @@ impl Signature {
         self
     }
 
+    /// Add an example invocation to the signature
+    pub fn example(mut self, example: impl Into<String>) -> Signature {
+        self.examples.push(example.into());
+        self
+    }
+
     /// Add a required positional argument to the signature
     pub fn required(
         mut self,
@@ -262,6 +297,22 @@ impl Signature {
         self
     }
 
+    /// Add an optional named flag argument to the signature that takes a default value when it is not given
+    pub fn named_with_default(
+        self,
+        name: impl Into<String>,
+        ty: impl Into<SyntaxShape>,
+        desc: impl Into<String>,
+        short: Option<char>,
+        default: Value,
+    ) -> Signature {
+        let name = name.into();
+
+        let mut signature = self.named(name.clone(), ty, desc, short);
+        signature.defaults.insert(name, default);
+        signature
+    }
+
     /// Add a required named flag argument to the signature
     pub fn required_named(
         mut self,
@@ -303,12 +354,47 @@ impl Signature {
         self
     }
 
+    /// Describe a switch, replacing the description it was declared with. A switch that is not
+    /// declared yet is added without a short flag
+    pub fn switch_desc(mut self, name: impl Into<String>, desc: impl Into<String>) -> Signature {
+        let name = name.into();
+        let desc = desc.into();
+
+        match self.named.get_mut(&name) {
+            Some((_, described)) => *described = desc,
+            None => {
+                self.named.insert(name, (NamedType::Switch(None), desc));
+            }
+        }
+
+        self
+    }
+
+    /// Declare a group of flags that can not be used together
+    pub fn exclusive_group(mut self, flags: &[&str]) -> Signature {
+        self.exclusive_groups
+            .push(flags.iter().map(|flag| flag.to_string()).collect());
+        self
+    }
+
     /// Set the filter flag for the signature
     pub fn filter(mut self) -> Signature {
         self.is_filter = true;
         self
     }
 
+    /// Mark the filter as stateless: filtering a value does not depend on the values before it
+    pub fn stateless(mut self) -> Signature {
+        self.is_stateless = true;
+        self
+    }
+
+    /// Mark the signature as a sink, which consumes all of its input at once and yields nothing downstream
+    pub fn sink(mut self) -> Signature {
+        self.is_filter = false;
+        self
+    }
+
     /// Set the type for the "rest" of the positional arguments
     /// Note: Not naming the field in your struct holding the rest values "rest", can
     /// cause errors when deserializing
@@ -339,4 +425,168 @@ impl Signature {
         }
         shorts
     }
+
+    /// Checks that a plugin signature was built against the protocol version Nu speaks.
+    /// Signatures that do not report a version, such as those of script plugins, are accepted
+    pub fn check_protocol_version(&self) -> Result<(), ShellError> {
+        match self.protocol_version {
+            Some(version) if version != PLUGIN_PROTOCOL_VERSION => {
+                Err(ShellError::untagged_runtime_error(format!(
+                    "{}: plugin speaks protocol version {}, but Nu expects version {}",
+                    self.name, version, PLUGIN_PROTOCOL_VERSION
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that no two flags of the signature share a short flag and that every default
+    /// could have been given for its flag
+    pub fn validate(&self) -> Result<(), ShellError> {
+        let mut seen: IndexMap<char, &str> = IndexMap::new();
+
+        for (name, (t, _)) in &self.named {
+            if let Some(c) = t.get_short() {
+                if let Some(other) = seen.insert(c, name) {
+                    return Err(ShellError::untagged_runtime_error(format!(
+                        "{}: the flags --{} and --{} both use the short flag -{}",
+                        self.name, other, name, c
+                    )));
+                }
+            }
+        }
+
+        for (name, default) in &self.defaults {
+            let shape = match self.named.get(name) {
+                Some((NamedType::Mandatory(_, shape), _))
+                | Some((NamedType::Optional(_, shape), _)) => *shape,
+                _ => {
+                    return Err(ShellError::untagged_runtime_error(format!(
+                        "{}: there is a default for --{}, but no flag takes a value by that name",
+                        self.name, name
+                    )))
+                }
+            };
+
+            if !default_fits_shape(default, shape) {
+                return Err(ShellError::untagged_runtime_error(format!(
+                    "{}: expected {} for the default of --{}, found {}",
+                    self.name,
+                    shape.display(),
+                    name,
+                    default.type_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that a default value could have been given for a flag of the given shape
+fn default_fits_shape(value: &Value, shape: SyntaxShape) -> bool {
+    match (shape, &value.value) {
+        (SyntaxShape::Any, _) => true,
+        (SyntaxShape::Int, UntaggedValue::Primitive(Primitive::Int(_))) => true,
+        (SyntaxShape::Number, UntaggedValue::Primitive(Primitive::Int(_)))
+        | (SyntaxShape::Number, UntaggedValue::Primitive(Primitive::Decimal(_))) => true,
+        (SyntaxShape::String, UntaggedValue::Primitive(Primitive::String(_))) => true,
+        (SyntaxShape::FilePath, UntaggedValue::Primitive(Primitive::FilePath(_))) => true,
+        (SyntaxShape::ColumnPath, UntaggedValue::Primitive(Primitive::ColumnPath(_))) => true,
+        (SyntaxShape::Range, UntaggedValue::Primitive(Primitive::Range(_))) => true,
+        (SyntaxShape::Unit, UntaggedValue::Primitive(Primitive::Filesize(_)))
+        | (SyntaxShape::Unit, UntaggedValue::Primitive(Primitive::Duration(_))) => true,
+        (SyntaxShape::Table, UntaggedValue::Table(_)) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NamedType, Signature, PLUGIN_PROTOCOL_VERSION};
+    use crate::syntax_shape::SyntaxShape;
+    use crate::value::UntaggedValue;
+
+    fn speaking(version: Option<u32>) -> Signature {
+        let mut signature = Signature::build("plugin");
+        signature.protocol_version = version;
+        signature
+    }
+
+    #[test]
+    fn matching_protocol_version_is_accepted() {
+        assert!(speaking(Some(PLUGIN_PROTOCOL_VERSION))
+            .check_protocol_version()
+            .is_ok());
+    }
+
+    #[test]
+    fn mismatched_protocol_version_is_rejected() {
+        let actual = speaking(Some(PLUGIN_PROTOCOL_VERSION + 1))
+            .check_protocol_version()
+            .unwrap_err();
+
+        assert!(format!("{:?}", actual).contains("plugin speaks protocol version"));
+    }
+
+    #[test]
+    fn unversioned_signatures_are_accepted() {
+        assert!(speaking(None).check_protocol_version().is_ok());
+    }
+
+    #[test]
+    fn switch_desc_replaces_the_description_of_a_declared_switch() {
+        let signature = Signature::build("inc")
+            .switch("major", "major", Some('M'))
+            .switch_desc("major", "bump the major version");
+
+        assert_eq!(
+            signature.named.get("major"),
+            Some(&(
+                NamedType::Switch(Some('M')),
+                "bump the major version".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn switch_desc_declares_a_missing_switch() {
+        let signature = Signature::build("inc").switch_desc("minor", "bump the minor version");
+
+        assert_eq!(
+            signature.named.get("minor"),
+            Some(&(
+                NamedType::Switch(None),
+                "bump the minor version".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn defaults_matching_the_shape_of_their_flag_are_accepted() {
+        let signature = Signature::build("inc").named_with_default(
+            "by",
+            SyntaxShape::Int,
+            "the step",
+            Some('b'),
+            UntaggedValue::int(1).into_untagged_value(),
+        );
+
+        assert!(signature.validate().is_ok());
+    }
+
+    #[test]
+    fn defaults_not_matching_the_shape_of_their_flag_are_rejected() {
+        let signature = Signature::build("inc").named_with_default(
+            "by",
+            SyntaxShape::Int,
+            "the step",
+            Some('b'),
+            UntaggedValue::string("one").into_untagged_value(),
+        );
+
+        let actual = format!("{:?}", signature.validate().unwrap_err());
+
+        assert!(actual.contains("inc: expected integer for the default of --by, found string"));
+    }
 }
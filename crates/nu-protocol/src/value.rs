@@ -6,6 +6,7 @@ pub mod did_you_mean;
 pub mod iter;
 pub mod primitive;
 pub mod range;
+pub mod render;
 mod serde_bigdecimal;
 mod serde_bigint;
 pub mod value_structure;
@@ -16,6 +17,7 @@ use crate::value::dict::Dictionary;
 use crate::value::iter::{RowValueIter, TableValueIter};
 use crate::value::primitive::Primitive;
 use crate::value::range::{Range, RangeInclusion};
+use crate::value::render::ValueRender;
 use crate::ColumnPath;
 use bigdecimal::BigDecimal;
 use bigdecimal::FromPrimitive;
@@ -26,6 +28,7 @@ use nu_source::{AnchorLocation, HasSpan, Span, Spanned, SpannedItem, Tag};
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -241,6 +244,53 @@ impl UntaggedValue {
         UntaggedValue::Primitive(Primitive::Duration(nanos.into()))
     }
 
+    /// Helper for creating date duration values from strings of amounts and units (eg. 90sec, 1hr30min)
+    pub fn duration_from_str(s: &str, span: Span) -> Result<UntaggedValue, ShellError> {
+        let error = || {
+            ShellError::labeled_error(
+                format!("Could not parse '{}' as a duration", s),
+                "expected a duration (eg. 90sec or 1hr30min)",
+                span,
+            )
+        };
+
+        let mut rest = s.trim();
+        let mut nanos = BigInt::from(0);
+
+        if rest.is_empty() {
+            return Err(error());
+        }
+
+        while !rest.is_empty() {
+            let unit_start = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let unit_end = rest[unit_start..]
+                .find(|c: char| c.is_ascii_digit())
+                .map(|idx| unit_start + idx)
+                .unwrap_or(rest.len());
+
+            let amount = rest[..unit_start].parse::<BigInt>().map_err(|_| error())?;
+
+            let nanos_per_unit: i64 = match &rest[unit_start..unit_end] {
+                "ns" => 1,
+                "us" => 1000,
+                "ms" => 1000 * 1000,
+                "s" | "sec" => 1000 * 1000 * 1000,
+                "min" => 60 * 1000 * 1000 * 1000,
+                "hr" => 60 * 60 * 1000 * 1000 * 1000,
+                "day" => 24 * 60 * 60 * 1000 * 1000 * 1000,
+                "wk" => 7 * 24 * 60 * 60 * 1000 * 1000 * 1000,
+                _ => return Err(error()),
+            };
+
+            nanos += amount * nanos_per_unit;
+            rest = &rest[unit_end..];
+        }
+
+        Ok(UntaggedValue::duration(nanos))
+    }
+
     /// Helper for creating datatime values
     pub fn system_date(s: SystemTime) -> UntaggedValue {
         let utc: DateTime<Utc> = s.into();
@@ -251,6 +301,18 @@ impl UntaggedValue {
         UntaggedValue::Primitive(Primitive::Date(d.into()))
     }
 
+    /// Helper for creating date values from RFC 3339 strings (eg. 2020-04-16T09:15:00+00:00)
+    pub fn date_from_str(s: &str, span: Span) -> Result<UntaggedValue, ShellError> {
+        match DateTime::parse_from_rfc3339(s) {
+            Ok(date) => Ok(UntaggedValue::date(date)),
+            Err(reason) => Err(ShellError::labeled_error(
+                format!("Could not parse '{}' as a date", s),
+                format!("expected an RFC 3339 date ({})", reason),
+                span,
+            )),
+        }
+    }
+
     /// Helper for creating the Nothing value
     pub fn nothing() -> UntaggedValue {
         UntaggedValue::Primitive(Primitive::Nothing)
@@ -362,6 +424,11 @@ impl Value {
         }
     }
 
+    /// Render the value as text with the given renderer
+    pub fn render(&self, renderer: &dyn ValueRender) -> String {
+        renderer.render(self)
+    }
+
     pub fn convert_to_string(&self) -> String {
         match &self.value {
             UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
@@ -445,6 +512,106 @@ impl Value {
         }
     }
 
+    /// Order two values the way a user would expect: numbers numerically (ints and decimals mix),
+    /// filesizes by their bytes (also against plain numbers), strings lexically, and rows key by
+    /// key. Values of types that can not be ordered against each other return None
+    pub fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (&self.value, &other.value) {
+            (UntaggedValue::Primitive(left), UntaggedValue::Primitive(right)) => {
+                compare_primitives(left, right)
+            }
+            (UntaggedValue::Row(left), UntaggedValue::Row(right)) => {
+                for ((left_key, left_value), (right_key, right_value)) in
+                    left.entries.iter().zip(right.entries.iter())
+                {
+                    match left_key.cmp(right_key) {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+
+                    match left_value.compare(right_value)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+
+                Some(left.entries.len().cmp(&right.entries.len()))
+            }
+            (UntaggedValue::Table(left), UntaggedValue::Table(right)) => {
+                for (left_value, right_value) in left.iter().zip(right.iter()) {
+                    match left_value.compare(right_value)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+
+                Some(left.len().cmp(&right.len()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Deep-merge the overlay onto this value. Rows are merged key by key, keeping the order of
+    /// the keys here and adding the overlay's new keys after them. Tables are concatenated when
+    /// `concat_tables` is set, and replaced by the overlay otherwise. Everything else, including
+    /// values of different types, takes the overlay.
+    pub fn merge(&self, overlay: &Value, concat_tables: bool) -> Value {
+        match (&self.value, &overlay.value) {
+            (UntaggedValue::Row(base), UntaggedValue::Row(over)) => {
+                let mut entries = base.entries.clone();
+
+                for (key, value) in over.entries.iter() {
+                    let merged = match entries.get(key) {
+                        Some(existing) => existing.merge(value, concat_tables),
+                        None => value.clone(),
+                    };
+                    entries.insert(key.clone(), merged);
+                }
+
+                UntaggedValue::row(entries).into_value(&self.tag)
+            }
+            (UntaggedValue::Table(base), UntaggedValue::Table(over)) if concat_tables => {
+                UntaggedValue::Table(base.iter().chain(over.iter()).cloned().collect())
+                    .into_value(&self.tag)
+            }
+            _ => overlay.clone(),
+        }
+    }
+
+    /// Returns true if the Value is a number, a filesize, or a string that parses as a number
+    pub fn is_numeric(&self) -> bool {
+        match &self.value {
+            UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Filesize(_)) => true,
+            UntaggedValue::Primitive(Primitive::String(s)) => {
+                s.trim().parse::<BigDecimal>().is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Coerce the Value into a signed 64-bit integer. Filesizes count their bytes, and decimals
+    /// as well as numeric strings are truncated towards zero
+    pub fn coerce_to_int(&self) -> Result<i64, ShellError> {
+        match &self.value {
+            UntaggedValue::Primitive(Primitive::Filesize(size)) => {
+                Primitive::Int(size.clone()).as_i64(self.tag.span)
+            }
+            UntaggedValue::Primitive(Primitive::String(s)) => {
+                match s.trim().parse::<BigDecimal>() {
+                    Ok(decimal) => Primitive::Decimal(decimal).as_i64(self.tag.span),
+                    Err(_) => Err(ShellError::labeled_error(
+                        format!("'{}' is not a number", s),
+                        "expected a number",
+                        self.tag.span,
+                    )),
+                }
+            }
+            _ => self.as_i64(),
+        }
+    }
+
     /// View the Value as boolean, if possible
     pub fn as_bool(&self) -> Result<bool, ShellError> {
         match &self.value {
@@ -574,6 +741,35 @@ impl HasSpan for Value {
     }
 }
 
+fn compare_primitives(left: &Primitive, right: &Primitive) -> Option<Ordering> {
+    match (left, right) {
+        (Primitive::Int(left), Primitive::Int(right)) => Some(left.cmp(right)),
+        (Primitive::Int(left), Primitive::Decimal(right)) => {
+            Some(BigDecimal::new(left.clone(), 0).cmp(right))
+        }
+        (Primitive::Decimal(left), Primitive::Int(right)) => {
+            Some(left.cmp(&BigDecimal::new(right.clone(), 0)))
+        }
+        (Primitive::Decimal(left), Primitive::Decimal(right)) => Some(left.cmp(right)),
+        (Primitive::Filesize(left), Primitive::Filesize(right)) => Some(left.cmp(right)),
+        (Primitive::Filesize(left), Primitive::Int(right)) => Some(left.cmp(right)),
+        (Primitive::Int(left), Primitive::Filesize(right)) => Some(left.cmp(right)),
+        (Primitive::Filesize(left), Primitive::Decimal(right)) => {
+            Some(BigDecimal::new(left.clone(), 0).cmp(right))
+        }
+        (Primitive::Decimal(left), Primitive::Filesize(right)) => {
+            Some(left.cmp(&BigDecimal::new(right.clone(), 0)))
+        }
+        (Primitive::Duration(left), Primitive::Duration(right)) => Some(left.cmp(right)),
+        (Primitive::String(left), Primitive::String(right)) => Some(left.cmp(right)),
+        (Primitive::Boolean(left), Primitive::Boolean(right)) => Some(left.cmp(right)),
+        (Primitive::Date(left), Primitive::Date(right)) => Some(left.cmp(right)),
+        (Primitive::FilePath(left), Primitive::FilePath(right)) => Some(left.cmp(right)),
+        (Primitive::Nothing, Primitive::Nothing) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
 impl ShellTypeName for Value {
     /// Get the type name for the Value
     fn type_name(&self) -> &'static str {
@@ -964,6 +1160,147 @@ mod tests {
     use super::*;
     use indexmap::indexmap;
 
+    fn int(i: i64) -> Value {
+        UntaggedValue::int(i).into_untagged_value()
+    }
+
+    fn string(s: &str) -> Value {
+        UntaggedValue::string(s).into_untagged_value()
+    }
+
+    #[test]
+    fn compares_ints_numerically() {
+        assert_eq!(int(2).compare(&int(10)), Some(Ordering::Less));
+        assert_eq!(int(10).compare(&int(10)), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn compares_ints_against_decimals() {
+        let decimal = UntaggedValue::decimal_from_float(2.5, Span::unknown()).into_untagged_value();
+
+        assert_eq!(int(3).compare(&decimal), Some(Ordering::Greater));
+        assert_eq!(decimal.compare(&int(2)), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn compares_filesizes_by_their_bytes() {
+        let small = UntaggedValue::filesize(512).into_untagged_value();
+        let large = UntaggedValue::filesize(2048).into_untagged_value();
+
+        assert_eq!(small.compare(&large), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn compares_strings_lexically() {
+        assert_eq!(string("10").compare(&string("9")), Some(Ordering::Less));
+        assert_eq!(string("b").compare(&string("a")), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn compares_booleans() {
+        let no = UntaggedValue::boolean(false).into_untagged_value();
+        let yes = UntaggedValue::boolean(true).into_untagged_value();
+
+        assert_eq!(no.compare(&yes), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn compares_rows_key_by_key() {
+        let older = UntaggedValue::row(indexmap! {
+            "name".into() => string("nu"),
+            "version".into() => int(26),
+        })
+        .into_untagged_value();
+        let newer = UntaggedValue::row(indexmap! {
+            "name".into() => string("nu"),
+            "version".into() => int(27),
+        })
+        .into_untagged_value();
+
+        assert_eq!(older.compare(&newer), Some(Ordering::Less));
+        assert_eq!(newer.compare(&newer.clone()), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn compares_filesizes_against_numbers() {
+        let size = UntaggedValue::filesize(2048).into_untagged_value();
+        let decimal =
+            UntaggedValue::decimal_from_float(2048.5, Span::unknown()).into_untagged_value();
+
+        assert_eq!(size.compare(&int(1024)), Some(Ordering::Greater));
+        assert_eq!(int(2048).compare(&size), Some(Ordering::Equal));
+        assert_eq!(size.compare(&decimal), Some(Ordering::Less));
+        assert_eq!(decimal.compare(&size), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn values_of_different_types_are_incomparable() {
+        let size = UntaggedValue::filesize(5).into_untagged_value();
+
+        assert_eq!(int(5).compare(&string("5")), None);
+        assert_eq!(size.compare(&string("5")), None);
+    }
+
+    #[test]
+    fn ints_filesizes_and_numeric_strings_are_numeric() {
+        assert!(UntaggedValue::int(5).into_untagged_value().is_numeric());
+        assert!(UntaggedValue::filesize(1024)
+            .into_untagged_value()
+            .is_numeric());
+        assert!(UntaggedValue::string("12")
+            .into_untagged_value()
+            .is_numeric());
+        assert!(UntaggedValue::string("3.75")
+            .into_untagged_value()
+            .is_numeric());
+        assert!(!UntaggedValue::string("abc")
+            .into_untagged_value()
+            .is_numeric());
+        assert!(!UntaggedValue::boolean(true)
+            .into_untagged_value()
+            .is_numeric());
+    }
+
+    #[test]
+    fn coerces_ints_and_filesizes_to_ints() {
+        assert_eq!(
+            UntaggedValue::int(5).into_untagged_value().coerce_to_int(),
+            Ok(5)
+        );
+        assert_eq!(
+            UntaggedValue::filesize(1024)
+                .into_untagged_value()
+                .coerce_to_int(),
+            Ok(1024)
+        );
+    }
+
+    #[test]
+    fn coerces_numeric_strings_to_ints() {
+        assert_eq!(
+            UntaggedValue::string("12")
+                .into_untagged_value()
+                .coerce_to_int(),
+            Ok(12)
+        );
+        assert_eq!(
+            UntaggedValue::string(" 3.75 ")
+                .into_untagged_value()
+                .coerce_to_int(),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn non_numeric_strings_do_not_coerce_to_ints() {
+        let actual = UntaggedValue::string("abc")
+            .into_untagged_value()
+            .coerce_to_int()
+            .unwrap_err();
+
+        assert!(format!("{:?}", actual).contains("'abc' is not a number"));
+    }
+
     #[test]
     fn test_merge_descriptors() {
         let value = vec![
@@ -1044,6 +1381,105 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_date_from_str() {
+        let expected = DateTime::parse_from_rfc3339("2020-04-16T09:15:00+02:00").unwrap();
+
+        assert_eq!(
+            UntaggedValue::date_from_str("2020-04-16T09:15:00+02:00", Span::default()),
+            Ok(UntaggedValue::date(expected))
+        );
+    }
+
+    #[test]
+    fn test_date_from_str_rejects_non_dates() {
+        let actual = UntaggedValue::date_from_str("last tuesday", Span::new(0, 12));
+
+        assert!(format!("{:?}", actual.unwrap_err()).contains("Could not parse 'last tuesday'"));
+    }
+
+    #[test]
+    fn test_date_round_trips_through_serialization() {
+        let date = UntaggedValue::date_from_str("2020-04-16T09:15:00Z", Span::default())
+            .unwrap()
+            .into_untagged_value();
+
+        let serialized = serde_json::to_string(&date).unwrap();
+        let deserialized: Value = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, date);
+    }
+
+    #[test]
+    fn test_duration_from_str() {
+        let second: i64 = 1000 * 1000 * 1000;
+
+        assert_eq!(
+            UntaggedValue::duration_from_str("90s", Span::default()),
+            Ok(UntaggedValue::duration(90 * second))
+        );
+        assert_eq!(
+            UntaggedValue::duration_from_str("1hr30min", Span::default()),
+            Ok(UntaggedValue::duration(90 * 60 * second))
+        );
+    }
+
+    #[test]
+    fn test_duration_from_str_rejects_unknown_units() {
+        for input in &["", "hr", "5parsecs", "1hr30"] {
+            assert!(UntaggedValue::duration_from_str(input, Span::default()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_duration_display() {
+        let duration = UntaggedValue::duration_from_str("1hr30min", Span::default()).unwrap();
+
+        match duration {
+            UntaggedValue::Primitive(primitive) => {
+                assert_eq!(primitive::format_primitive(&primitive, None), "1hr 30min")
+            }
+            _ => panic!("expected a duration"),
+        }
+    }
+
+    #[test]
+    fn test_filesize_display_in_binary_units() {
+        let format = |bytes: u64| {
+            primitive::format_filesize(&bytes.into(), primitive::FilesizeUnits::Binary)
+        };
+
+        assert_eq!(format(1024), "1.0 KB");
+        assert_eq!(format(1000), "1000 B");
+        assert_eq!(format(3 * 1024 * 1024 / 2), "1.5 MB");
+    }
+
+    #[test]
+    fn test_filesize_display_in_decimal_units() {
+        let format = |bytes: u64| {
+            primitive::format_filesize(&bytes.into(), primitive::FilesizeUnits::Decimal)
+        };
+
+        assert_eq!(format(1000), "1.0 KB");
+        assert_eq!(format(999), "999 B");
+        assert_eq!(format(2_500_000_000), "2.5 GB");
+    }
+
+    #[test]
+    fn test_duration_adds_to_date() {
+        let date = DateTime::parse_from_rfc3339("2020-04-16T09:15:00+00:00").unwrap();
+        let expected = DateTime::parse_from_rfc3339("2020-04-16T10:45:00+00:00").unwrap();
+
+        let duration = match UntaggedValue::duration_from_str("1hr30min", Span::default()) {
+            Ok(UntaggedValue::Primitive(primitive)) => {
+                Primitive::into_chrono_duration(primitive, Span::default()).unwrap()
+            }
+            _ => panic!("expected a duration"),
+        };
+
+        assert_eq!(date + duration, expected);
+    }
+
     #[test]
     fn test_string_to_string_untagged_value_extension() {
         assert_eq!(
@@ -1113,4 +1549,77 @@ mod tests {
             UntaggedValue::from("a_str".to_string())
         );
     }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn table(list: &[Value]) -> Value {
+        UntaggedValue::table(list).into_untagged_value()
+    }
+
+    fn manifest() -> Value {
+        row(indexmap! {
+            "package".to_string() => row(indexmap! {
+                "name".to_string() => string("nu"),
+                "version".to_string() => string("0.27.2"),
+            }),
+            "features".to_string() => table(&[string("stable")]),
+        })
+    }
+
+    #[test]
+    fn merges_nested_rows_key_by_key() {
+        let overlay = row(indexmap! {
+            "package".to_string() => row(indexmap! {
+                "version".to_string() => string("0.27.3"),
+                "edition".to_string() => string("2018"),
+            }),
+        });
+
+        assert_eq!(
+            manifest().merge(&overlay, false),
+            row(indexmap! {
+                "package".to_string() => row(indexmap! {
+                    "name".to_string() => string("nu"),
+                    "version".to_string() => string("0.27.3"),
+                    "edition".to_string() => string("2018"),
+                }),
+                "features".to_string() => table(&[string("stable")]),
+            })
+        );
+    }
+
+    #[test]
+    fn overlay_scalars_override_base_values() {
+        let overlay = row(indexmap! { "package".to_string() => int(1) });
+
+        assert_eq!(
+            manifest().merge(&overlay, false),
+            row(indexmap! {
+                "package".to_string() => int(1),
+                "features".to_string() => table(&[string("stable")]),
+            })
+        );
+        assert_eq!(int(1).merge(&string("one"), false), string("one"));
+    }
+
+    #[test]
+    fn replaces_or_concatenates_tables() {
+        let overlay = row(indexmap! { "features".to_string() => table(&[string("extra")]) });
+
+        let features = |merged: Value| match merged.value {
+            UntaggedValue::Row(dict) => dict.entries["features"].clone(),
+            _ => panic!("expected a row"),
+        };
+
+        assert_eq!(
+            features(manifest().merge(&overlay, false)),
+            table(&[string("extra")])
+        );
+        assert_eq!(
+            features(manifest().merge(&overlay, true)),
+            table(&[string("stable"), string("extra")])
+        );
+    }
 }
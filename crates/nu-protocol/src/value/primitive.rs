@@ -327,6 +327,36 @@ pub fn format_primitive(primitive: &Primitive, field_name: Option<&String>) -> S
     }
 }
 
+/// The units a filesize is formatted in, stepping up every 1024 or every 1000 bytes
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FilesizeUnits {
+    Binary,
+    Decimal,
+}
+
+/// Format a filesize in bytes into a string in the largest unit it reaches, eg 1.5 MB
+pub fn format_filesize(num_bytes: &BigInt, units: FilesizeUnits) -> String {
+    const UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+    let step = match units {
+        FilesizeUnits::Binary => 1024.0,
+        FilesizeUnits::Decimal => 1000.0,
+    };
+
+    let mut value = match num_bytes.to_f64() {
+        Some(bytes) if bytes.abs() >= step => bytes,
+        _ => return format!("{} B", num_bytes),
+    };
+
+    let mut unit = 0;
+    while value.abs() >= step && unit < UNITS.len() - 1 {
+        value /= step;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Format a duration in nanoseconds into a string
 pub fn format_duration(duration: &BigInt) -> String {
     let is_zero = duration.is_zero();
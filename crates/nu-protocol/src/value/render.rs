@@ -0,0 +1,201 @@
+use crate::value::primitive::{format_duration, format_filesize, FilesizeUnits, Primitive};
+use crate::value::range::RangeInclusion;
+use crate::value::{UntaggedValue, Value};
+use num_traits::ToPrimitive;
+
+/// Turns values into text, so that every output can show a value the same way
+pub trait ValueRender {
+    /// Render the value as text
+    fn render(&self, value: &Value) -> String;
+}
+
+/// Renders values as plain text, eg `{name: nu, stars: 5}` for a row
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainRender;
+
+impl PlainRender {
+    fn primitive(&self, primitive: &Primitive) -> String {
+        match primitive {
+            Primitive::Nothing | Primitive::BeginningOfStream | Primitive::EndOfStream => {
+                String::new()
+            }
+            Primitive::Int(int) => int.to_string(),
+            Primitive::Decimal(decimal) => decimal.to_string(),
+            Primitive::Filesize(bytes) => format_filesize(bytes, FilesizeUnits::Decimal),
+            Primitive::String(string) | Primitive::GlobPattern(string) => string.clone(),
+            Primitive::ColumnPath(path) => path
+                .iter()
+                .map(|member| member.as_string())
+                .collect::<Vec<String>>()
+                .join("."),
+            Primitive::Boolean(boolean) => boolean.to_string(),
+            Primitive::Date(date) => date.to_rfc3339(),
+            Primitive::Duration(duration) => format_duration(duration),
+            Primitive::Range(range) => format!(
+                "{}..{}{}",
+                self.primitive(&range.from.0.item),
+                if range.to.1 == RangeInclusion::Exclusive {
+                    "<"
+                } else {
+                    ""
+                },
+                self.primitive(&range.to.0.item)
+            ),
+            Primitive::FilePath(path) => path.display().to_string(),
+            Primitive::Binary(bytes) => format!("<{} bytes of binary>", bytes.len()),
+        }
+    }
+}
+
+impl ValueRender for PlainRender {
+    fn render(&self, value: &Value) -> String {
+        match &value.value {
+            UntaggedValue::Primitive(primitive) => self.primitive(primitive),
+            UntaggedValue::Row(dict) => format!(
+                "{{{}}}",
+                dict.entries
+                    .iter()
+                    .map(|(column, value)| format!("{}: {}", column, self.render(value)))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            UntaggedValue::Table(values) => format!(
+                "[{}]",
+                values
+                    .iter()
+                    .map(|value| self.render(value))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            UntaggedValue::Error(error) => format!("<error: {}>", error),
+            UntaggedValue::Block(_) => "<block>".to_string(),
+        }
+    }
+}
+
+/// Renders values as compact JSON, eg `{"name":"nu","stars":5}` for a row
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonRender;
+
+impl JsonRender {
+    fn json(&self, value: &Value) -> serde_json::Value {
+        match &value.value {
+            UntaggedValue::Primitive(primitive) => match primitive {
+                Primitive::Nothing | Primitive::BeginningOfStream | Primitive::EndOfStream => {
+                    serde_json::Value::Null
+                }
+                Primitive::Int(int) | Primitive::Filesize(int) | Primitive::Duration(int) => {
+                    match int.to_i64() {
+                        Some(int) => serde_json::Value::from(int),
+                        None => serde_json::Value::String(int.to_string()),
+                    }
+                }
+                Primitive::Decimal(decimal) => decimal
+                    .to_f64()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(serde_json::Value::Number)
+                    .unwrap_or_else(|| serde_json::Value::String(decimal.to_string())),
+                Primitive::Boolean(boolean) => serde_json::Value::Bool(*boolean),
+                Primitive::Binary(bytes) => serde_json::Value::from(bytes.clone()),
+                other => serde_json::Value::String(PlainRender.primitive(other)),
+            },
+            UntaggedValue::Row(dict) => serde_json::Value::Object(
+                dict.entries
+                    .iter()
+                    .map(|(column, value)| (column.clone(), self.json(value)))
+                    .collect(),
+            ),
+            UntaggedValue::Table(values) => {
+                serde_json::Value::Array(values.iter().map(|value| self.json(value)).collect())
+            }
+            UntaggedValue::Error(error) => serde_json::Value::String(error.to_string()),
+            UntaggedValue::Block(_) => serde_json::Value::Null,
+        }
+    }
+}
+
+impl ValueRender for JsonRender {
+    fn render(&self, value: &Value) -> String {
+        self.json(value).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonRender, PlainRender, ValueRender};
+    use crate::value::dict::TaggedDictBuilder;
+    use crate::value::{UntaggedValue, Value};
+    use bigdecimal::BigDecimal;
+    use chrono::DateTime;
+    use nu_source::{Span, Tag};
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    fn plain(value: UntaggedValue) -> String {
+        PlainRender.render(&value.into_untagged_value())
+    }
+
+    fn row() -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_untagged("name", UntaggedValue::string("nu"));
+            row.insert_untagged("stars", UntaggedValue::int(5));
+        })
+    }
+
+    #[test]
+    fn renders_each_primitive_as_plain_text() {
+        let decimal = BigDecimal::from_str("1.25").expect("a decimal");
+        let date = DateTime::parse_from_rfc3339("2021-03-01T12:00:00+01:00").expect("a date");
+        let second: i64 = 1_000_000_000;
+
+        assert_eq!(plain(UntaggedValue::nothing()), "");
+        assert_eq!(plain(UntaggedValue::int(-42)), "-42");
+        assert_eq!(plain(UntaggedValue::decimal(decimal)), "1.25");
+        assert_eq!(plain(UntaggedValue::filesize(1500u64)), "1.5 KB");
+        assert_eq!(plain(UntaggedValue::string("nu")), "nu");
+        assert_eq!(
+            plain(UntaggedValue::column_path(
+                "package.version",
+                Span::unknown()
+            )),
+            "package.version"
+        );
+        assert_eq!(plain(UntaggedValue::glob_pattern("*.rs")), "*.rs");
+        assert_eq!(plain(UntaggedValue::boolean(true)), "true");
+        assert_eq!(
+            plain(UntaggedValue::date(date)),
+            "2021-03-01T12:00:00+01:00"
+        );
+        assert_eq!(plain(UntaggedValue::duration(90 * second)), "1min 30sec");
+        assert_eq!(
+            plain(UntaggedValue::filepath(PathBuf::from("src/lib.rs"))),
+            "src/lib.rs"
+        );
+        assert_eq!(
+            plain(UntaggedValue::binary(vec![0, 1, 2])),
+            "<3 bytes of binary>"
+        );
+    }
+
+    #[test]
+    fn renders_rows_and_tables_as_plain_text() {
+        let table = UntaggedValue::table(&[row(), row()]).into_untagged_value();
+
+        assert_eq!(PlainRender.render(&row()), "{name: nu, stars: 5}");
+        assert_eq!(
+            PlainRender.render(&table),
+            "[{name: nu, stars: 5}, {name: nu, stars: 5}]"
+        );
+    }
+
+    #[test]
+    fn renders_values_as_json() {
+        let table = UntaggedValue::table(&[row(), UntaggedValue::nothing().into_untagged_value()])
+            .into_untagged_value();
+
+        assert_eq!(
+            JsonRender.render(&table),
+            r#"[{"name":"nu","stars":5},null]"#
+        );
+    }
+}
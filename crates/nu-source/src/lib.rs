@@ -1,5 +1,6 @@
 mod meta;
 mod pretty;
+mod source_map;
 mod term_colored;
 mod text;
 
@@ -11,5 +12,6 @@ pub use self::pretty::{
     DbgDocBldr, DebugDoc, DebugDocBuilder, PrettyDebug, PrettyDebugRefineKind,
     PrettyDebugWithSource, ShellAnnotation,
 };
+pub use self::source_map::SourceMap;
 pub use self::term_colored::TermColored;
 pub use self::text::Text;
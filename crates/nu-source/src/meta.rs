@@ -399,6 +399,19 @@ impl Tag {
         }
     }
 
+    /// Merges the current `Tag` with the given `Tag`, in either order.
+    ///
+    /// The resulting `Tag` will have a `Span` covering both `Span`s, see `Span::merge`.
+    /// The `AnchorLocation` of the current `Tag` is kept, falling back to the one of the given `Tag`.
+    pub fn merge(&self, other: impl Into<Tag>) -> Tag {
+        let other = other.into();
+
+        Tag {
+            span: self.span.merge(other.span),
+            anchor: self.anchor.clone().or(other.anchor),
+        }
+    }
+
     pub fn slice<'a>(&self, source: &'a str) -> &'a str {
         self.span.slice(source)
     }
@@ -612,6 +625,37 @@ impl Span {
         }
     }
 
+    /// Returns a new Span covering both the current Span and the given Span, in either order.
+    ///
+    /// An unknown Span covers nothing, so merging with one returns the other Span.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let field_span = Span::new(9, 11);
+    /// let row_span = Span::new(2, 6);
+    /// let merged_span = field_span.merge(row_span);
+    ///
+    /// assert_eq!(merged_span.start(), 2);
+    /// assert_eq!(merged_span.end(), 11);
+    /// ```
+    pub fn merge(&self, other: impl Into<Span>) -> Span {
+        let other = other.into();
+
+        if self.is_unknown() {
+            return other;
+        }
+
+        if other.is_unknown() {
+            return *self;
+        }
+
+        Span::new(
+            std::cmp::min(self.start, other.start),
+            std::cmp::max(self.end, other.end),
+        )
+    }
+
     pub fn string(&self, source: &str) -> String {
         self.slice(source).to_string()
     }
@@ -827,3 +871,45 @@ impl<T> HasSpan for Tagged<T> {
         self.tag.span
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AnchorLocation, Span, Tag};
+
+    #[test]
+    fn merging_spans_yields_the_enclosing_range() {
+        assert_eq!(Span::new(9, 11).merge(Span::new(2, 6)), Span::new(2, 11));
+        assert_eq!(Span::new(2, 6).merge(Span::new(9, 11)), Span::new(2, 11));
+    }
+
+    #[test]
+    fn merging_overlapping_spans_yields_the_enclosing_range() {
+        assert_eq!(Span::new(2, 8).merge(Span::new(4, 6)), Span::new(2, 8));
+        assert_eq!(Span::new(4, 9).merge(Span::new(2, 6)), Span::new(2, 9));
+    }
+
+    #[test]
+    fn merging_with_an_unknown_span_keeps_the_known_one() {
+        assert_eq!(Span::unknown().merge(Span::new(4, 6)), Span::new(4, 6));
+        assert_eq!(Span::new(4, 6).merge(Span::unknown()), Span::new(4, 6));
+    }
+
+    #[test]
+    fn merging_tags_covers_both_and_keeps_an_anchor() {
+        let file = AnchorLocation::File("Cargo.toml".to_string());
+
+        let row = Tag::unknown_anchor(Span::new(2, 6));
+        let field = Tag {
+            anchor: Some(file.clone()),
+            span: Span::new(9, 11),
+        };
+
+        assert_eq!(
+            row.merge(&field),
+            Tag {
+                anchor: Some(file),
+                span: Span::new(2, 11),
+            }
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::meta::{AnchorLocation, Tag};
+use std::collections::HashMap;
+
+/// The source text of the places values were loaded from, so errors can show the lines they point into
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    sources: HashMap<AnchorLocation, String>,
+}
+
+impl SourceMap {
+    /// Creates an empty `SourceMap`
+    pub fn new() -> SourceMap {
+        Default::default()
+    }
+
+    /// Remembers the source text found at the given `AnchorLocation`
+    pub fn insert(&mut self, anchor: AnchorLocation, source: impl Into<String>) {
+        self.sources.insert(anchor, source.into());
+    }
+
+    /// Gets the source text of the given `AnchorLocation`, if known.
+    ///
+    /// A `Source` anchor carries its own text, so it is always known.
+    pub fn get(&self, anchor: &AnchorLocation) -> Option<&str> {
+        match self.sources.get(anchor) {
+            Some(source) => Some(source.as_str()),
+            None => match anchor {
+                AnchorLocation::Source(text) => Some(text.as_ref()),
+                _ => None,
+            },
+        }
+    }
+
+    /// Renders the line the `Tag` points into, with carets under its `Span` followed by the message.
+    ///
+    /// Returns `None` when the source of the `Tag` is unknown or the `Span` does not fit into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //  --> Cargo.toml:2:11
+    /// //   |
+    /// // 2 | version = "0.27.2"
+    /// //   |           ^^^^^^^^ not a valid version
+    /// ```
+    pub fn render(&self, tag: &Tag, message: &str) -> Option<String> {
+        let source = self.get(tag.anchor.as_ref()?)?;
+        let (start, end) = (tag.span.start(), tag.span.end());
+
+        if end > source.len() || !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+            return None;
+        }
+
+        let line_start = source[..start].rfind('\n').map_or(0, |at| at + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |at| start + at);
+
+        let line_number = source[..start].matches('\n').count() + 1;
+        let column = source[line_start..start].chars().count();
+        let width = std::cmp::max(source[start..end.min(line_end)].chars().count(), 1);
+
+        let gutter = " ".repeat(line_number.to_string().len());
+        let name = tag.anchor_name().unwrap_or_else(|| "source".to_string());
+
+        Some(format!(
+            "{gutter}--> {name}:{line}:{column}\n{gutter} |\n{line} | {text}\n{gutter} | {padding}{carets} {message}",
+            gutter = gutter,
+            name = name,
+            line = line_number,
+            column = column + 1,
+            text = &source[line_start..line_end],
+            padding = " ".repeat(column),
+            carets = "^".repeat(width),
+            message = message,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceMap;
+    use crate::meta::{AnchorLocation, Span, Tag};
+
+    fn manifest() -> AnchorLocation {
+        AnchorLocation::File("Cargo.toml".to_string())
+    }
+
+    fn source_map() -> SourceMap {
+        let mut source_map = SourceMap::new();
+        source_map.insert(manifest(), "name = \"nu\"\nversion = \"0.27.x\"\n");
+        source_map
+    }
+
+    #[test]
+    fn renders_the_line_the_tag_points_into() {
+        let tag = Tag {
+            anchor: Some(manifest()),
+            span: Span::new(22, 30),
+        };
+
+        assert_eq!(
+            source_map().render(&tag, "not a valid version"),
+            Some(
+                [
+                    " --> Cargo.toml:2:11",
+                    "  |",
+                    "2 | version = \"0.27.x\"",
+                    "  |           ^^^^^^^^ not a valid version",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn renders_nothing_for_an_unknown_source() {
+        let tag = Tag {
+            anchor: Some(AnchorLocation::File("Nu.toml".to_string())),
+            span: Span::new(0, 4),
+        };
+
+        assert_eq!(source_map().render(&tag, "unknown"), None);
+        assert_eq!(source_map().render(&Tag::unknown(), "unknown"), None);
+    }
+
+    #[test]
+    fn renders_nothing_for_a_span_past_the_source() {
+        let tag = Tag {
+            anchor: Some(manifest()),
+            span: Span::new(30, 90),
+        };
+
+        assert_eq!(source_map().render(&tag, "too far"), None);
+    }
+
+    #[test]
+    fn renders_from_the_text_of_a_source_anchor() {
+        let tag = Tag {
+            anchor: Some(AnchorLocation::Source("echo 1.x".into())),
+            span: Span::new(5, 8),
+        };
+
+        assert_eq!(
+            SourceMap::new().render(&tag, "here"),
+            Some(" --> source:1:6\n  |\n1 | echo 1.x\n  |      ^^^ here".to_string())
+        );
+    }
+}
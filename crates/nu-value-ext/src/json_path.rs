@@ -0,0 +1,228 @@
+use nu_errors::ShellError;
+use nu_protocol::{UntaggedValue, Value};
+use nu_source::Spanned;
+
+/// A single step of a JSON path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// A column of a row, written `.name` or `['name']`
+    Key(String),
+    /// A row of a table, written `[2]`. Negative indices count from the end, `[-1]` being the last row.
+    Index(i64),
+    /// Every column of a row or every row of a table, written `.*` or `[*]`
+    Wildcard,
+}
+
+/// Parses the small subset of JSONPath supported by `get_data_by_json_path`: an optional leading
+/// `$`, dotted keys, quoted keys in brackets, integer indices (negative ones included) and `*`
+/// wildcards. Anything else, like filters, slices or recursive descent, is an error.
+pub fn parse_json_path(path: Spanned<&str>) -> Result<Vec<JsonPathSegment>, ShellError> {
+    let unsupported = |syntax: &str| {
+        ShellError::labeled_error(
+            format!("unsupported JSON path syntax '{}'", syntax),
+            "only keys, indices and * are supported",
+            path.span,
+        )
+    };
+
+    let text = path.item;
+    let mut rest = text.strip_prefix('$').unwrap_or(text);
+    let mut segments = vec![];
+
+    if text.is_empty() {
+        return Err(ShellError::labeled_error(
+            "empty JSON path",
+            "expected a key or an index",
+            path.span,
+        ));
+    }
+
+    let mut first = rest.len() == text.len();
+
+    while !rest.is_empty() {
+        if let Some(bracketed) = rest.strip_prefix('[') {
+            let close = bracketed.find(']').ok_or_else(|| unsupported(rest))?;
+            let inner = &bracketed[..close];
+
+            segments.push(bracket_segment(inner).ok_or_else(|| unsupported(&rest[..close + 2]))?);
+
+            rest = &bracketed[close + 1..];
+        } else {
+            let key_text = match rest.strip_prefix('.') {
+                Some(_) if rest.starts_with("..") => return Err(unsupported("..")),
+                Some(after_dot) => after_dot,
+                None if first => rest,
+                None => return Err(unsupported(rest)),
+            };
+
+            let end = key_text
+                .find(|c| c == '.' || c == '[')
+                .unwrap_or(key_text.len());
+            let key = &key_text[..end];
+
+            segments.push(match key {
+                "" => return Err(unsupported(rest)),
+                "*" => JsonPathSegment::Wildcard,
+                key => JsonPathSegment::Key(key.to_string()),
+            });
+
+            rest = &key_text[end..];
+        }
+
+        first = false;
+    }
+
+    Ok(segments)
+}
+
+fn bracket_segment(inner: &str) -> Option<JsonPathSegment> {
+    let inner = inner.trim();
+
+    if inner == "*" {
+        return Some(JsonPathSegment::Wildcard);
+    }
+
+    if let Ok(index) = inner.parse::<i64>() {
+        return Some(JsonPathSegment::Index(index));
+    }
+
+    ['\'', '"'].iter().find_map(|quote| {
+        inner
+            .strip_prefix(*quote)
+            .and_then(|unquoted| unquoted.strip_suffix(*quote))
+            .map(|key| JsonPathSegment::Key(key.to_string()))
+    })
+}
+
+/// Finds every value matched by a JSON path such as `items[*].id` or `$.items[-1]`.
+/// Keys and indices that can not be found are skipped, so a path that matches nothing gives an empty list.
+pub fn get_data_by_json_path(value: &Value, path: Spanned<&str>) -> Result<Vec<Value>, ShellError> {
+    let mut matches = vec![value.clone()];
+
+    for segment in parse_json_path(path)? {
+        let mut next = vec![];
+
+        for current in matches {
+            match (&segment, &current.value) {
+                (JsonPathSegment::Key(key), UntaggedValue::Row(dict)) => {
+                    if let Some(found) = dict.entries.get(key) {
+                        next.push(found.clone());
+                    }
+                }
+                (JsonPathSegment::Index(index), UntaggedValue::Table(rows)) => {
+                    let index = if *index < 0 {
+                        rows.len() as i64 + index
+                    } else {
+                        *index
+                    };
+
+                    if index >= 0 {
+                        if let Some(found) = rows.get(index as usize) {
+                            next.push(found.clone());
+                        }
+                    }
+                }
+                (JsonPathSegment::Wildcard, UntaggedValue::Row(dict)) => {
+                    next.extend(dict.entries.values().cloned());
+                }
+                (JsonPathSegment::Wildcard, UntaggedValue::Table(rows)) => {
+                    next.extend(rows.iter().cloned());
+                }
+                _ => {}
+            }
+        }
+
+        matches = next;
+    }
+
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_data_by_json_path, parse_json_path, JsonPathSegment};
+    use indexmap::indexmap;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::{Span, SpannedItem};
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    fn response() -> Value {
+        let item = |id: i64, name: &str| {
+            UntaggedValue::row(indexmap! {
+                "id".to_string() => int(id),
+                "name".to_string() => string(name),
+            })
+            .into_untagged_value()
+        };
+
+        UntaggedValue::row(indexmap! {
+            "items".to_string() => UntaggedValue::table(&[item(1, "nu"), item(2, "serde"), item(3, "rayon")]).into_untagged_value(),
+        })
+        .into_untagged_value()
+    }
+
+    fn select(path: &str) -> Vec<Value> {
+        get_data_by_json_path(&response(), path.spanned(Span::unknown())).expect("a supported path")
+    }
+
+    #[test]
+    fn parses_keys_indices_and_wildcards() {
+        assert_eq!(
+            parse_json_path("$.items[*].tags[-1]['full name']".spanned(Span::unknown())),
+            Ok(vec![
+                JsonPathSegment::Key("items".to_string()),
+                JsonPathSegment::Wildcard,
+                JsonPathSegment::Key("tags".to_string()),
+                JsonPathSegment::Index(-1),
+                JsonPathSegment::Key("full name".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn wildcards_match_every_row() {
+        assert_eq!(select("items[*].id"), vec![int(1), int(2), int(3)]);
+    }
+
+    #[test]
+    fn negative_indices_count_from_the_end() {
+        assert_eq!(select("$.items[-1].name"), vec![string("rayon")]);
+        assert!(select("items[-4]").is_empty());
+    }
+
+    #[test]
+    fn missing_keys_match_nothing() {
+        assert!(select("items[*].version").is_empty());
+        assert!(select("items[7]").is_empty());
+    }
+
+    #[test]
+    fn errors_on_unsupported_syntax() {
+        for path in &[
+            "items[?(@.id > 1)]",
+            "items[0:2]",
+            "$..id",
+            "items.",
+            "items[0",
+        ] {
+            let error = get_data_by_json_path(&response(), path.spanned(Span::unknown()))
+                .expect_err("unsupported syntax")
+                .into_diagnostic()
+                .expect("a diagnostic");
+
+            assert!(
+                error.message.starts_with("unsupported JSON path syntax"),
+                "{}: {}",
+                path,
+                error.message
+            );
+        }
+    }
+}
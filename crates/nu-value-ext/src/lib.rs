@@ -3,14 +3,19 @@ use indexmap::set::IndexSet;
 use itertools::Itertools;
 use nu_errors::{ExpectedRange, ShellError};
 use nu_protocol::{
-    ColumnPath, MaybeOwned, PathMember, Primitive, ShellTypeName, SpannedTypeName,
+    did_you_mean, ColumnPath, MaybeOwned, PathMember, Primitive, ShellTypeName, SpannedTypeName,
     UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::{
-    HasFallibleSpan, HasSpan, PrettyDebug, Span, Spanned, SpannedItem, Tag, Tagged, TaggedItem,
+    span_for_spanned_list, HasFallibleSpan, HasSpan, PrettyDebug, Span, Spanned, SpannedItem, Tag,
+    Tagged, TaggedItem,
 };
 use num_traits::cast::ToPrimitive;
 
+mod json_path;
+
+pub use json_path::{get_data_by_json_path, parse_json_path, JsonPathSegment};
+
 pub trait ValueExt {
     fn into_parts(self) -> (UntaggedValue, Tag);
     fn get_data(&self, desc: &str) -> MaybeOwned<'_, Value>;
@@ -21,6 +26,8 @@ pub trait ValueExt {
         path: &ColumnPath,
         callback: Box<dyn FnOnce(&Value, &PathMember, ShellError) -> ShellError>,
     ) -> Result<Value, ShellError>;
+    fn get_data_by_column_path_pattern(&self, path: &ColumnPath) -> Vec<(ColumnPath, Value)>;
+    fn get_data_by_json_path(&self, path: Spanned<&str>) -> Result<Vec<Value>, ShellError>;
     fn swap_data_by_column_path(
         &self,
         path: &ColumnPath,
@@ -77,6 +84,14 @@ impl ValueExt for Value {
         get_data_by_column_path(self, path, get_error)
     }
 
+    fn get_data_by_column_path_pattern(&self, path: &ColumnPath) -> Vec<(ColumnPath, Value)> {
+        get_data_by_column_path_pattern(self, path)
+    }
+
+    fn get_data_by_json_path(&self, path: Spanned<&str>) -> Result<Vec<Value>, ShellError> {
+        get_data_by_json_path(self, path)
+    }
+
     fn swap_data_by_column_path(
         &self,
         path: &ColumnPath,
@@ -228,6 +243,98 @@ where
     Ok(current)
 }
 
+/// Find the value at the column path, failing with an "Unknown column" error that suggests a
+/// similarly named column when the path is not there
+pub fn get_data_by_column_path_or_suggest(
+    value: &Value,
+    path: &Tagged<ColumnPath>,
+) -> Result<Value, ShellError> {
+    let name = path
+        .iter()
+        .map(|member| member.as_string())
+        .collect::<Vec<String>>()
+        .join(".");
+    let span = span_for_spanned_list(path.iter().map(|member| member.span));
+
+    get_data_by_column_path(value, path, move |obj_source, column_path_tried, _| {
+        let label = match did_you_mean(&obj_source, column_path_tried.as_string()) {
+            Some(suggestions) => format!("did you mean '{}'?", suggestions[0]),
+            None => "row does not contain this column".to_string(),
+        };
+
+        ShellError::labeled_error(format!("Unknown column '{}'", name), label, span)
+    })
+}
+
+/// Replace the value at the column path with what `update` makes of it, failing the same way
+/// `get_data_by_column_path_or_suggest` does when the path is not there
+pub fn update_data_at_column_path<F>(
+    value: &Value,
+    path: &Tagged<ColumnPath>,
+    update: F,
+) -> Result<Value, ShellError>
+where
+    F: FnOnce(Value) -> Result<Value, ShellError>,
+{
+    let updated = update(get_data_by_column_path_or_suggest(value, path)?)?;
+
+    replace_data_at_column_path(value, path, updated).ok_or_else(|| {
+        ShellError::labeled_error("could not find field to replace", "column name", &value.tag)
+    })
+}
+
+/// Find every value matched by a column path that may contain `*` wildcards, together with the
+/// concrete path each one was found at. A `*` matches every column of a row or every row of a
+/// table. Members that can not be found are skipped, so a path that matches nothing gives an empty list.
+pub fn get_data_by_column_path_pattern(
+    value: &Value,
+    path: &ColumnPath,
+) -> Vec<(ColumnPath, Value)> {
+    let mut matches = vec![(vec![], value.clone())];
+
+    for member in path.iter() {
+        let mut next = vec![];
+
+        for (found_at, current) in matches {
+            let wildcard = matches!(&member.unspanned, UnspannedPathMember::String(s) if s == "*");
+
+            if !wildcard {
+                if let Ok(found) = get_data_by_member(&current, member) {
+                    let mut found_at = found_at;
+                    found_at.push(member.clone());
+                    next.push((found_at, found));
+                }
+                continue;
+            }
+
+            match &current.value {
+                UntaggedValue::Row(dict) => {
+                    for (key, found) in dict.entries.iter() {
+                        let mut found_at = found_at.clone();
+                        found_at.push(PathMember::string(key, member.span));
+                        next.push((found_at, found.clone()));
+                    }
+                }
+                UntaggedValue::Table(rows) => {
+                    for (idx, found) in rows.iter().enumerate() {
+                        let mut found_at = found_at.clone();
+                        found_at.push(PathMember::int(idx, member.span));
+                        next.push((found_at, found.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        matches = next;
+    }
+
+    matches
+        .into_iter()
+        .map(|(found_at, found)| (ColumnPath::new(found_at), found))
+        .collect()
+}
+
 pub fn swap_data_by_column_path<F>(
     value: &Value,
     path: &ColumnPath,
@@ -486,6 +593,8 @@ pub fn missing_path_members_by_column_path(value: &Value, path: &ColumnPath) ->
     None
 }
 
+/// Like `insert_data_at_column_path`, but creates any missing rows (or tables for integer members)
+/// along the path instead of failing.
 pub fn forgiving_insert_data_at_column_path(
     value: &Value,
     split_path: &ColumnPath,
@@ -525,7 +634,7 @@ pub fn forgiving_insert_data_at_column_path(
             split_path
                 .iter()
                 .cloned()
-                .take(split_path.members().len() - missed_at + 1)
+                .take(missed_at + 1)
                 .collect::<Vec<_>>(),
         );
 
@@ -802,3 +911,217 @@ pub(crate) fn get_mut_data_by_member<'value>(
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        forgiving_insert_data_at_column_path, get_data_by_column_path,
+        get_data_by_column_path_or_suggest, get_data_by_column_path_pattern,
+        insert_data_at_column_path, replace_data_at_column_path, update_data_at_column_path,
+    };
+    use indexmap::indexmap;
+    use nu_protocol::{ColumnPath, UntaggedValue, Value};
+    use nu_source::{SpannedItem, TaggedItem};
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    fn path(input: &str) -> ColumnPath {
+        ColumnPath::build(&input.to_string().spanned_unknown())
+    }
+
+    fn found(matches: Vec<(ColumnPath, Value)>) -> Vec<(String, Value)> {
+        matches
+            .into_iter()
+            .map(|(found_at, value)| {
+                let found_at = found_at
+                    .iter()
+                    .map(|member| member.as_string())
+                    .collect::<Vec<String>>()
+                    .join(".");
+
+                (found_at, value)
+            })
+            .collect()
+    }
+
+    fn dependencies() -> Value {
+        UntaggedValue::row(indexmap! {
+            "deps".into() => UntaggedValue::row(indexmap! {
+                "serde".into() => UntaggedValue::row(indexmap! {
+                    "version".into() => string("1.0.0"),
+                }).into_untagged_value(),
+                "chrono".into() => UntaggedValue::row(indexmap! {
+                    "version".into() => string("0.4.19"),
+                }).into_untagged_value(),
+                "semver".into() => UntaggedValue::row(indexmap! {
+                    "optional".into() => string("true"),
+                }).into_untagged_value(),
+            }).into_untagged_value(),
+        })
+        .into_untagged_value()
+    }
+
+    #[test]
+    fn wildcard_matches_every_sibling_key() {
+        let actual = found(get_data_by_column_path_pattern(
+            &dependencies(),
+            &path("deps.*.version"),
+        ));
+
+        assert_eq!(
+            actual,
+            vec![
+                ("deps.serde.version".to_string(), string("1.0.0")),
+                ("deps.chrono.version".to_string(), string("0.4.19")),
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_path_matches_a_single_value() {
+        let actual = get_data_by_column_path_pattern(&dependencies(), &path("deps.chrono.version"));
+
+        assert_eq!(
+            actual,
+            vec![(path("deps.chrono.version"), string("0.4.19"))]
+        );
+    }
+
+    #[test]
+    fn wildcard_matching_nothing_is_empty() {
+        let actual = get_data_by_column_path_pattern(&dependencies(), &path("deps.*.license"));
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn suggests_a_similar_column_when_the_path_is_not_there() {
+        let error = get_data_by_column_path_or_suggest(
+            &dependencies(),
+            &path("deps.serd.version").tagged_unknown(),
+        )
+        .expect_err("no such column")
+        .into_diagnostic()
+        .expect("a diagnostic");
+
+        assert_eq!(error.message, "Unknown column 'deps.serd.version'");
+        assert_eq!(error.labels[0].message, "did you mean 'serde'?");
+    }
+
+    #[test]
+    fn update_replaces_the_value_at_the_path() {
+        let actual = update_data_at_column_path(
+            &dependencies(),
+            &path("deps.serde.version").tagged_unknown(),
+            |found| {
+                assert_eq!(found, string("1.0.0"));
+                Ok(string("1.0.1"))
+            },
+        )
+        .expect("a version to replace");
+
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("deps.serde.version"), |_, _, err| err).ok(),
+            Some(string("1.0.1"))
+        );
+    }
+
+    #[test]
+    fn forgiving_insert_creates_every_missing_level() {
+        let empty = UntaggedValue::row(indexmap! {}).into_untagged_value();
+
+        let actual = forgiving_insert_data_at_column_path(&empty, &path("a.b.c"), string("1"))
+            .expect("intermediate rows created");
+
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("a.b.c"), |_, _, err| err).ok(),
+            Some(string("1"))
+        );
+    }
+
+    #[test]
+    fn forgiving_insert_creates_levels_below_an_existing_row() {
+        let value = UntaggedValue::row(indexmap! {
+            "a".into() => UntaggedValue::row(indexmap! {
+                "keep".into() => string("me"),
+            }).into_untagged_value(),
+        })
+        .into_untagged_value();
+
+        let actual = forgiving_insert_data_at_column_path(&value, &path("a.b.c"), string("1"))
+            .expect("intermediate rows created");
+
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("a.b.c"), |_, _, err| err).ok(),
+            Some(string("1"))
+        );
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("a.keep"), |_, _, err| err).ok(),
+            Some(string("me"))
+        );
+    }
+
+    #[test]
+    fn strict_insert_errors_on_missing_levels() {
+        let empty = UntaggedValue::row(indexmap! {}).into_untagged_value();
+
+        assert!(insert_data_at_column_path(&empty, &path("a.b.c"), string("1")).is_err());
+    }
+
+    fn items() -> Value {
+        UntaggedValue::row(indexmap! {
+            "items".into() => UntaggedValue::table(&[
+                UntaggedValue::row(indexmap! {
+                    "name".into() => string("arepa"),
+                }).into_untagged_value(),
+                UntaggedValue::row(indexmap! {
+                    "name".into() => string("empanada"),
+                }).into_untagged_value(),
+            ]).into_untagged_value(),
+        })
+        .into_untagged_value()
+    }
+
+    #[test]
+    fn index_segments_select_rows_of_a_table() {
+        assert_eq!(
+            get_data_by_column_path(&items(), &path("items.1.name"), |_, _, err| err).ok(),
+            Some(string("empanada"))
+        );
+    }
+
+    #[test]
+    fn index_segments_replace_rows_of_a_table() {
+        let actual = replace_data_at_column_path(&items(), &path("items.0.name"), string("taco"))
+            .expect("row replaced");
+
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("items.0.name"), |_, _, err| err).ok(),
+            Some(string("taco"))
+        );
+        assert_eq!(
+            get_data_by_column_path(&actual, &path("items.1.name"), |_, _, err| err).ok(),
+            Some(string("empanada"))
+        );
+    }
+
+    #[test]
+    fn out_of_range_index_segments_error() {
+        assert!(get_data_by_column_path(&items(), &path("items.2.name"), |_, _, err| err).is_err());
+        assert!(
+            replace_data_at_column_path(&items(), &path("items.2.name"), string("taco")).is_none()
+        );
+    }
+
+    #[test]
+    fn negative_index_segments_are_column_names() {
+        assert!(
+            get_data_by_column_path(&items(), &path("items.-1.name"), |_, _, err| err).is_err()
+        );
+        assert!(
+            replace_data_at_column_path(&items(), &path("items.-1.name"), string("taco")).is_none()
+        );
+    }
+}
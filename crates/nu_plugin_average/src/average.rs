@@ -0,0 +1,135 @@
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_plugin_sum::Sum;
+use nu_protocol::{UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct Average {
+    pub sum: Sum,
+}
+
+impl Average {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the value, or its field when one is set, the same way `sum` does
+    pub fn add(&mut self, value: &Value) -> Result<(), ShellError> {
+        self.sum.add(value)
+    }
+
+    /// The mean of the values added so far, as a filesize rounded down to whole bytes when every
+    /// value added was one and as a decimal otherwise
+    pub fn average(&self) -> Result<Value, ShellError> {
+        if self.sum.count == 0 {
+            return Err(ShellError::labeled_error(
+                "no values to average",
+                "the stream had no numbers",
+                &self.sum.tag,
+            ));
+        }
+
+        let mean = &self.sum.total / BigDecimal::from(self.sum.count as u64);
+
+        if self.sum.all_filesizes() {
+            let (mean, _) = mean.with_scale(0).as_bigint_and_exponent();
+            return Ok(UntaggedValue::filesize(mean).into_value(&self.sum.tag));
+        }
+
+        Ok(UntaggedValue::decimal(mean).into_value(&self.sum.tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Average;
+    use bigdecimal::BigDecimal;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, decimal, decimal_from_float, int, string};
+    use nu_value_ext::ValueExt;
+    use std::str::FromStr;
+
+    fn average_of(average: &mut Average, values: &[Value]) -> Result<Value, nu_errors::ShellError> {
+        for value in values {
+            average.add(value)?;
+        }
+
+        average.average()
+    }
+
+    #[test]
+    fn averages_a_stream_of_ints() {
+        assert_eq!(
+            average_of(&mut Average::new(), &[int(1), int(2), int(3), int(4)]),
+            Ok(decimal_from_float(2.5))
+        );
+    }
+
+    #[test]
+    fn averages_a_field_across_rows() {
+        let mut average = Average::new();
+        average.sum.field = Some(
+            column_path("stars")
+                .as_column_path()
+                .expect("a column path"),
+        );
+
+        let rows = [10, 20]
+            .iter()
+            .map(|stars| {
+                TaggedDictBuilder::build(Tag::unknown(), |row| {
+                    row.insert_value("name", string("nu"));
+                    row.insert_value("stars", int(*stars));
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            average_of(&mut average, &rows),
+            Ok(decimal_from_float(15.0))
+        );
+    }
+
+    #[test]
+    fn averages_decimals_exactly() {
+        let tenth = || decimal(BigDecimal::from_str("0.1").expect("a decimal"));
+
+        assert_eq!(
+            average_of(&mut Average::new(), &[tenth(), tenth(), int(1)]),
+            Ok(decimal(BigDecimal::from_str("0.4").expect("a decimal")))
+        );
+    }
+
+    #[test]
+    fn averages_filesizes_as_a_filesize() {
+        let filesize = |bytes: i64| UntaggedValue::filesize(bytes).into_untagged_value();
+
+        assert_eq!(
+            average_of(
+                &mut Average::new(),
+                &[filesize(1024), filesize(2048), filesize(2049)]
+            ),
+            Ok(filesize(1707))
+        );
+    }
+
+    #[test]
+    fn errors_on_an_empty_stream() {
+        let error = Average::new()
+            .average()
+            .expect_err("nothing to average")
+            .into_diagnostic()
+            .expect("a diagnostic");
+
+        assert_eq!(error.message, "no values to average");
+    }
+
+    #[test]
+    fn errors_when_every_value_was_skipped() {
+        let mut average = Average::new();
+        average.sum.skip_non_numeric = true;
+
+        assert!(average_of(&mut average, &[string("nu")]).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod average;
+mod nu;
+
+pub use average::Average;
@@ -0,0 +1,19 @@
+mod integration {
+    use crate::Average;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{decimal_from_float, int};
+
+    #[test]
+    fn emits_the_mean_at_the_end() {
+        let run = plugin(&mut Average::new())
+            .args(CallStub::new().create())
+            .input(int(3))
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, decimal_from_float(3.0));
+    }
+}
@@ -9,7 +9,8 @@ impl Plugin for BinaryView {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("binaryview")
             .desc("Autoview of binary data.")
-            .switch("lores", "use low resolution output mode", Some('l')))
+            .switch("lores", "use low resolution output mode", Some('l'))
+            .sink())
     }
 
     fn sink(&mut self, call_info: CallInfo, input: Vec<Value>) {
@@ -0,0 +1,112 @@
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::get_data_by_column_path;
+
+#[derive(Default)]
+pub struct Count {
+    pub by: Option<Tagged<ColumnPath>>,
+    pub count: usize,
+    pub groups: IndexMap<String, usize>,
+    pub tag: Tag,
+}
+
+impl Count {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Counts the value, under the group of its field's value when counting by a field
+    pub fn add(&mut self, value: &Value) -> Result<(), ShellError> {
+        if let Some(by) = &self.by {
+            let group = get_data_by_column_path(value, by, |_, _, error| error)?;
+            *self.groups.entry(group.convert_to_string()).or_insert(0) += 1;
+        }
+
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// The number of values counted, or a row of counts keyed by the groups in the order
+    /// they were first seen when counting by a field
+    pub fn counted(&self) -> Value {
+        if self.by.is_none() {
+            return UntaggedValue::int(self.count).into_value(&self.tag);
+        }
+
+        let mut row = TaggedDictBuilder::new(&self.tag);
+        for (group, count) in &self.groups {
+            row.insert_untagged(group, UntaggedValue::int(*count));
+        }
+        row.into_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Count;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+    use nu_value_ext::{get_data, ValueExt};
+
+    fn pod(name: &str, status: &str) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string(name));
+            row.insert_value("status", string(status));
+        })
+    }
+
+    #[test]
+    fn counts_every_value() {
+        let mut count = Count::new();
+        for value in &[int(1), string("nu"), pod("web", "Running")] {
+            count.add(value).expect("counted");
+        }
+
+        assert_eq!(count.counted(), int(3));
+    }
+
+    #[test]
+    fn counts_nothing_as_zero() {
+        assert_eq!(Count::new().counted(), int(0));
+    }
+
+    #[test]
+    fn counts_by_the_value_of_a_field() {
+        let mut count = Count::new();
+        count.by = Some(
+            column_path("status")
+                .as_column_path()
+                .expect("a column path"),
+        );
+
+        for value in &[
+            pod("web", "Running"),
+            pod("db", "Pending"),
+            pod("cache", "Running"),
+        ] {
+            count.add(value).expect("counted");
+        }
+
+        let actual = count.counted();
+
+        assert_eq!(actual.data_descriptors(), vec!["Running", "Pending"]);
+        assert_eq!(get_data(&actual, "Running").borrow(), &int(2));
+        assert_eq!(get_data(&actual, "Pending").borrow(), &int(1));
+    }
+
+    #[test]
+    fn errors_when_a_value_lacks_the_field() {
+        let mut count = Count::new();
+        count.by = Some(
+            column_path("status")
+                .as_column_path()
+                .expect("a column path"),
+        );
+
+        assert!(count.add(&int(1)).is_err());
+    }
+}
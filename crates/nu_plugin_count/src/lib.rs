@@ -0,0 +1,4 @@
+mod count;
+mod nu;
+
+pub use count::Count;
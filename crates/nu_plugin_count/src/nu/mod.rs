@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Count;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Count {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("count-values")
+            .desc("Count the values of the stream.")
+            .named(
+                "by",
+                SyntaxShape::ColumnPath,
+                "count the rows of each value of this column instead",
+                Some('b'),
+            )
+            .example("ls | count-values --by type")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if let Some(by) = call_info.args.get("by") {
+            self.by = Some(by.as_column_path()?);
+        }
+
+        self.tag = call_info.name_tag;
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.add(&input)?;
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.counted())])
+    }
+}
@@ -0,0 +1,42 @@
+mod integration {
+    use crate::Count;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_the_count_at_the_end() {
+        let run = plugin(&mut Count::new())
+            .args(CallStub::new().create())
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), int(1));
+    }
+
+    #[test]
+    fn emits_counts_by_field() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("type", string("File"));
+        });
+
+        let run = plugin(&mut Count::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("by", column_path("type"))
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "File").borrow(), &int(1));
+    }
+}
@@ -0,0 +1,205 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::{HasSpan, SpannedItem, Tagged};
+use nu_value_ext::update_data_at_column_path;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Action {
+    SemVerAction(SemVerAction),
+    Default,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum SemVerAction {
+    Major,
+    Minor,
+    Patch,
+}
+
+#[derive(Default)]
+pub struct Dec {
+    pub fields: Vec<Tagged<ColumnPath>>,
+    pub error: Option<String>,
+    pub action: Option<Action>,
+}
+
+impl Dec {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply(&self, input: &str) -> Result<UntaggedValue, ShellError> {
+        Ok(match &self.action {
+            Some(Action::SemVerAction(act_on)) => {
+                let mut ver = match semver::Version::parse(&input) {
+                    Ok(parsed_ver) => parsed_ver,
+                    Err(_) => return Ok(UntaggedValue::string(input.to_string())),
+                };
+
+                match act_on {
+                    SemVerAction::Major if ver.major > 0 => {
+                        ver.major -= 1;
+                        ver.minor = 0;
+                        ver.patch = 0;
+                    }
+                    SemVerAction::Minor if ver.minor > 0 => {
+                        ver.minor -= 1;
+                        ver.patch = 0;
+                    }
+                    SemVerAction::Patch if ver.patch > 0 => ver.patch -= 1,
+                    _ => {
+                        return Err(ShellError::untagged_runtime_error(format!(
+                            "can not decrement the {} version of '{}' below zero",
+                            act_on.name(),
+                            input
+                        )))
+                    }
+                }
+
+                ver.pre = vec![];
+                ver.build = vec![];
+
+                UntaggedValue::string(ver.to_string())
+            }
+            Some(Action::Default) | None => match input.parse::<u64>() {
+                Ok(v) => UntaggedValue::string(format!("{}", v.saturating_sub(1))),
+                Err(_) => UntaggedValue::string(input),
+            },
+        })
+    }
+
+    pub fn for_semver(&mut self, part: SemVerAction) {
+        if self.permit() {
+            self.action = Some(Action::SemVerAction(part));
+        } else {
+            self.log_error("can only apply one");
+        }
+    }
+
+    fn permit(&mut self) -> bool {
+        self.action.is_none()
+    }
+
+    fn log_error(&mut self, message: &str) {
+        self.error = Some(message.to_string());
+    }
+
+    pub fn usage() -> &'static str {
+        "Usage: dec field [--major|--minor|--patch]"
+    }
+
+    pub fn dec(&self, value: Value) -> Result<Value, ShellError> {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::Int(i)) => {
+                // like filesizes, integers stop at zero, but negative ones keep going down
+                let decremented = if *i == 0.into() { i.clone() } else { i - 1 };
+                Ok(UntaggedValue::int(decremented).into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::Filesize(b)) => {
+                let decremented = std::cmp::max(b - 1, 0.into());
+                Ok(UntaggedValue::filesize(decremented).into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::String(ref s)) => {
+                Ok(self.apply(&s)?.into_value(value.tag()))
+            }
+            UntaggedValue::Table(values) => {
+                if values.len() == 1 {
+                    Ok(UntaggedValue::Table(vec![self.dec(values[0].clone())?])
+                        .into_value(value.tag()))
+                } else {
+                    Err(ShellError::type_error(
+                        "decrementable value",
+                        value.type_name().spanned(value.span()),
+                    ))
+                }
+            }
+
+            UntaggedValue::Row(_) => {
+                if self.fields.is_empty() {
+                    return Err(ShellError::untagged_runtime_error(
+                        "dec needs a field when decrementing a column in a table",
+                    ));
+                }
+
+                let mut result = value.clone();
+
+                for field in &self.fields {
+                    result = update_data_at_column_path(&result, field, |found| self.dec(found))?;
+                }
+
+                Ok(result)
+            }
+            _ => Err(ShellError::type_error(
+                "decrementable value",
+                value.type_name().spanned(value.span()),
+            )),
+        }
+    }
+}
+
+impl SemVerAction {
+    fn name(&self) -> &'static str {
+        match self {
+            SemVerAction::Major => "major",
+            SemVerAction::Minor => "minor",
+            SemVerAction::Patch => "patch",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod semver {
+        use crate::dec::SemVerAction;
+        use crate::Dec;
+        use nu_test_support::value::string;
+
+        #[test]
+        fn major() {
+            let mut dec = Dec::new();
+            dec.for_semver(SemVerAction::Major);
+            assert_eq!(dec.apply("1.2.3"), Ok(string("0.0.0").value));
+        }
+
+        #[test]
+        fn minor() {
+            let mut dec = Dec::new();
+            dec.for_semver(SemVerAction::Minor);
+            assert_eq!(dec.apply("0.2.3"), Ok(string("0.1.0").value));
+        }
+
+        #[test]
+        fn patch() {
+            let mut dec = Dec::new();
+            dec.for_semver(SemVerAction::Patch);
+            assert_eq!(dec.apply("0.1.3"), Ok(string("0.1.2").value));
+        }
+
+        #[test]
+        fn does_not_underflow() {
+            let mut dec = Dec::new();
+            dec.for_semver(SemVerAction::Patch);
+
+            let actual = format!("{}", dec.apply("0.1.0").unwrap_err());
+
+            assert!(actual.contains("can not decrement the patch version of '0.1.0' below zero"));
+        }
+    }
+
+    mod default {
+        use crate::Dec;
+        use nu_test_support::value::string;
+
+        #[test]
+        fn numeric_string() {
+            let dec = Dec::new();
+            assert_eq!(dec.apply("5"), Ok(string("4").value));
+        }
+
+        #[test]
+        fn numeric_string_saturates_at_zero() {
+            let dec = Dec::new();
+            assert_eq!(dec.apply("0"), Ok(string("0").value));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod dec;
+mod nu;
+
+pub use dec::Dec;
+
+#[cfg(test)]
+mod tests {
+    use super::Dec;
+    use crate::dec::Action;
+    use nu_protocol::Value;
+    use nu_value_ext::ValueExt;
+
+    impl Dec {
+        pub fn expect_action(&self, action: Action) {
+            match &self.action {
+                Some(set) if set == &action => {}
+                Some(other) => panic!(format!("\nExpected {:#?}\n\ngot {:#?}", action, other)),
+                None => panic!(format!("\nAction {:#?} not found.", action)),
+            }
+        }
+
+        pub fn expect_field(&self, field: Value) {
+            let field = match field.as_column_path() {
+                Ok(column_path) => column_path,
+                Err(reason) => panic!(format!(
+                    "\nExpected {:#?} to be a ColumnPath, \n\ngot {:#?}",
+                    field, reason
+                )),
+            };
+
+            if !self.fields.iter().any(|column_path| column_path == &field) {
+                panic!(format!(
+                    "\nExpected {:#?} \n\ngot {:#?}",
+                    field, self.fields
+                ))
+            }
+        }
+    }
+}
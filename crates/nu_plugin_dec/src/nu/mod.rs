@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests;
+
+use crate::dec::{Action, SemVerAction};
+use crate::Dec;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{
+    CallInfo, Primitive, ReturnSuccess, ReturnValue, ShellTypeName, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::{HasSpan, SpannedItem};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Dec {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("dec")
+            .desc("Decrement a value or version. Optionally use the column of a table.")
+            .switch(
+                "major",
+                "decrement the major version (eg 1.2.1 -> 0.0.0)",
+                Some('M'),
+            )
+            .switch(
+                "minor",
+                "decrement the minor version (eg 1.2.1 -> 1.1.0)",
+                Some('m'),
+            )
+            .switch(
+                "patch",
+                "decrement the patch version (eg 1.2.1 -> 1.2.0)",
+                Some('p'),
+            )
+            .exclusive_group(&["major", "minor", "patch"])
+            .rest(SyntaxShape::ColumnPath, "the column(s) to update")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if call_info.switch_present("major") {
+            self.for_semver(SemVerAction::Major);
+        }
+        if call_info.switch_present("minor") {
+            self.for_semver(SemVerAction::Minor);
+        }
+        if call_info.switch_present("patch") {
+            self.for_semver(SemVerAction::Patch);
+        }
+
+        if let Some(args) = call_info.args.positional {
+            for arg in args {
+                match arg {
+                    table
+                    @
+                    Value {
+                        value: UntaggedValue::Primitive(Primitive::ColumnPath(_)),
+                        ..
+                    } => {
+                        self.fields.push(table.as_column_path()?);
+                    }
+                    value => {
+                        return Err(ShellError::type_error(
+                            "table",
+                            value.type_name().spanned(value.span()),
+                        ))
+                    }
+                }
+            }
+        }
+
+        if self.action.is_none() {
+            self.action = Some(Action::Default);
+        }
+
+        match &self.error {
+            Some(reason) => Err(ShellError::untagged_runtime_error(format!(
+                "{}: {}",
+                reason,
+                Dec::usage()
+            ))),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.dec(input)?)])
+    }
+}
@@ -0,0 +1,155 @@
+use nu_errors::ShellError;
+use nu_protocol::{SpannedTypeName, Value};
+use nu_source::{SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::ValueExt;
+
+#[derive(Default)]
+pub struct DefaultColumns {
+    pub fields: Vec<(Tagged<String>, Value)>,
+}
+
+impl DefaultColumns {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a column to fill in, which may be nested like `meta.status`
+    pub fn add_field(&mut self, field: &Value, value: &Value) -> Result<(), ShellError> {
+        let field = field.as_string()?.tagged(&field.tag);
+
+        self.fields.push((field, value.clone()));
+
+        Ok(())
+    }
+
+    /// Fills in the columns the row is missing or has as nothing, leaving the others untouched
+    pub fn fill(&self, value: Value) -> Result<Value, ShellError> {
+        if !value.is_row() {
+            return Err(ShellError::type_error("row", value.spanned_type_name()));
+        }
+
+        let mut row = value;
+
+        for (field, default) in &self.fields {
+            if !is_missing(&row, &field.item) {
+                continue;
+            }
+
+            row = row
+                .insert_data_at_path(&field.item, default.clone())
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        format!("no row to fill '{}' into", field.item),
+                        "the columns leading to it are missing",
+                        &field.tag,
+                    )
+                })?;
+        }
+
+        Ok(row)
+    }
+}
+
+fn is_missing(row: &Value, path: &str) -> bool {
+    let mut current = row.clone();
+
+    for column in path.split('.') {
+        current = match current.get_data_by_key(column.spanned_unknown()) {
+            Some(next) => next,
+            None => return true,
+        };
+    }
+
+    current.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DefaultColumns;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    fn default(fields: &[(&str, Value)]) -> DefaultColumns {
+        let mut default = DefaultColumns::new();
+
+        for (field, value) in fields {
+            default
+                .add_field(&string(*field), value)
+                .expect("a column to fill in");
+        }
+
+        default
+    }
+
+    #[test]
+    fn fills_in_a_missing_column() {
+        assert_eq!(
+            default(&[("status", string("unknown"))]).fill(row(vec![("name", string("web"))])),
+            Ok(row(vec![
+                ("name", string("web")),
+                ("status", string("unknown"))
+            ]))
+        );
+    }
+
+    #[test]
+    fn fills_in_a_column_of_nothing() {
+        assert_eq!(
+            default(&[("status", string("unknown"))]).fill(row(vec![
+                ("status", UntaggedValue::nothing().into_untagged_value()),
+                ("name", string("web")),
+            ])),
+            Ok(row(vec![
+                ("status", string("unknown")),
+                ("name", string("web"))
+            ]))
+        );
+    }
+
+    #[test]
+    fn leaves_present_columns_untouched() {
+        let service = row(vec![("name", string("web")), ("status", string("up"))]);
+
+        assert_eq!(
+            default(&[("status", string("unknown"))]).fill(service.clone()),
+            Ok(service)
+        );
+    }
+
+    #[test]
+    fn fills_in_several_columns() {
+        assert_eq!(
+            default(&[("status", string("unknown")), ("replicas", int(1))])
+                .fill(row(vec![("status", string("up"))])),
+            Ok(row(vec![("status", string("up")), ("replicas", int(1))]))
+        );
+    }
+
+    #[test]
+    fn fills_in_a_nested_column() {
+        assert_eq!(
+            default(&[("meta.owner", string("ops"))])
+                .fill(row(vec![("meta", row(vec![("team", string("web"))]))])),
+            Ok(row(vec![(
+                "meta",
+                row(vec![("team", string("web")), ("owner", string("ops"))])
+            )]))
+        );
+    }
+
+    #[test]
+    fn errors_when_the_nested_row_is_missing() {
+        assert!(default(&[("meta.owner", string("ops"))])
+            .fill(row(vec![("name", string("web"))]))
+            .is_err());
+    }
+}
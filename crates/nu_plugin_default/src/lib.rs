@@ -0,0 +1,4 @@
+mod default;
+mod nu;
+
+pub use default::DefaultColumns;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests;
+
+use crate::DefaultColumns;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for DefaultColumns {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("default-columns")
+            .desc("Fill in columns that rows of the stream are missing or have as nothing.")
+            .required("field", SyntaxShape::String, "the column to fill in")
+            .required("value", SyntaxShape::Any, "the value to fill it in with")
+            .rest(SyntaxShape::Any, "more pairs of columns and values")
+            .example("open services.json | default-columns status unknown replicas 1")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let args: Vec<&Value> = call_info.args.positional_iter().collect();
+
+        if args.is_empty() {
+            return Err(ShellError::labeled_error(
+                "default-columns needs a column to fill in",
+                "requires a column and a value",
+                &call_info.name_tag,
+            ));
+        }
+
+        for pair in args.chunks(2) {
+            match pair {
+                [field, value] => self.add_field(field, value)?,
+                [field] => {
+                    return Err(ShellError::labeled_error(
+                        "default-columns needs a value for every column",
+                        "no value for this column",
+                        &field.tag,
+                    ))
+                }
+                _ => unreachable!("chunks of two"),
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.fill(input)?)])
+    }
+}
@@ -0,0 +1,39 @@
+mod integration {
+    use crate::DefaultColumns;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+    use nu_value_ext::ValueExt;
+
+    #[test]
+    fn requires_a_value_for_every_column() {
+        plugin(&mut DefaultColumns::new())
+            .args(CallStub::new().with_positional(string("status")).create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn fills_in_the_column_of_each_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("web"));
+        });
+
+        let run = plugin(&mut DefaultColumns::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string("status"))
+                    .with_positional(string("unknown"))
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual.get_data("status").borrow(), &string("unknown"));
+    }
+}
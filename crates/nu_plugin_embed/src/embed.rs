@@ -0,0 +1,66 @@
+use nu_protocol::{TaggedDictBuilder, Value};
+
+#[derive(Default)]
+pub struct Embed {
+    pub field: Option<String>,
+}
+
+impl Embed {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn embed(&self, value: Value) -> Value {
+        let tag = value.tag();
+        let field = self.field.clone().unwrap_or_default();
+
+        let mut row = TaggedDictBuilder::new(tag);
+        row.insert_value(field, value);
+        row.into_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Embed;
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn embed_under(field: &str) -> Embed {
+        let mut embed = Embed::new();
+        embed.field = Some(field.to_string());
+        embed
+    }
+
+    #[test]
+    fn embeds_a_string() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("andres"));
+        });
+
+        assert_eq!(embed_under("name").embed(string("andres")), expected);
+    }
+
+    #[test]
+    fn embeds_an_int() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("count", int(5));
+        });
+
+        assert_eq!(embed_under("count").embed(int(5)), expected);
+    }
+
+    #[test]
+    fn embeds_an_existing_row() {
+        let inner = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("version", string("0.1.0"));
+        });
+
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("package", inner.clone());
+        });
+
+        assert_eq!(embed_under("package").embed(inner), expected);
+    }
+}
@@ -0,0 +1,4 @@
+mod embed;
+mod nu;
+
+pub use embed::Embed;
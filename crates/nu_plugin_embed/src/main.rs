@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_embed::Embed;
+
+fn main() {
+    serve_plugin(&mut Embed::new())
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Embed;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Embed {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("embed")
+            .desc("Embed each incoming value under a column of a new row.")
+            .required(
+                "field",
+                SyntaxShape::String,
+                "the name of the column to embed the value under",
+            )
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(field) => self.field = Some(field.as_string()?),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "embed needs a field name",
+                    "requires a column name",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.embed(input))])
+    }
+}
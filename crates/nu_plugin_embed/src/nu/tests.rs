@@ -0,0 +1,58 @@
+mod integration {
+    use crate::Embed;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn requires_a_field_name() {
+        plugin(&mut Embed::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn picks_up_field_name() {
+        plugin(&mut Embed::new())
+            .args(CallStub::new().with_positional(string("name")).create())
+            .setup(|plugin, _| assert_eq!(plugin.field, Some("name".to_string())));
+    }
+
+    #[test]
+    fn embeds_the_input_under_the_field() {
+        let run = plugin(&mut Embed::new())
+            .args(CallStub::new().with_positional(string("count")).create())
+            .input(int(5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(
+            actual,
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("count", int(5));
+            })
+        );
+    }
+
+    #[test]
+    fn preserves_the_tag_of_the_embedded_value() {
+        let tag = Tag::from(Span::new(3, 9));
+
+        let run = plugin(&mut Embed::new())
+            .args(CallStub::new().with_positional(string("name")).create())
+            .input(UntaggedValue::string("andres").into_value(&tag))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+        let embedded = actual.row_entries().next().map(|(_, value)| value.tag());
+
+        assert_eq!(actual.tag(), tag);
+        assert_eq!(embedded, Some(tag));
+    }
+}
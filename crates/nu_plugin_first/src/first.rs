@@ -0,0 +1,90 @@
+use nu_errors::ShellError;
+use nu_protocol::Value;
+
+pub struct First {
+    pub rows: usize,
+    pub seen: usize,
+}
+
+impl Default for First {
+    fn default() -> Self {
+        First { rows: 1, seen: 0 }
+    }
+}
+
+impl First {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets how many values to keep, which has to be at least one
+    pub fn set_rows(&mut self, rows: &Value) -> Result<(), ShellError> {
+        let count = rows.as_i64()?;
+
+        if count < 1 {
+            return Err(ShellError::labeled_error(
+                "keep-first needs to keep at least one value",
+                "expected a positive number",
+                &rows.tag,
+            ));
+        }
+
+        self.rows = count as usize;
+
+        Ok(())
+    }
+
+    /// Passes the value through while fewer than `rows` values were seen.
+    ///
+    /// Plugins can not stop the stream, so every value after those is dropped without
+    /// being looked at.
+    pub fn first(&mut self, value: Value) -> Option<Value> {
+        if self.seen >= self.rows {
+            return None;
+        }
+
+        self.seen += 1;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::First;
+    use nu_protocol::Value;
+    use nu_test_support::value::int;
+
+    fn first(rows: i64, values: Vec<Value>) -> Vec<Value> {
+        let mut first = First::new();
+        first.set_rows(&int(rows)).expect("a row count");
+
+        values
+            .into_iter()
+            .filter_map(|value| first.first(value))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_first_values() {
+        assert_eq!(first(2, vec![int(1), int(2), int(3)]), vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn keeps_one_value_by_default() {
+        let mut first = First::new();
+
+        assert_eq!(first.first(int(1)), Some(int(1)));
+        assert_eq!(first.first(int(2)), None);
+    }
+
+    #[test]
+    fn keeps_the_whole_stream_when_asked_for_more() {
+        assert_eq!(first(5, vec![int(1), int(2)]), vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn errors_on_zero_or_negative_counts() {
+        assert!(First::new().set_rows(&int(0)).is_err());
+        assert!(First::new().set_rows(&int(-3)).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod first;
+mod nu;
+
+pub use first::First;
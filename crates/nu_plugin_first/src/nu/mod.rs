@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests;
+
+use crate::First;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for First {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("keep-first")
+            .desc("Keep the first values of the stream. The rest of it is still read, since a plugin can not end the stream early.")
+            .optional(
+                "rows",
+                SyntaxShape::Int,
+                "how many values to keep (defaults to 1)",
+            )
+            .example("ls | keep-first 3")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if let Some(rows) = call_info.args.nth(0) {
+            self.set_rows(rows)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .first(input)
+            .map(ReturnSuccess::value)
+            .into_iter()
+            .collect())
+    }
+}
@@ -0,0 +1,163 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tag};
+use nu_value_ext::get_data_by_column_path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatCommand {
+    Text(String),
+    Column(String),
+}
+
+#[derive(Default)]
+pub struct Format {
+    pub commands: Vec<FormatCommand>,
+    pub tag: Tag,
+    pub empty_missing: bool,
+}
+
+impl Format {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn template(&mut self, template: &str, tag: impl Into<Tag>) {
+        self.commands = parse(template);
+        self.tag = tag.into();
+    }
+
+    pub fn render(&self, value: &Value) -> Result<Value, ShellError> {
+        let mut output = String::new();
+
+        for command in &self.commands {
+            match command {
+                FormatCommand::Text(text) => output.push_str(text),
+                FormatCommand::Column(column) => {
+                    let path = ColumnPath::build(&column.clone().spanned(self.tag.span));
+
+                    match get_data_by_column_path(value, &path, |_, _, error| error) {
+                        Ok(found) => output.push_str(&found.convert_to_string()),
+                        Err(_) if self.empty_missing => {}
+                        Err(_) => {
+                            return Err(ShellError::labeled_error(
+                                format!("format-fields could not find the field '{}'", column),
+                                "used in this template",
+                                &self.tag,
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(UntaggedValue::string(output).into_value(value.tag()))
+    }
+}
+
+fn parse(template: &str) -> Vec<FormatCommand> {
+    let mut output = vec![];
+
+    let mut chars = template.chars();
+    loop {
+        let mut before = String::new();
+
+        for c in &mut chars {
+            if c == '{' {
+                break;
+            }
+            before.push(c);
+        }
+
+        if !before.is_empty() {
+            output.push(FormatCommand::Text(before.to_string()));
+        }
+
+        let mut column = String::new();
+
+        for c in &mut chars {
+            if c == '}' {
+                break;
+            }
+            column.push(c);
+        }
+
+        if !column.is_empty() {
+            output.push(FormatCommand::Column(column.trim().to_string()));
+        }
+
+        if before.is_empty() && column.is_empty() {
+            break;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Format, FormatCommand};
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::string;
+
+    fn package() -> Value {
+        let metadata = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("version", string("0.27.2"));
+        });
+
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("package", metadata);
+        })
+    }
+
+    #[test]
+    fn parses_text_and_columns() {
+        assert_eq!(
+            parse("{name} v{package.version}"),
+            vec![
+                FormatCommand::Column("name".to_string()),
+                FormatCommand::Text(" v".to_string()),
+                FormatCommand::Column("package.version".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_fields_including_nested_ones() {
+        let mut format = Format::new();
+        format.template("{name} v{package.version}", Tag::unknown());
+
+        assert_eq!(
+            format.render(&package()).expect("rendered"),
+            string("nu v0.27.2")
+        );
+    }
+
+    #[test]
+    fn errors_on_a_missing_field() {
+        let mut format = Format::new();
+        format.template("{name} by {author}", Span::new(7, 25));
+
+        let error = format.render(&package()).expect_err("author is missing");
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(
+            diagnostic.message,
+            "format-fields could not find the field 'author'"
+        );
+        assert_eq!(diagnostic.labels[0].range, 7..25);
+    }
+
+    #[test]
+    fn renders_missing_fields_empty_when_asked() {
+        let mut format = Format::new();
+        format.template("{name} by {author}", Tag::unknown());
+        format.empty_missing = true;
+
+        assert_eq!(
+            format.render(&package()).expect("rendered"),
+            string("nu by ")
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod format;
+mod nu;
+
+pub use format::Format;
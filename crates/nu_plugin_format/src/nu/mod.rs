@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Format;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Format {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("format-fields")
+            .desc("Render the fields of each row into a template string.")
+            .required(
+                "template",
+                SyntaxShape::String,
+                "the template, with fields written as {name} or {package.version}",
+            )
+            .switch(
+                "empty-missing",
+                "render missing fields as an empty string instead of erroring",
+                Some('e'),
+            )
+            .example("open Cargo.toml | format-fields \"{package.name} v{package.version}\"")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(template) => self.template(&template.as_string()?, &template.tag),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "format-fields needs a template",
+                    "requires a template string",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.empty_missing = call_info.switch_present("empty-missing");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.render(&input)?)])
+    }
+}
@@ -0,0 +1,57 @@
+mod integration {
+    use crate::Format;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn requires_a_template() {
+        plugin(&mut Format::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn renders_each_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("version", string("0.27.2"));
+        });
+
+        let run = plugin(&mut Format::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string("{name}@{version}"))
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(row)
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("nu@0.27.2"));
+    }
+
+    #[test]
+    fn errors_when_a_field_is_missing() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        });
+
+        let run = plugin(&mut Format::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string("{name}@{version}"))
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(row)
+            .test();
+
+        assert!(run.is_err());
+    }
+}
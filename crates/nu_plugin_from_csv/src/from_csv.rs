@@ -0,0 +1,169 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Span;
+
+pub struct FromCSV {
+    pub headers: bool,
+    pub separator: u8,
+}
+
+impl Default for FromCSV {
+    fn default() -> Self {
+        FromCSV {
+            headers: false,
+            separator: b',',
+        }
+    }
+}
+
+impl FromCSV {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn separator(&mut self, separator: &str, span: Span) -> Result<(), ShellError> {
+        match separator.as_bytes() {
+            [byte] => {
+                self.separator = *byte;
+                Ok(())
+            }
+            _ => Err(ShellError::labeled_error(
+                format!("'{}' can not separate CSV fields", separator),
+                "expected a single character",
+                span,
+            )),
+        }
+    }
+
+    /// Parses the CSV into one row per record, keyed by the header line with `--headers`
+    /// and by the position of the field (`column0`, `column1`, ...) otherwise
+    pub fn from_csv(&self, value: &Value) -> Result<Vec<Value>, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(self.headers)
+            .delimiter(self.separator)
+            .flexible(true)
+            .from_reader(input.as_bytes());
+
+        let invalid = |error: csv::Error| {
+            ShellError::labeled_error(
+                format!("invalid CSV: {}", error),
+                "not valid CSV",
+                value.tag.span,
+            )
+        };
+
+        let headers = if self.headers {
+            reader
+                .headers()
+                .map_err(invalid)?
+                .iter()
+                .map(String::from)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        reader
+            .records()
+            .map(|record| {
+                let record = record.map_err(invalid)?;
+                let mut row = TaggedDictBuilder::new(&value.tag);
+
+                for (position, field) in record.iter().enumerate() {
+                    let column = match headers.get(position) {
+                        Some(header) => header.clone(),
+                        None => format!("column{}", position),
+                    };
+
+                    row.insert_untagged(column, UntaggedValue::string(field));
+                }
+
+                Ok(row.into_value())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromCSV;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::string;
+
+    fn row(columns: &[(&str, &str)]) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, field) in columns {
+                row.insert_value(*column, string(*field));
+            }
+        })
+    }
+
+    #[test]
+    fn keys_rows_by_the_header_line() {
+        let mut from_csv = FromCSV::new();
+        from_csv.headers = true;
+
+        assert_eq!(
+            from_csv.from_csv(&string("name,version\nnu,0.27.2\nserde,1.0.123\n")),
+            Ok(vec![
+                row(&[("name", "nu"), ("version", "0.27.2")]),
+                row(&[("name", "serde"), ("version", "1.0.123")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn keys_rows_by_position_without_headers() {
+        assert_eq!(
+            FromCSV::new().from_csv(&string("nu,0.27.2\n")),
+            Ok(vec![row(&[("column0", "nu"), ("column1", "0.27.2")])])
+        );
+    }
+
+    #[test]
+    fn splits_on_a_custom_separator() {
+        let mut from_csv = FromCSV::new();
+        from_csv.headers = true;
+        from_csv
+            .separator(";", Span::unknown())
+            .expect("a separator");
+
+        assert_eq!(
+            from_csv.from_csv(&string("name;version\nnu;0.27.2\n")),
+            Ok(vec![row(&[("name", "nu"), ("version", "0.27.2")])])
+        );
+    }
+
+    #[test]
+    fn keeps_separators_inside_quoted_fields() {
+        let mut from_csv = FromCSV::new();
+        from_csv.headers = true;
+
+        assert_eq!(
+            from_csv.from_csv(&string(
+                "name,description\nnu,\"a new type of shell, in rust\"\n"
+            )),
+            Ok(vec![row(&[
+                ("name", "nu"),
+                ("description", "a new type of shell, in rust"),
+            ])])
+        );
+    }
+
+    #[test]
+    fn rejects_separators_longer_than_a_character() {
+        let error = FromCSV::new()
+            .separator("::", Span::new(10, 14))
+            .unwrap_err();
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(diagnostic.message, "'::' can not separate CSV fields");
+        assert_eq!(diagnostic.labels[0].range, 10..14);
+    }
+}
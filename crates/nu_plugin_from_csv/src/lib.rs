@@ -0,0 +1,4 @@
+mod from_csv;
+mod nu;
+
+pub use from_csv::FromCSV;
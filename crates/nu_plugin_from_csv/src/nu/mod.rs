@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests;
+
+use crate::FromCSV;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for FromCSV {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("from csv-doc")
+            .desc("Parse a CSV string into a stream of rows")
+            .switch("headers", "use the first line as the column names", None)
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the character separating the fields (eg ;)",
+                Some('s'),
+            )
+            .example("open crates.csv --raw | from csv-doc --headers")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.headers = call_info.switch_present("headers");
+
+        if let Some(separator) = call_info.args.get("separator") {
+            self.separator(&separator.as_string()?, separator.tag.span)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .from_csv(&input)?
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
@@ -0,0 +1,29 @@
+mod integration {
+    use crate::FromCSV;
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_each_record_as_its_own_row() {
+        let run = plugin(&mut FromCSV::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("headers")
+                    .with_named_parameter("separator", string("\t"))
+                    .create(),
+            )
+            .input(string("name\tstars\nnu\t20\nserde\t5\n"))
+            .setup(|_, _| {})
+            .test()
+            .expect("parsed rows");
+
+        let names = run
+            .into_iter()
+            .filter_map(|returned| returned.ok().and_then(|value| value.raw_value()))
+            .map(|row| get_data(&row, "name").borrow().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec![string("nu"), string("serde")]);
+    }
+}
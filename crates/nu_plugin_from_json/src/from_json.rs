@@ -0,0 +1,148 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+
+#[derive(Default)]
+pub struct FromJSON;
+
+impl FromJSON {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_json(&self, value: Value) -> Result<Value, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let parsed = serde_json::from_str::<serde_json::Value>(input).map_err(|error| {
+            ShellError::labeled_error(
+                format!(
+                    "invalid JSON at byte {}: {}",
+                    byte_offset(input, error.line(), error.column()),
+                    error
+                ),
+                "not valid JSON",
+                value.tag.span,
+            )
+        })?;
+
+        Ok(convert(&parsed, &value.tag))
+    }
+}
+
+fn convert(json: &serde_json::Value, tag: &Tag) -> Value {
+    match json {
+        serde_json::Value::Null => UntaggedValue::nothing().into_value(tag),
+        serde_json::Value::Bool(b) => UntaggedValue::boolean(*b).into_value(tag),
+        serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => UntaggedValue::int(i).into_value(tag),
+            (_, Some(u), _) => UntaggedValue::int(u).into_value(tag),
+            (_, _, Some(f)) => UntaggedValue::decimal_from_float(f, tag.span).into_value(tag),
+            _ => UntaggedValue::nothing().into_value(tag),
+        },
+        serde_json::Value::String(s) => UntaggedValue::string(s).into_value(tag),
+        serde_json::Value::Array(list) => {
+            UntaggedValue::Table(list.iter().map(|item| convert(item, tag)).collect())
+                .into_value(tag)
+        }
+        serde_json::Value::Object(map) => {
+            let mut row = TaggedDictBuilder::new(tag);
+            for (key, item) in map {
+                row.insert_value(key.clone(), convert(item, tag));
+            }
+            row.into_value()
+        }
+    }
+}
+
+/// Turns the one-based line and column serde_json reports into a byte offset into the input
+fn byte_offset(input: &str, line: usize, column: usize) -> usize {
+    let preceding = input
+        .split('\n')
+        .take(line.saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum::<usize>();
+
+    std::cmp::min(preceding + column.saturating_sub(1), input.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_offset, FromJSON};
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{decimal_from_float, int, string};
+
+    fn parse(json: &str) -> Value {
+        FromJSON::new().from_json(string(json)).expect("valid JSON")
+    }
+
+    #[test]
+    fn parses_an_object() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("stars", int(20));
+            row.insert_value("ratio", decimal_from_float(0.5));
+            row.insert_value(
+                "stable",
+                UntaggedValue::boolean(false).into_untagged_value(),
+            );
+            row.insert_value(
+                "tags",
+                UntaggedValue::table(&[string("shell"), string("rust")]).into_untagged_value(),
+            );
+        });
+
+        assert_eq!(
+            parse(
+                r#"{"name": "nu", "stars": 20, "ratio": 0.5, "stable": false, "tags": ["shell", "rust"]}"#
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn sorts_the_keys() {
+        let actual = parse(r#"{"zeta": 1, "alpha": 2}"#);
+
+        let keys = actual
+            .row_entries()
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, vec!["alpha".to_string(), "zeta".to_string()]);
+    }
+
+    #[test]
+    fn parses_integral_numbers_as_ints() {
+        assert_eq!(
+            parse("18446744073709551615"),
+            UntaggedValue::int(std::u64::MAX).into_untagged_value()
+        );
+        assert_eq!(parse("-3"), int(-3));
+        assert_eq!(parse("2.5"), decimal_from_float(2.5));
+    }
+
+    #[test]
+    fn errors_on_malformed_input_with_the_byte_offset() {
+        let json = UntaggedValue::string(r#"{"name": ?}"#).into_value(Span::new(4, 15));
+
+        let error = FromJSON::new().from_json(json).unwrap_err();
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert!(diagnostic.message.starts_with("invalid JSON at byte 9:"));
+        assert_eq!(diagnostic.labels[0].range, 4..15);
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_strings() {
+        assert!(FromJSON::new().from_json(int(5)).is_err());
+    }
+
+    #[test]
+    fn finds_the_byte_offset_on_later_lines() {
+        assert_eq!(byte_offset("{\n  \"a\": ?\n}", 2, 8), 9);
+    }
+}
@@ -0,0 +1,4 @@
+mod from_json;
+mod nu;
+
+pub use from_json::FromJSON;
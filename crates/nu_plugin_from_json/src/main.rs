@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_from_json::FromJSON;
+
+fn main() {
+    serve_plugin(&mut FromJSON::new())
+}
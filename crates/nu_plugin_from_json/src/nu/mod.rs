@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::FromJSON;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for FromJSON {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("from json-doc")
+            .desc("Parse a JSON string into a table")
+            .example("open package.json --raw | from json-doc")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.from_json(input)?)])
+    }
+}
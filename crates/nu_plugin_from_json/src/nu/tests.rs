@@ -0,0 +1,32 @@
+mod integration {
+    use crate::FromJSON;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_the_parsed_object() {
+        let run = plugin(&mut FromJSON::new())
+            .args(CallStub::new().create())
+            .input(string(r#"{"name": "nu", "version": {"major": 0}}"#))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+        let version = get_data(&actual, "version").borrow().clone();
+
+        assert_eq!(get_data(&actual, "name").borrow(), &string("nu"));
+        assert_eq!(get_data(&version, "major").borrow(), &int(0));
+    }
+
+    #[test]
+    fn reports_malformed_input_as_errors() {
+        let run = plugin(&mut FromJSON::new())
+            .args(CallStub::new().create())
+            .input(string("{\"name\": "))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+}
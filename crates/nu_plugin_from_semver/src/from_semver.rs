@@ -0,0 +1,104 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct FromSemVer;
+
+impl FromSemVer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_semver(&self, value: Value) -> Result<Value, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let version = semver::Version::parse(input.trim()).map_err(|_| {
+            ShellError::labeled_error(
+                format!("'{}' is not a valid semantic version", input),
+                "not a semantic version",
+                value.tag.span,
+            )
+        })?;
+
+        let mut row = TaggedDictBuilder::new(value.tag());
+
+        row.insert_untagged("major", UntaggedValue::int(version.major));
+        row.insert_untagged("minor", UntaggedValue::int(version.minor));
+        row.insert_untagged("patch", UntaggedValue::int(version.patch));
+        row.insert_untagged("prerelease", UntaggedValue::string(joined(&version.pre)));
+        row.insert_untagged("build", UntaggedValue::string(joined(&version.build)));
+
+        Ok(row.into_value())
+    }
+}
+
+/// Joins the dot-separated identifiers of a prerelease or build back together
+fn joined(identifiers: &[semver::Identifier]) -> String {
+    identifiers
+        .iter()
+        .map(|identifier| identifier.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromSemVer;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn parses_a_full_version() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("major", int(1));
+            row.insert_value("minor", int(2));
+            row.insert_value("patch", int(3));
+            row.insert_value("prerelease", string("alpha.1"));
+            row.insert_value("build", string("build.5"));
+        });
+
+        assert_eq!(
+            FromSemVer::new().from_semver(string("1.2.3-alpha.1+build.5")),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn leaves_prerelease_and_build_empty_when_absent() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("major", int(0));
+            row.insert_value("minor", int(27));
+            row.insert_value("patch", int(2));
+            row.insert_value("prerelease", string(""));
+            row.insert_value("build", string(""));
+        });
+
+        assert_eq!(
+            FromSemVer::new().from_semver(string("0.27.2")),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn errors_on_an_invalid_version_at_its_span() {
+        let version = UntaggedValue::string("1.2.x").into_value(Span::new(5, 10));
+
+        let error = FromSemVer::new().from_semver(version).unwrap_err();
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(
+            diagnostic.message,
+            "'1.2.x' is not a valid semantic version"
+        );
+        assert_eq!(diagnostic.labels[0].range, 5..10);
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_strings() {
+        assert!(FromSemVer::new().from_semver(int(1)).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod from_semver;
+mod nu;
+
+pub use from_semver::FromSemVer;
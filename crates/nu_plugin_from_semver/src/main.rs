@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_from_semver::FromSemVer;
+
+fn main() {
+    serve_plugin(&mut FromSemVer::new())
+}
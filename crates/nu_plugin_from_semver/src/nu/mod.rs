@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::FromSemVer;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for FromSemVer {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("from semver")
+            .desc("Parse a semantic version string into its major, minor, patch, prerelease and build parts")
+            .example("echo 1.2.3-alpha.1 | from semver")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.from_semver(input)?)])
+    }
+}
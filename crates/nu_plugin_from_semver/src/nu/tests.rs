@@ -0,0 +1,32 @@
+mod integration {
+    use crate::FromSemVer;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_the_version_parts_as_a_row() {
+        let run = plugin(&mut FromSemVer::new())
+            .args(CallStub::new().create())
+            .input(string("2.0.1-rc.1+sha.5114f85"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "major").borrow(), &int(2));
+        assert_eq!(get_data(&actual, "prerelease").borrow(), &string("rc.1"));
+        assert_eq!(get_data(&actual, "build").borrow(), &string("sha.5114f85"));
+    }
+
+    #[test]
+    fn reports_invalid_versions_as_errors() {
+        let run = plugin(&mut FromSemVer::new())
+            .args(CallStub::new().create())
+            .input(string("not a version"))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+}
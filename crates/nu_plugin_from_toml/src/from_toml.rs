@@ -0,0 +1,163 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+
+#[derive(Default)]
+pub struct FromTOML;
+
+impl FromTOML {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_toml(&self, value: Value) -> Result<Value, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let parsed = input.parse::<toml::Value>().map_err(|error| {
+            let message = match error.line_col() {
+                Some((line, column)) => format!(
+                    "invalid TOML at line {}, column {}: {}",
+                    line + 1,
+                    column + 1,
+                    error
+                ),
+                None => format!("invalid TOML: {}", error),
+            };
+
+            ShellError::labeled_error(message, "not valid TOML", value.tag.span)
+        })?;
+
+        Ok(convert(&parsed, &value.tag))
+    }
+}
+
+fn convert(toml: &toml::Value, tag: &Tag) -> Value {
+    match toml {
+        toml::Value::String(s) => UntaggedValue::string(s).into_value(tag),
+        toml::Value::Integer(i) => UntaggedValue::int(*i).into_value(tag),
+        toml::Value::Float(f) => UntaggedValue::decimal_from_float(*f, tag.span).into_value(tag),
+        toml::Value::Boolean(b) => UntaggedValue::boolean(*b).into_value(tag),
+        toml::Value::Datetime(datetime) => {
+            // local dates and times have no offset, so they stay strings
+            let text = datetime.to_string();
+            UntaggedValue::date_from_str(&text, tag.span)
+                .unwrap_or_else(|_| UntaggedValue::string(text))
+                .into_value(tag)
+        }
+        toml::Value::Array(list) => {
+            UntaggedValue::Table(list.iter().map(|item| convert(item, tag)).collect())
+                .into_value(tag)
+        }
+        toml::Value::Table(table) => {
+            let mut row = TaggedDictBuilder::new(tag);
+            for (key, item) in table {
+                row.insert_value(key.clone(), convert(item, tag));
+            }
+            row.into_value()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromTOML;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{decimal_from_float, int, string};
+
+    const MANIFEST: &str = r#"
+[package]
+name = "nu"
+version = "0.27.2"
+publish = false
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+
+[profile.release]
+opt-level = 3
+lto = 0.5
+"#;
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    #[test]
+    fn parses_a_cargo_manifest() {
+        let expected = row(vec![
+            (
+                "package",
+                row(vec![
+                    ("name", string("nu")),
+                    ("version", string("0.27.2")),
+                    (
+                        "publish",
+                        UntaggedValue::boolean(false).into_untagged_value(),
+                    ),
+                ]),
+            ),
+            (
+                "dependencies",
+                row(vec![(
+                    "serde",
+                    row(vec![
+                        ("version", string("1.0")),
+                        (
+                            "features",
+                            UntaggedValue::table(&[string("derive")]).into_untagged_value(),
+                        ),
+                    ]),
+                )]),
+            ),
+            (
+                "profile",
+                row(vec![(
+                    "release",
+                    row(vec![
+                        ("opt-level", int(3)),
+                        ("lto", decimal_from_float(0.5)),
+                    ]),
+                )]),
+            ),
+        ]);
+
+        assert_eq!(FromTOML::new().from_toml(string(MANIFEST)), Ok(expected));
+    }
+
+    #[test]
+    fn parses_offset_datetimes_into_dates() {
+        let actual = FromTOML::new()
+            .from_toml(string("released = 2021-02-23T09:15:00+00:00"))
+            .expect("valid TOML");
+
+        let expected = row(vec![(
+            "released",
+            UntaggedValue::date_from_str("2021-02-23T09:15:00+00:00", Span::unknown())
+                .expect("a date")
+                .into_untagged_value(),
+        )]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_with_the_line_and_column() {
+        let toml = UntaggedValue::string("[package]\nname = nu\n").into_value(Span::new(3, 23));
+
+        let error = FromTOML::new().from_toml(toml).unwrap_err();
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert!(diagnostic
+            .message
+            .starts_with("invalid TOML at line 2, column 8:"));
+        assert_eq!(diagnostic.labels[0].range, 3..23);
+    }
+}
@@ -0,0 +1,4 @@
+mod from_toml;
+mod nu;
+
+pub use from_toml::FromTOML;
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::FromTOML;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for FromTOML {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("from toml-doc")
+            .desc("Parse a TOML string into a table")
+            .example("open Cargo.toml --raw | from toml-doc | inc --patch package.version")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.from_toml(input)?)])
+    }
+}
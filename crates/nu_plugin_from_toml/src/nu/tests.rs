@@ -0,0 +1,31 @@
+mod integration {
+    use crate::FromTOML;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_the_parsed_table() {
+        let run = plugin(&mut FromTOML::new())
+            .args(CallStub::new().create())
+            .input(string("[package]\nversion = \"0.27.2\"\n"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+        let package = get_data(&actual, "package").borrow().clone();
+
+        assert_eq!(get_data(&package, "version").borrow(), &string("0.27.2"));
+    }
+
+    #[test]
+    fn reports_invalid_toml_as_errors() {
+        let run = plugin(&mut FromTOML::new())
+            .args(CallStub::new().create())
+            .input(string("[package"))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+}
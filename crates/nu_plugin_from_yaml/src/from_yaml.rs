@@ -0,0 +1,157 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tag;
+use serde::Deserialize;
+
+#[derive(Default)]
+pub struct FromYAML;
+
+impl FromYAML {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Parses every document of the YAML stream into its own value, expanding aliases
+    pub fn from_yaml(&self, value: &Value) -> Result<Vec<Value>, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        serde_yaml::Deserializer::from_str(input)
+            .map(|document| {
+                let parsed = serde_yaml::Value::deserialize(document).map_err(|error| {
+                    ShellError::labeled_error(
+                        format!("invalid YAML: {}", error),
+                        "not valid YAML",
+                        value.tag.span,
+                    )
+                })?;
+
+                convert(&parsed, &value.tag)
+            })
+            .collect()
+    }
+}
+
+fn convert(yaml: &serde_yaml::Value, tag: &Tag) -> Result<Value, ShellError> {
+    Ok(match yaml {
+        serde_yaml::Value::Null => UntaggedValue::nothing().into_value(tag),
+        serde_yaml::Value::Bool(b) => UntaggedValue::boolean(*b).into_value(tag),
+        serde_yaml::Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => UntaggedValue::int(i).into_value(tag),
+            (_, Some(u), _) => UntaggedValue::int(u).into_value(tag),
+            (_, _, Some(f)) => UntaggedValue::decimal_from_float(f, tag.span).into_value(tag),
+            _ => UntaggedValue::nothing().into_value(tag),
+        },
+        serde_yaml::Value::String(s) => UntaggedValue::string(s).into_value(tag),
+        serde_yaml::Value::Sequence(list) => UntaggedValue::Table(
+            list.iter()
+                .map(|item| convert(item, tag))
+                .collect::<Result<Vec<_>, ShellError>>()?,
+        )
+        .into_value(tag),
+        serde_yaml::Value::Mapping(mapping) => {
+            let mut row = TaggedDictBuilder::new(tag);
+            for (key, item) in mapping {
+                row.insert_value(column_name(key, tag)?, convert(item, tag)?);
+            }
+            row.into_value()
+        }
+    })
+}
+
+fn column_name(key: &serde_yaml::Value, tag: &Tag) -> Result<String, ShellError> {
+    match key {
+        serde_yaml::Value::String(s) => Ok(s.clone()),
+        serde_yaml::Value::Bool(b) => Ok(b.to_string()),
+        serde_yaml::Value::Number(n) => Ok(n.to_string()),
+        _ => Err(ShellError::labeled_error(
+            "YAML keys must be strings, numbers or booleans to become column names",
+            "unsupported key",
+            &tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromYAML;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    #[test]
+    fn parses_a_nested_mapping() {
+        let yaml = "
+apiVersion: v1
+kind: Pod
+spec:
+  containers:
+    - name: nu
+      ports:
+        - 8080
+";
+
+        let expected = row(vec![
+            ("apiVersion", string("v1")),
+            ("kind", string("Pod")),
+            (
+                "spec",
+                row(vec![(
+                    "containers",
+                    UntaggedValue::table(&[row(vec![
+                        ("name", string("nu")),
+                        (
+                            "ports",
+                            UntaggedValue::table(&[int(8080)]).into_untagged_value(),
+                        ),
+                    ])])
+                    .into_untagged_value(),
+                )]),
+            ),
+        ]);
+
+        assert_eq!(FromYAML::new().from_yaml(&string(yaml)), Ok(vec![expected]));
+    }
+
+    #[test]
+    fn parses_each_document_of_a_stream() {
+        let yaml = "---\nkind: Service\n---\nkind: Deployment\n";
+
+        assert_eq!(
+            FromYAML::new().from_yaml(&string(yaml)),
+            Ok(vec![
+                row(vec![("kind", string("Service"))]),
+                row(vec![("kind", string("Deployment"))]),
+            ])
+        );
+    }
+
+    #[test]
+    fn expands_aliases() {
+        let yaml = "base: &base\n  image: nu\nother: *base\n";
+
+        let actual = FromYAML::new()
+            .from_yaml(&string(yaml))
+            .expect("valid YAML");
+
+        assert_eq!(
+            nu_value_ext::get_data(&actual[0], "other").borrow(),
+            &row(vec![("image", string("nu"))])
+        );
+    }
+
+    #[test]
+    fn errors_on_malformed_input() {
+        assert!(FromYAML::new().from_yaml(&string("kind: [Pod")).is_err());
+    }
+}
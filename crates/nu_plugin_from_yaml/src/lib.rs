@@ -0,0 +1,4 @@
+mod from_yaml;
+mod nu;
+
+pub use from_yaml::FromYAML;
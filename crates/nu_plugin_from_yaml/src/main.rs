@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_from_yaml::FromYAML;
+
+fn main() {
+    serve_plugin(&mut FromYAML::new())
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests;
+
+use crate::FromYAML;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for FromYAML {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("from yaml-doc")
+            .desc("Parse a YAML string into a table, one per document")
+            .example("open deployment.yaml --raw | from yaml-doc")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .from_yaml(&input)?
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
@@ -0,0 +1,26 @@
+mod integration {
+    use crate::FromYAML;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    fn run() -> Result<Vec<nu_protocol::ReturnValue>, nu_errors::ShellError> {
+        plugin(&mut FromYAML::new())
+            .args(CallStub::new().create())
+            .input(string("kind: Service\n---\nkind: Deployment\n"))
+            .setup(|_, _| {})
+            .test()
+    }
+
+    #[test]
+    fn emits_each_document_separately() {
+        let service = expect_return_value_at(run(), 0);
+        let deployment = expect_return_value_at(run(), 1);
+
+        assert_eq!(get_data(&service, "kind").borrow(), &string("Service"));
+        assert_eq!(
+            get_data(&deployment, "kind").borrow(),
+            &string("Deployment")
+        );
+    }
+}
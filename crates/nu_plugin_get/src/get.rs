@@ -0,0 +1,96 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Value};
+use nu_source::Tagged;
+use nu_value_ext::get_data_by_column_path;
+
+#[derive(Default)]
+pub struct Get {
+    pub path: Option<Tagged<ColumnPath>>,
+    pub ignore_missing: bool,
+}
+
+impl Get {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the value at the path, or nothing when it is missing and `--ignore-missing`
+    /// was given.
+    pub fn get(&self, value: &Value) -> Result<Option<Value>, ShellError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Err(ShellError::untagged_runtime_error("get-field needs a path")),
+        };
+
+        match get_data_by_column_path(value, path, |_, _, error| error) {
+            Ok(found) => Ok(Some(found)),
+            Err(_) if self.ignore_missing => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Get;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, string};
+    use nu_value_ext::ValueExt;
+
+    fn get(path: &str) -> Get {
+        let mut get = Get::new();
+        get.path = Some(column_path(path).as_column_path().expect("a column path"));
+        get
+    }
+
+    fn manifest() -> Value {
+        let dependency = |name: &str| {
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("name", string(name));
+            })
+        };
+
+        let package = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        });
+
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("package", package);
+            row.insert_value(
+                "dependencies",
+                UntaggedValue::table(&[dependency("serde"), dependency("rayon")])
+                    .into_untagged_value(),
+            );
+        })
+    }
+
+    #[test]
+    fn projects_a_nested_field() {
+        assert_eq!(
+            get("package.name").get(&manifest()).expect("found"),
+            Some(string("nu"))
+        );
+    }
+
+    #[test]
+    fn projects_through_an_array_index() {
+        assert_eq!(
+            get("dependencies.1.name").get(&manifest()).expect("found"),
+            Some(string("rayon"))
+        );
+    }
+
+    #[test]
+    fn errors_on_a_missing_path() {
+        assert!(get("package.version").get(&manifest()).is_err());
+    }
+
+    #[test]
+    fn skips_a_missing_path_when_ignored() {
+        let mut get = get("package.version");
+        get.ignore_missing = true;
+
+        assert_eq!(get.get(&manifest()).expect("ignored"), None);
+    }
+}
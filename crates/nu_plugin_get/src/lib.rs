@@ -0,0 +1,4 @@
+mod get;
+mod nu;
+
+pub use get::Get;
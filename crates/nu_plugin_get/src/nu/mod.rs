@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Get;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Get {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("get-field")
+            .desc("Project a single field out of each row.")
+            .required(
+                "path",
+                SyntaxShape::ColumnPath,
+                "the path to the field, such as package.name or dependencies.0",
+            )
+            .switch(
+                "ignore-missing",
+                "skip the rows that do not have the path instead of erroring",
+                Some('i'),
+            )
+            .example("open Cargo.toml | get-field package.version")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(path) => self.path = Some(path.as_column_path()?),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "get-field needs a path",
+                    "requires a column path",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.ignore_missing = call_info.switch_present("ignore-missing");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .get(&input)?
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
@@ -0,0 +1,53 @@
+mod integration {
+    use crate::Get;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, string};
+
+    fn package() -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        })
+    }
+
+    #[test]
+    fn requires_a_path() {
+        plugin(&mut Get::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn emits_the_projected_value() {
+        let run = plugin(&mut Get::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("name"))
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(package())
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("nu"));
+    }
+
+    #[test]
+    fn emits_nothing_for_a_missing_path_when_ignored() {
+        let run = plugin(&mut Get::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("version"))
+                    .with_long_flag("ignore-missing")
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(package())
+            .test();
+
+        assert_eq!(run.expect("no error").len(), 0);
+    }
+}
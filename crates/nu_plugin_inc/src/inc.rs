@@ -1,11 +1,21 @@
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDate};
 use nu_errors::ShellError;
-use nu_protocol::{did_you_mean, ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
-use nu_source::{span_for_spanned_list, HasSpan, SpannedItem, Tagged};
-use nu_value_ext::{get_data_by_column_path, ValueExt};
+use nu_protocol::{ColumnPath, Primitive, ShellTypeName, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{HasSpan, Span, SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::{
+    get_data_by_column_path, get_data_by_column_path_or_suggest, update_data_at_column_path,
+    ValueExt,
+};
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Action {
     SemVerAction(SemVerAction),
+    Date,
     Default,
 }
 
@@ -14,11 +24,95 @@ pub enum SemVerAction {
     Major,
     Minor,
     Patch,
+    Prerelease,
+    Identifier(String),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum OutputType {
+    Int,
+    String,
+}
+
+impl OutputType {
+    pub fn parse(name: &str, span: Span) -> Result<OutputType, ShellError> {
+        match name {
+            "int" => Ok(OutputType::Int),
+            "string" => Ok(OutputType::String),
+            other => Err(ShellError::labeled_error(
+                format!("'{}' is not an output type", other),
+                "expected int or string",
+                span,
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RoundMode {
+    HalfUp,
+    HalfEven,
+}
+
+impl RoundMode {
+    pub fn parse(name: &str, span: Span) -> Result<RoundMode, ShellError> {
+        match name {
+            "half-up" => Ok(RoundMode::HalfUp),
+            "half-even" | "bankers" => Ok(RoundMode::HalfEven),
+            other => Err(ShellError::labeled_error(
+                format!("'{}' is not a rounding mode", other),
+                "expected half-up or half-even",
+                span,
+            )),
+        }
+    }
+}
+
+/// What to do with rows missing the field to increment
+#[derive(Debug, Eq, PartialEq)]
+pub enum NullAs {
+    Skip,
+    Error,
+    Zero,
+}
+
+impl NullAs {
+    pub fn parse(name: &str, span: Span) -> Result<NullAs, ShellError> {
+        match name {
+            "skip" => Ok(NullAs::Skip),
+            "error" => Ok(NullAs::Error),
+            "zero" => Ok(NullAs::Zero),
+            other => Err(ShellError::labeled_error(
+                format!("'{}' is not a way to treat missing fields", other),
+                "expected skip, error or zero",
+                span,
+            )),
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct Inc {
-    pub field: Option<Tagged<ColumnPath>>,
+    pub fields: Vec<Tagged<ColumnPath>>,
+    pub step: Option<i64>,
+    pub reset_to: Option<u64>,
+    pub decrement: bool,
+    pub float: bool,
+    pub pad: bool,
+    pub wrapping: bool,
+    pub saturating: bool,
+    pub output_type: Option<OutputType>,
+    pub round: Option<i64>,
+    pub round_mode: Option<RoundMode>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub null_as: Option<NullAs>,
+    pub strict: bool,
+    pub lenient_semver: bool,
+    pub verbose: bool,
+    pub dry_run: bool,
+    pub auto: bool,
+    pub ignore_errors: bool,
     pub error: Option<String>,
     pub action: Option<Action>,
 }
@@ -28,26 +122,260 @@ impl Inc {
         Default::default()
     }
 
-    fn apply(&self, input: &str) -> UntaggedValue {
-        match &self.action {
+    fn apply(&self, input: &str, span: Span) -> Result<UntaggedValue, ShellError> {
+        Ok(match &self.action {
             Some(Action::SemVerAction(act_on)) => {
-                let mut ver = match semver::Version::parse(&input) {
-                    Ok(parsed_ver) => parsed_ver,
-                    Err(_) => return UntaggedValue::string(input.to_string()),
+                // values from padded columns still parse, only non-versions are passed through as-is
+                let (mut ver, parts) = match self.parse_version(input) {
+                    Some(parsed) => parsed,
+                    None if self.strict => {
+                        return Err(ShellError::labeled_error(
+                            format!("'{}' is not a valid semantic version", input),
+                            "not a semantic version",
+                            span,
+                        ))
+                    }
+                    None => return Ok(UntaggedValue::string(input.to_string())),
                 };
 
                 match act_on {
-                    SemVerAction::Major => ver.increment_major(),
-                    SemVerAction::Minor => ver.increment_minor(),
+                    SemVerAction::Major => {
+                        ver.increment_major();
+
+                        if let Some(reset_to) = self.reset_to {
+                            ver.minor = reset_to;
+                            ver.patch = reset_to;
+                        }
+                    }
+                    SemVerAction::Minor => {
+                        ver.increment_minor();
+
+                        if let Some(reset_to) = self.reset_to {
+                            ver.patch = reset_to;
+                        }
+                    }
                     SemVerAction::Patch => ver.increment_patch(),
+                    SemVerAction::Prerelease => increment_prerelease(&mut ver, span)?,
+                    SemVerAction::Identifier(name) => {
+                        if !increment_identifier(&mut ver, name, span)? {
+                            return Err(ShellError::labeled_error(
+                                format!("'{}' has no prerelease identifier '{}'", input, name),
+                                "identifier not found",
+                                span,
+                            ));
+                        }
+                    }
                 }
 
-                UntaggedValue::string(ver.to_string())
+                UntaggedValue::string(format_version(&ver, parts))
             }
+            Some(Action::Date) => match NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+                Ok(date) => {
+                    // no two dates are further apart than an i32 of days, and larger durations
+                    // would panic inside chrono
+                    let shifted = self
+                        .delta()
+                        .to_i32()
+                        .and_then(|days| date.checked_add_signed(Duration::days(days.into())));
+
+                    match shifted {
+                        Some(shifted) => {
+                            UntaggedValue::string(shifted.format("%Y-%m-%d").to_string())
+                        }
+                        None => {
+                            return Err(ShellError::labeled_error(
+                                format!(
+                                    "moving '{}' by {} days is out of range for a date",
+                                    input,
+                                    self.delta()
+                                ),
+                                "date out of range",
+                                span,
+                            ))
+                        }
+                    }
+                }
+                Err(_) => {
+                    return Err(ShellError::labeled_error(
+                        format!("'{}' is not a valid date (expected YYYY-MM-DD)", input),
+                        "not a date",
+                        span,
+                    ))
+                }
+            },
+            Some(Action::Default) | None if self.float => self.apply_float(input),
             Some(Action::Default) | None => match input.parse::<u64>() {
-                Ok(v) => UntaggedValue::string(format!("{}", v + 1)),
+                Ok(v) => {
+                    // numeric strings can not go below zero either, so decrementing saturates
+                    let number = std::cmp::max(BigInt::from(v) + self.delta(), BigInt::zero());
+
+                    match number.to_u64() {
+                        Some(number) => self.numeric(input, number),
+                        None => {
+                            return Err(ShellError::labeled_error(
+                                format!(
+                                    "incrementing '{}' by {} overflows a 64-bit unsigned integer",
+                                    input,
+                                    self.delta()
+                                ),
+                                "overflows",
+                                span,
+                            ))
+                        }
+                    }
+                }
                 Err(_) => UntaggedValue::string(input),
             },
+        })
+    }
+
+    /// Parses the version along with how many of its major, minor and patch parts were given,
+    /// which with `--lenient-semver` can be fewer than three (like "1.2")
+    fn parse_version(&self, input: &str) -> Option<(semver::Version, usize)> {
+        let input = input.trim();
+
+        if let Ok(version) = semver::Version::parse(input) {
+            return Some((version, 3));
+        }
+
+        if !self.lenient_semver {
+            return None;
+        }
+
+        let parts = input.split('.').collect::<Vec<&str>>();
+        let numeric = |part: &&str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+        if parts.len() > 2 || !parts.iter().all(numeric) {
+            return None;
+        }
+
+        let padded = format!("{}{}", input, ".0".repeat(3 - parts.len()));
+
+        semver::Version::parse(&padded)
+            .ok()
+            .map(|version| (version, parts.len()))
+    }
+
+    /// Takes the `--prefix` and `--suffix` off the string before incrementing it and puts them
+    /// back on the result, so "v1.2.3" increments like "1.2.3" does
+    fn apply_affixed(&self, input: &str, span: Span) -> Result<UntaggedValue, ShellError> {
+        let prefix = match &self.prefix {
+            Some(prefix) if input.starts_with(prefix.as_str()) => prefix.as_str(),
+            _ => "",
+        };
+        let rest = &input[prefix.len()..];
+        let suffix = match &self.suffix {
+            Some(suffix) if rest.ends_with(suffix.as_str()) => suffix.as_str(),
+            _ => "",
+        };
+
+        if prefix.is_empty() && suffix.is_empty() {
+            return self.apply(input, span);
+        }
+
+        let incremented = match self.apply(&rest[..rest.len() - suffix.len()], span)? {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+            other => return Ok(other),
+        };
+
+        Ok(UntaggedValue::string(format!(
+            "{}{}{}",
+            prefix, incremented, suffix
+        )))
+    }
+
+    fn numeric(&self, input: &str, number: u64) -> UntaggedValue {
+        match self.output_type {
+            Some(OutputType::Int) => UntaggedValue::int(number),
+            Some(OutputType::String) => UntaggedValue::string(self.padded(input, number)),
+            // zero-padding only survives in a string
+            None if self.pad => UntaggedValue::string(self.padded(input, number)),
+            // "5" increments like 5 does
+            None => UntaggedValue::int(number),
+        }
+    }
+
+    /// Zero-pads the number to the width of the input with `--pad`, growing it if it no longer fits
+    fn padded(&self, input: &str, number: u64) -> String {
+        if self.pad {
+            format!("{:0width$}", number, width = input.len())
+        } else {
+            format!("{}", number)
+        }
+    }
+
+    fn apply_float(&self, input: &str) -> UntaggedValue {
+        if self.round.is_some() {
+            // f64 can not hold ties like 1.005 exactly, so rounding has to happen in decimal
+            return match BigDecimal::from_str(input) {
+                Ok(v) => UntaggedValue::string(
+                    self.rounded(v + BigDecimal::new(self.delta(), 0))
+                        .to_string(),
+                ),
+                Err(_) => UntaggedValue::string(input),
+            };
+        }
+
+        match input.parse::<f64>() {
+            Ok(v) if v.is_finite() => {
+                // keep as many decimal places as the input had
+                let precision = input
+                    .split('.')
+                    .nth(1)
+                    .map(|decimals| decimals.chars().take_while(|c| c.is_ascii_digit()).count())
+                    .unwrap_or(0);
+
+                UntaggedValue::string(format!(
+                    "{:.*}",
+                    precision,
+                    v + self.delta().to_f64().unwrap_or_default()
+                ))
+            }
+            _ => UntaggedValue::string(input),
+        }
+    }
+
+    /// Adds the step to the integer, which errors when the result leaves the 64-bit range
+    /// unless `--wrapping` or `--saturating` say how to bring it back
+    fn add_int(&self, number: &BigInt, span: Span) -> Result<BigInt, ShellError> {
+        let sum = number + self.delta();
+
+        if sum.to_i64().is_some() {
+            return Ok(sum);
+        }
+
+        let (min, max) = (BigInt::from(i64::MIN), BigInt::from(i64::MAX));
+
+        if self.saturating {
+            Ok(if sum < min { min } else { max })
+        } else if self.wrapping {
+            let range = &max - &min + 1;
+            let mut offset = (sum - &min) % &range;
+
+            if offset.is_negative() {
+                offset += &range;
+            }
+
+            Ok(offset + min)
+        } else {
+            Err(ShellError::labeled_error(
+                format!("incrementing {} overflows a 64-bit integer", number),
+                "overflows, use --wrapping or --saturating to allow it",
+                span,
+            ))
+        }
+    }
+
+    /// Rounds the decimal to the places given with `--round`, leaving it exact without
+    fn rounded(&self, number: BigDecimal) -> BigDecimal {
+        match self.round {
+            Some(places) => round_decimal(
+                &number,
+                places,
+                self.round_mode.as_ref().unwrap_or(&RoundMode::HalfUp),
+            ),
+            None => number,
         }
     }
 
@@ -59,6 +387,32 @@ impl Inc {
         }
     }
 
+    pub fn step(&self) -> i64 {
+        self.step.unwrap_or(1)
+    }
+
+    /// The step to add, which is negated when decrementing. It is a `BigInt` since negating
+    /// `--by -9223372036854775808` leaves the 64-bit range.
+    fn delta(&self) -> BigInt {
+        if self.decrement {
+            -BigInt::from(self.step())
+        } else {
+            BigInt::from(self.step())
+        }
+    }
+
+    pub fn for_date(&mut self) {
+        if self.permit() {
+            self.action = Some(Action::Date);
+        } else {
+            self.log_error("can only apply one");
+        }
+    }
+
+    pub fn is_semver(&self) -> bool {
+        matches!(self.action, Some(Action::SemVerAction(_)))
+    }
+
     fn permit(&mut self) -> bool {
         self.action.is_none()
     }
@@ -67,80 +421,306 @@ impl Inc {
         self.error = Some(message.to_string());
     }
 
-    pub fn usage() -> &'static str {
-        "Usage: inc field [--major|--minor|--patch]"
+    fn inc_field(&self, value: &Value, field: &Tagged<ColumnPath>) -> Result<Value, ShellError> {
+        update_data_at_column_path(value, field, |found| self.inc_found(found, field))
+    }
+
+    /// Looks the field up in the row and increments it, returning what was found there
+    /// together with its replacement
+    fn field_change(
+        &self,
+        value: &Value,
+        field: &Tagged<ColumnPath>,
+    ) -> Result<(Value, Value), ShellError> {
+        let got = get_data_by_column_path_or_suggest(value, field)?;
+        let replacement = self.inc_found(got.clone(), field)?;
+
+        Ok((got, replacement))
+    }
+
+    /// Increments what was found at the field, naming the field in any error
+    fn inc_found(&self, found: Value, field: &Tagged<ColumnPath>) -> Result<Value, ShellError> {
+        self.inc(found).map_err(|err| {
+            err.with_context(format!("while incrementing field '{}'", path_name(field)))
+        })
+    }
+
+    /// The fields to increment in the row, which `--auto` finds when none were given
+    fn fields_of(&self, value: &Value) -> Result<Vec<Tagged<ColumnPath>>, ShellError> {
+        if self.fields.is_empty() && self.auto {
+            return Ok(vec![self.detect_field(value)?]);
+        }
+
+        if self.fields.is_empty() {
+            return Err(ShellError::labeled_error(
+                "inc needs a field when incrementing a column in a table",
+                "requires a column path",
+                value.tag(),
+            ));
+        }
+
+        Ok(self.fields.clone())
+    }
+
+    /// Describes what incrementing the value would change, as rows of the `path` incremented
+    /// (empty for values that are not rows) and the values it goes `from` and `to`
+    pub fn plan(&self, value: &Value) -> Result<Vec<Value>, ShellError> {
+        let change = |path: String, from: Value, to: Value| {
+            let mut row = TaggedDictBuilder::new(value.tag());
+            row.insert_untagged("path", UntaggedValue::string(path));
+            row.insert_value("from", from);
+            row.insert_value("to", to);
+            row.into_value()
+        };
+
+        if !value.is_row() {
+            let to = self.inc(value.clone())?;
+            return Ok(vec![change(String::new(), value.clone(), to)]);
+        }
+
+        self.fields_of(value)?
+            .iter()
+            .map(|field| -> Result<Value, ShellError> {
+                let (from, to) = self.field_change(value, field)?;
+                Ok(change(path_name(field), from, to))
+            })
+            .collect()
+    }
+
+    /// Finds the one column of a row holding a string that looks like a semantic version
+    fn detect_field(&self, value: &Value) -> Result<Tagged<ColumnPath>, ShellError> {
+        let candidates = value
+            .row_entries()
+            .filter(|(_, column)| match &column.value {
+                UntaggedValue::Primitive(Primitive::String(s)) => self.parse_version(s).is_some(),
+                _ => false,
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+
+        match candidates.as_slice() {
+            [name] => {
+                Ok(ColumnPath::build(&name.clone().spanned(value.span())).tagged(value.tag()))
+            }
+            [] => Err(ShellError::labeled_error(
+                "inc --auto found no column holding a version",
+                "no version in this row",
+                value.tag(),
+            )),
+            _ => Err(ShellError::labeled_error(
+                format!(
+                    "inc --auto found several columns holding a version: {}",
+                    candidates.join(", ")
+                ),
+                "pass the column to increment",
+                value.tag(),
+            )),
+        }
     }
 
     pub fn inc(&self, value: Value) -> Result<Value, ShellError> {
         match &value.value {
             UntaggedValue::Primitive(Primitive::Int(i)) => {
-                Ok(UntaggedValue::int(i + 1).into_value(value.tag()))
+                Ok(UntaggedValue::int(self.add_int(i, value.span())?).into_value(value.tag()))
             }
-            UntaggedValue::Primitive(Primitive::Filesize(b)) => {
-                Ok(UntaggedValue::filesize(b + 1_u64).into_value(value.tag()))
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+                let incremented = self.rounded(d + BigDecimal::new(self.delta(), 0));
+                Ok(UntaggedValue::decimal(incremented).into_value(value.tag()))
             }
-            UntaggedValue::Primitive(Primitive::String(ref s)) => {
-                Ok(self.apply(&s).into_value(value.tag()))
+            UntaggedValue::Primitive(Primitive::Filesize(b)) => {
+                // filesizes can not go below zero, so decrementing saturates
+                let size = std::cmp::max(b + self.delta(), 0.into());
+                Ok(UntaggedValue::filesize(size).into_value(value.tag()))
             }
+            UntaggedValue::Primitive(Primitive::String(ref s)) => Ok(self
+                .apply_affixed(&s, value.span())?
+                .into_value(value.tag())),
             UntaggedValue::Table(values) => {
-                if values.len() == 1 {
-                    Ok(UntaggedValue::Table(vec![self.inc(values[0].clone())?])
-                        .into_value(value.tag()))
-                } else {
-                    Err(ShellError::type_error(
-                        "incrementable value",
-                        value.type_name().spanned(value.span()),
-                    ))
-                }
+                let incremented = values
+                    .iter()
+                    .map(|element| self.inc(element.clone()))
+                    .collect::<Result<Vec<Value>, ShellError>>()?;
+
+                Ok(UntaggedValue::Table(incremented).into_value(value.tag()))
             }
 
-            UntaggedValue::Row(_) => match self.field {
-                Some(ref f) => {
-                    let fields = f.clone();
-
-                    let replace_for = get_data_by_column_path(
-                        &value,
-                        &f,
-                        move |obj_source, column_path_tried, _| match did_you_mean(
-                            &obj_source,
-                            column_path_tried.as_string(),
-                        ) {
-                            Some(suggestions) => ShellError::labeled_error(
-                                "Unknown column",
-                                format!("did you mean '{}'?", suggestions[0]),
-                                span_for_spanned_list(fields.iter().map(|p| p.span)),
-                            ),
-                            None => ShellError::labeled_error(
-                                "Unknown column",
-                                "row does not contain this column",
-                                span_for_spanned_list(fields.iter().map(|p| p.span)),
-                            ),
-                        },
-                    );
-
-                    let got = replace_for?;
-                    let replacement = self.inc(got)?;
-
-                    value
-                        .replace_data_at_column_path(&f, replacement.value.into_untagged_value())
-                        .ok_or_else(|| {
-                            ShellError::labeled_error(
-                                "inc could not find field to replace",
-                                "column name",
-                                value.tag(),
-                            )
-                        })
+            UntaggedValue::Row(_) => {
+                // the rewritten row keeps the tag of the input row, so errors downstream
+                // still point at where the row came from
+                let mut result = value.clone();
+
+                for field in &self.fields_of(&value)? {
+                    let missing = get_data_by_column_path(&result, field, |_, _, err| err).is_err();
+
+                    result = match &self.null_as {
+                        Some(NullAs::Skip) if missing => result,
+                        Some(NullAs::Zero) if missing => {
+                            let zero = UntaggedValue::int(0).into_value(value.tag());
+                            result.insert_data_at_column_path(field, self.inc(zero)?)?
+                        }
+                        _ => self.inc_field(&result, field)?,
+                    };
                 }
-                None => Err(ShellError::untagged_runtime_error(
-                    "inc needs a field when incrementing a column in a table",
-                )),
-            },
+
+                Ok(result.value.into_value(value.tag()))
+            }
             _ => Err(ShellError::type_error(
                 "incrementable value",
                 value.type_name().spanned(value.span()),
             )),
         }
     }
+
+    /// Increments the value the way the plugin filters it, passing it through unchanged on
+    /// errors with `--ignore-errors`
+    pub fn transform(&self, value: Tagged<Value>) -> Result<Tagged<Value>, ShellError> {
+        let tag = value.tag;
+
+        match self.inc(value.item.clone()) {
+            Ok(incremented) => Ok(incremented.tagged(tag)),
+            // keep the stream flowing by passing the value through untouched
+            Err(_) if self.ignore_errors => Ok(value),
+            Err(reason) => Err(reason),
+        }
+    }
+}
+
+/// Increments the value with the given action the way `inc` does with no other flags, going
+/// into the column path given as `field` (like `package.version`) when there is one.
+///
+/// This is the transform behind the plugin, for callers that have no use for the protocol.
+pub fn increment(
+    value: Tagged<Value>,
+    action: Action,
+    field: Option<&str>,
+) -> Result<Tagged<Value>, ShellError> {
+    let mut inc = Inc::new();
+    inc.action = Some(action);
+
+    if let Some(field) = field {
+        let path = ColumnPath::build(&field.to_string().spanned(value.tag.span));
+        inc.fields.push(path.tagged(&value.tag));
+    }
+
+    inc.transform(value)
+}
+
+/// Writes the version back with as many parts as it was given with, unless that would drop
+/// parts that are not zero
+fn format_version(ver: &semver::Version, parts: usize) -> String {
+    let droppable = ver.pre.is_empty()
+        && ver.build.is_empty()
+        && match parts {
+            1 => ver.minor == 0 && ver.patch == 0,
+            2 => ver.patch == 0,
+            _ => false,
+        };
+
+    if !droppable {
+        return ver.to_string();
+    }
+
+    [ver.major, ver.minor, ver.patch][..parts]
+        .iter()
+        .map(|part| part.to_string())
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+fn path_name(field: &Tagged<ColumnPath>) -> String {
+    field
+        .iter()
+        .map(|member| member.as_string())
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// Rounds the decimal to the given number of places, breaking ties away from zero for
+/// `HalfUp` and towards the even neighbour for `HalfEven`
+fn round_decimal(number: &BigDecimal, places: i64, mode: &RoundMode) -> BigDecimal {
+    let (digits, scale) = number.as_bigint_and_exponent();
+
+    if scale <= places {
+        return number.with_scale(places);
+    }
+
+    let two = BigInt::from(2);
+    let divisor = num_traits::pow(BigInt::from(10), (scale - places) as usize);
+    let quotient = &digits / &divisor;
+    let twice_remainder = (&digits % &divisor).abs() * &two;
+
+    let away_from_zero = match twice_remainder.cmp(&divisor) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => match mode {
+            RoundMode::HalfUp => true,
+            RoundMode::HalfEven => !(&quotient % &two).is_zero(),
+        },
+    };
+
+    let rounded = if away_from_zero {
+        quotient + digits.signum()
+    } else {
+        quotient
+    };
+
+    BigDecimal::new(rounded, places)
+}
+
+fn increment_prerelease(ver: &mut semver::Version, span: Span) -> Result<(), ShellError> {
+    use semver::Identifier;
+
+    match ver.pre.last_mut() {
+        Some(Identifier::Numeric(n)) => bump(n, span)?,
+        Some(Identifier::AlphaNumeric(_)) => ver.pre.push(Identifier::Numeric(1)),
+        None => {
+            ver.pre = vec![
+                Identifier::AlphaNumeric("alpha".to_string()),
+                Identifier::Numeric(1),
+            ]
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds one to a numeric prerelease identifier, erroring instead of overflowing
+fn bump(number: &mut u64, span: Span) -> Result<(), ShellError> {
+    *number = number.checked_add(1).ok_or_else(|| {
+        ShellError::labeled_error(
+            format!("the prerelease number {} can not be incremented", number),
+            "prerelease number overflows",
+            span,
+        )
+    })?;
+
+    Ok(())
+}
+
+/// Bumps the number following the named prerelease identifier, returning false when the
+/// version has no such identifier
+fn increment_identifier(
+    ver: &mut semver::Version,
+    name: &str,
+    span: Span,
+) -> Result<bool, ShellError> {
+    use semver::Identifier;
+
+    let position = match ver
+        .pre
+        .iter()
+        .position(|identifier| matches!(identifier, Identifier::AlphaNumeric(s) if s == name))
+    {
+        Some(position) => position + 1,
+        None => return Ok(false),
+    };
+
+    match ver.pre.get_mut(position) {
+        Some(Identifier::Numeric(n)) => bump(n, span)?,
+        _ => ver.pre.insert(position, Identifier::Numeric(1)),
+    }
+
+    Ok(true)
 }
 
 #[cfg(test)]
@@ -148,27 +728,1000 @@ mod tests {
     mod semver {
         use crate::inc::SemVerAction;
         use crate::Inc;
-        use nu_test_support::value::string;
+        use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+        use nu_source::{Span, Tag};
+        use nu_test_support::value::{column_path, string};
 
         #[test]
         fn major() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Major);
-            assert_eq!(inc.apply("0.1.3"), string("1.0.0").value);
+            assert_eq!(
+                inc.apply("0.1.3", Span::unknown()),
+                Ok(string("1.0.0").value)
+            );
         }
 
         #[test]
         fn minor() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Minor);
-            assert_eq!(inc.apply("0.1.3"), string("0.2.0").value);
+            assert_eq!(
+                inc.apply("0.1.3", Span::unknown()),
+                Ok(string("0.2.0").value)
+            );
+        }
+
+        #[test]
+        fn major_resets_minor_and_patch_to_the_value_given() {
+            let mut inc = Inc::new();
+            inc.reset_to = Some(1);
+            inc.for_semver(SemVerAction::Major);
+            assert_eq!(
+                inc.apply("0.4.3-beta.2", Span::unknown()),
+                Ok(string("1.1.1").value)
+            );
+        }
+
+        #[test]
+        fn minor_resets_patch_to_the_value_given() {
+            let mut inc = Inc::new();
+            inc.reset_to = Some(1);
+            inc.for_semver(SemVerAction::Minor);
+            assert_eq!(
+                inc.apply("0.1.3", Span::unknown()),
+                Ok(string("0.2.1").value)
+            );
         }
 
         #[test]
         fn patch() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Patch);
-            assert_eq!(inc.apply("0.1.3"), string("0.1.4").value);
+            assert_eq!(
+                inc.apply("0.1.3", Span::unknown()),
+                Ok(string("0.1.4").value)
+            );
+        }
+
+        #[test]
+        fn prerelease() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Prerelease);
+            assert_eq!(
+                inc.apply("1.2.3-alpha.1", Span::unknown()),
+                Ok(string("1.2.3-alpha.2").value)
+            );
+        }
+
+        #[test]
+        fn prerelease_starts_at_alpha_one() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Prerelease);
+            assert_eq!(
+                inc.apply("1.2.3", Span::unknown()),
+                Ok(string("1.2.3-alpha.1").value)
+            );
+        }
+
+        #[test]
+        fn prerelease_appends_numeric_identifier() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Prerelease);
+            assert_eq!(
+                inc.apply("1.2.3-beta", Span::unknown()),
+                Ok(string("1.2.3-beta.1").value)
+            );
+        }
+
+        #[test]
+        fn errors_on_a_prerelease_number_that_can_not_grow() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Prerelease);
+
+            let error = inc
+                .apply(&format!("1.2.3-alpha.{}", u64::MAX), Span::new(2, 12))
+                .unwrap_err();
+            let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+            assert_eq!(
+                diagnostic.message,
+                format!("the prerelease number {} can not be incremented", u64::MAX)
+            );
+            assert_eq!(diagnostic.labels[0].range, 2..12);
+        }
+
+        #[test]
+        fn identifier() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Identifier("beta".to_string()));
+            assert_eq!(
+                inc.apply("1.0.0-beta.3", Span::unknown()),
+                Ok(string("1.0.0-beta.4").value)
+            );
+        }
+
+        #[test]
+        fn identifier_in_the_middle_of_the_prerelease() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Identifier("beta".to_string()));
+            assert_eq!(
+                inc.apply("1.0.0-beta.3.rc.1", Span::unknown()),
+                Ok(string("1.0.0-beta.4.rc.1").value)
+            );
+        }
+
+        #[test]
+        fn identifier_without_a_number_starts_at_one() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Identifier("beta".to_string()));
+            assert_eq!(
+                inc.apply("1.0.0-beta", Span::unknown()),
+                Ok(string("1.0.0-beta.1").value)
+            );
+        }
+
+        #[test]
+        fn errors_on_a_missing_identifier() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Identifier("beta".to_string()));
+
+            let error = inc.apply("1.0.0-rc.1", Span::new(2, 12)).unwrap_err();
+            let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+            assert_eq!(
+                diagnostic.message,
+                "'1.0.0-rc.1' has no prerelease identifier 'beta'"
+            );
+            assert_eq!(diagnostic.labels[0].range, 2..12);
+        }
+
+        #[test]
+        fn errors_on_an_identifier_number_that_can_not_grow() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Identifier("beta".to_string()));
+
+            let actual = format!(
+                "{:?}",
+                inc.apply(&format!("1.0.0-beta.{}.rc.1", u64::MAX), Span::unknown())
+                    .unwrap_err()
+            );
+
+            assert!(actual.contains("prerelease number overflows"));
+        }
+
+        #[test]
+        fn lenient_semver_increments_two_part_versions() {
+            let mut inc = Inc::new();
+            inc.lenient_semver = true;
+            inc.for_semver(SemVerAction::Minor);
+            assert_eq!(inc.apply("1.2", Span::unknown()), Ok(string("1.3").value));
+
+            let mut inc = Inc::new();
+            inc.lenient_semver = true;
+            inc.for_semver(SemVerAction::Major);
+            assert_eq!(inc.apply("7", Span::unknown()), Ok(string("8").value));
+        }
+
+        #[test]
+        fn lenient_semver_keeps_the_parts_it_can_not_drop() {
+            let mut inc = Inc::new();
+            inc.lenient_semver = true;
+            inc.for_semver(SemVerAction::Patch);
+            assert_eq!(inc.apply("1.2", Span::unknown()), Ok(string("1.2.1").value));
+
+            let mut inc = Inc::new();
+            inc.lenient_semver = true;
+            inc.for_semver(SemVerAction::Prerelease);
+            assert_eq!(
+                inc.apply("1.2", Span::unknown()),
+                Ok(string("1.2.0-alpha.1").value)
+            );
+        }
+
+        #[test]
+        fn lenient_semver_leaves_full_versions_and_non_versions_alone() {
+            let mut inc = Inc::new();
+            inc.lenient_semver = true;
+            inc.for_semver(SemVerAction::Minor);
+            assert_eq!(
+                inc.apply("1.2.0", Span::unknown()),
+                Ok(string("1.3.0").value)
+            );
+            assert_eq!(inc.apply("1.x", Span::unknown()), Ok(string("1.x").value));
+        }
+
+        #[test]
+        fn passes_through_two_part_versions_without_lenient_semver() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Minor);
+            assert_eq!(inc.apply("1.2", Span::unknown()), Ok(string("1.2").value));
+        }
+
+        #[test]
+        fn trims_whitespace_around_a_version() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            assert_eq!(
+                inc.apply("  0.1.3", Span::unknown()),
+                Ok(string("0.1.4").value)
+            );
+            assert_eq!(
+                inc.apply("0.1.3 \n", Span::unknown()),
+                Ok(string("0.1.4").value)
+            );
+        }
+
+        #[test]
+        fn passes_through_invalid_version_with_its_whitespace() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            assert_eq!(
+                inc.apply(" latest ", Span::unknown()),
+                Ok(string(" latest ").value)
+            );
+        }
+
+        #[test]
+        fn passes_through_invalid_version() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            assert_eq!(
+                inc.apply("1.2.x", Span::unknown()),
+                Ok(string("1.2.x").value)
+            );
+        }
+
+        #[test]
+        fn errors_on_invalid_version_when_strict() {
+            let mut inc = Inc::new();
+            inc.strict = true;
+            inc.for_semver(SemVerAction::Patch);
+
+            let actual = format!("{:?}", inc.apply("1.2.x", Span::unknown()).unwrap_err());
+
+            assert!(actual.contains("'1.2.x' is not a valid semantic version"));
+        }
+
+        #[test]
+        fn invalid_version_error_points_at_the_value() {
+            let mut inc = Inc::new();
+            inc.strict = true;
+            inc.for_semver(SemVerAction::Patch);
+
+            let version = UntaggedValue::string("1.2.x").into_value(Span::new(4, 9));
+            let error = inc.inc(version).unwrap_err();
+            let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+            assert_eq!(diagnostic.labels[0].range, 4..9);
+        }
+
+        #[test]
+        fn field_errors_name_the_field_being_incremented() {
+            let mut inc = Inc::new();
+            inc.strict = true;
+            inc.for_semver(SemVerAction::Patch);
+            inc.fields = vec![column_path("package.version")
+                .as_column_path()
+                .expect("a column path")];
+
+            let manifest = TaggedDictBuilder::build(Tag::unknown(), |manifest| {
+                manifest.insert_value(
+                    "package",
+                    TaggedDictBuilder::build(Tag::unknown(), |package| {
+                        package.insert_value("version", string("1.2.x"));
+                    }),
+                );
+            });
+
+            let actual = format!("{}", inc.inc(manifest).unwrap_err());
+
+            assert!(actual.contains("while incrementing field 'package.version'"));
+        }
+    }
+
+    mod auto {
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+        use nu_protocol::{TaggedDictBuilder, Value};
+        use nu_source::Tag;
+        use nu_test_support::value::{int, string};
+        use nu_value_ext::get_data;
+
+        fn auto_patch() -> Inc {
+            let mut inc = Inc::new();
+            inc.auto = true;
+            inc.for_semver(SemVerAction::Patch);
+            inc
+        }
+
+        fn package(columns: &[(&str, Value)]) -> Value {
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                for (name, value) in columns {
+                    row.insert_value(*name, value.clone());
+                }
+            })
+        }
+
+        #[test]
+        fn increments_the_only_version_column() {
+            let row = package(&[
+                ("name", string("nu")),
+                ("version", string("0.27.2")),
+                ("stars", int(5)),
+            ]);
+
+            let actual = auto_patch().inc(row).expect("an incremented row");
+
+            assert_eq!(get_data(&actual, "version").borrow(), &string("0.27.3"));
+            assert_eq!(get_data(&actual, "name").borrow(), &string("nu"));
+        }
+
+        #[test]
+        fn lists_the_candidates_when_several_columns_hold_a_version() {
+            let row = package(&[("version", string("0.27.2")), ("msrv", string("1.50.0"))]);
+
+            let actual = format!("{:?}", auto_patch().inc(row).unwrap_err());
+
+            assert!(actual.contains("several columns holding a version: version, msrv"));
+        }
+
+        #[test]
+        fn errors_when_no_column_holds_a_version() {
+            let row = package(&[("name", string("nu"))]);
+
+            assert!(auto_patch().inc(row).is_err());
+        }
+    }
+
+    mod tags {
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+        use nu_protocol::{TaggedDictBuilder, Value};
+        use nu_source::{AnchorLocation, Span, Tag};
+        use nu_test_support::value::{column_path, string};
+        use nu_value_ext::ValueExt;
+
+        fn manifest_tag() -> Tag {
+            Tag {
+                anchor: Some(AnchorLocation::File("Cargo.toml".to_string())),
+                span: Span::new(4, 40),
+            }
+        }
+
+        fn package() -> Value {
+            TaggedDictBuilder::build(manifest_tag(), |row| {
+                row.insert_value("version", string("0.27.2"));
+            })
+        }
+
+        #[test]
+        fn keeps_the_tag_of_the_row_for_a_field() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.fields = vec![column_path("version")
+                .as_column_path()
+                .expect("a column path")];
+
+            let actual = inc.inc(package()).expect("an incremented row");
+
+            assert_eq!(actual.tag, manifest_tag());
+        }
+
+        #[test]
+        fn keeps_the_tag_of_the_row_for_a_detected_field() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.auto = true;
+
+            let actual = inc.inc(package()).expect("an incremented row");
+
+            assert_eq!(actual.tag, manifest_tag());
+        }
+    }
+
+    mod date {
+        use crate::Inc;
+        use nu_source::Span;
+        use nu_test_support::value::string;
+
+        #[test]
+        fn next_day() {
+            let mut inc = Inc::new();
+            inc.for_date();
+            assert_eq!(
+                inc.apply("2019-03-01", Span::unknown()),
+                Ok(string("2019-03-02").value)
+            );
+        }
+
+        #[test]
+        fn rolls_over_end_of_month() {
+            let mut inc = Inc::new();
+            inc.for_date();
+            assert_eq!(
+                inc.apply("2019-02-28", Span::unknown()),
+                Ok(string("2019-03-01").value)
+            );
+        }
+
+        #[test]
+        fn rolls_over_end_of_year() {
+            let mut inc = Inc::new();
+            inc.for_date();
+            assert_eq!(
+                inc.apply("2019-12-31", Span::unknown()),
+                Ok(string("2020-01-01").value)
+            );
+        }
+
+        #[test]
+        fn errors_on_non_date_strings() {
+            let mut inc = Inc::new();
+            inc.for_date();
+
+            let actual = format!("{:?}", inc.apply("5", Span::unknown()).unwrap_err());
+
+            assert!(actual.contains("'5' is not a valid date"));
+        }
+
+        #[test]
+        fn errors_on_steps_past_the_range_of_dates() {
+            let mut inc = Inc::new();
+            inc.for_date();
+
+            for step in &[i64::MAX, 1_000_000_000] {
+                inc.step = Some(*step);
+
+                let actual = format!(
+                    "{:?}",
+                    inc.apply("2019-03-01", Span::unknown()).unwrap_err()
+                );
+
+                assert!(actual.contains("out of range for a date"));
+            }
+        }
+    }
+
+    mod default {
+        use crate::inc::OutputType;
+        use crate::Inc;
+        use nu_protocol::UntaggedValue;
+        use nu_source::Span;
+        use nu_test_support::value::string;
+
+        #[test]
+        fn decrement() {
+            let mut inc = Inc::new();
+            inc.decrement = true;
+            assert_eq!(inc.apply("5", Span::unknown()), Ok(UntaggedValue::int(4)));
+        }
+
+        #[test]
+        fn decrement_saturates_at_zero() {
+            let mut inc = Inc::new();
+            inc.decrement = true;
+            assert_eq!(inc.apply("0", Span::unknown()), Ok(UntaggedValue::int(0)));
+        }
+
+        #[test]
+        fn decrement_by_step() {
+            let mut inc = Inc::new();
+            inc.decrement = true;
+            inc.step = Some(3);
+            assert_eq!(inc.apply("5", Span::unknown()), Ok(UntaggedValue::int(2)));
+            assert_eq!(inc.apply("2", Span::unknown()), Ok(UntaggedValue::int(0)));
+        }
+
+        #[test]
+        fn decrement_by_the_smallest_step() {
+            let mut inc = Inc::new();
+            inc.decrement = true;
+            inc.step = Some(i64::MIN);
+            assert_eq!(
+                inc.apply("5", Span::unknown()),
+                Ok(UntaggedValue::int(9_223_372_036_854_775_813u64))
+            );
+        }
+
+        #[test]
+        fn errors_past_the_largest_numeric_string() {
+            let inc = Inc::new();
+
+            let actual = format!(
+                "{:?}",
+                inc.apply(&u64::MAX.to_string(), Span::unknown())
+                    .unwrap_err()
+            );
+
+            assert!(actual.contains("overflows a 64-bit unsigned integer"));
+        }
+
+        #[test]
+        fn numeric_strings_increment_into_ints() {
+            let inc = Inc::new();
+            assert_eq!(inc.apply("5", Span::unknown()), Ok(UntaggedValue::int(6)));
+            assert_eq!(inc.apply("41", Span::unknown()), Ok(UntaggedValue::int(42)));
+        }
+
+        #[test]
+        fn non_numeric_strings_stay_strings() {
+            let inc = Inc::new();
+            assert_eq!(inc.apply("5a", Span::unknown()), Ok(string("5a").value));
+        }
+
+        #[test]
+        fn output_type_int() {
+            let mut inc = Inc::new();
+            inc.output_type = Some(OutputType::Int);
+            assert_eq!(inc.apply("5", Span::unknown()), Ok(UntaggedValue::int(6)));
+        }
+
+        #[test]
+        fn output_type_string() {
+            let mut inc = Inc::new();
+            inc.output_type = Some(OutputType::String);
+            assert_eq!(inc.apply("5", Span::unknown()), Ok(string("6").value));
+        }
+
+        #[test]
+        fn output_type_does_not_apply_to_non_numeric_strings() {
+            let mut inc = Inc::new();
+            inc.output_type = Some(OutputType::Int);
+            assert_eq!(inc.apply("nu", Span::unknown()), Ok(string("nu").value));
+        }
+
+        #[test]
+        fn rejects_unknown_output_types() {
+            let error = OutputType::parse("float", Span::new(3, 8)).unwrap_err();
+            let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+            assert_eq!(diagnostic.message, "'float' is not an output type");
+            assert_eq!(diagnostic.labels[0].range, 3..8);
+        }
+
+        #[test]
+        fn pad_preserves_the_width() {
+            let mut inc = Inc::new();
+            inc.pad = true;
+            assert_eq!(inc.apply("007", Span::unknown()), Ok(string("008").value));
+        }
+
+        #[test]
+        fn pad_grows_past_the_width() {
+            let mut inc = Inc::new();
+            inc.pad = true;
+            assert_eq!(inc.apply("099", Span::unknown()), Ok(string("100").value));
+            assert_eq!(inc.apply("999", Span::unknown()), Ok(string("1000").value));
+        }
+
+        #[test]
+        fn pad_preserves_the_width_when_decrementing() {
+            let mut inc = Inc::new();
+            inc.pad = true;
+            inc.decrement = true;
+            assert_eq!(inc.apply("010", Span::unknown()), Ok(string("009").value));
+        }
+
+        #[test]
+        fn drops_padding_without_pad() {
+            let inc = Inc::new();
+            assert_eq!(inc.apply("007", Span::unknown()), Ok(UntaggedValue::int(8)));
+        }
+
+        #[test]
+        fn float() {
+            let mut inc = Inc::new();
+            inc.float = true;
+            assert_eq!(inc.apply("3.14", Span::unknown()), Ok(string("4.14").value));
+        }
+
+        #[test]
+        fn float_by_step() {
+            let mut inc = Inc::new();
+            inc.float = true;
+            inc.step = Some(2);
+            assert_eq!(inc.apply("1.5", Span::unknown()), Ok(string("3.5").value));
+        }
+
+        #[test]
+        fn float_passes_through_non_finite_values() {
+            let mut inc = Inc::new();
+            inc.float = true;
+            assert_eq!(inc.apply("NaN", Span::unknown()), Ok(string("NaN").value));
+            assert_eq!(inc.apply("inf", Span::unknown()), Ok(string("inf").value));
+        }
+    }
+
+    mod round {
+        use crate::inc::RoundMode;
+        use crate::Inc;
+        use bigdecimal::BigDecimal;
+        use nu_protocol::{UntaggedValue, Value};
+        use nu_source::Span;
+        use nu_test_support::value::string;
+        use std::str::FromStr;
+
+        fn decimal(number: &str) -> Value {
+            UntaggedValue::decimal(BigDecimal::from_str(number).expect("a decimal"))
+                .into_untagged_value()
+        }
+
+        fn rounding(places: i64, mode: Option<RoundMode>) -> Inc {
+            let mut inc = Inc::new();
+            inc.round = Some(places);
+            inc.round_mode = mode;
+            inc
+        }
+
+        #[test]
+        fn keeps_the_precision_without_round() {
+            assert_eq!(Inc::new().inc(decimal("1.125")), Ok(decimal("2.125")));
+        }
+
+        #[test]
+        fn rounds_ties_half_up_by_default() {
+            let inc = rounding(2, None);
+
+            assert_eq!(inc.inc(decimal("1.125")), Ok(decimal("2.13")));
+            assert_eq!(inc.inc(decimal("1.135")), Ok(decimal("2.14")));
+            assert_eq!(inc.inc(decimal("-4.125")), Ok(decimal("-3.13")));
+        }
+
+        #[test]
+        fn rounds_ties_to_even_with_half_even() {
+            let inc = rounding(2, Some(RoundMode::HalfEven));
+
+            assert_eq!(inc.inc(decimal("1.125")), Ok(decimal("2.12")));
+            assert_eq!(inc.inc(decimal("1.135")), Ok(decimal("2.14")));
+            assert_eq!(inc.inc(decimal("-4.125")), Ok(decimal("-3.12")));
+        }
+
+        #[test]
+        fn rounds_values_off_a_tie_to_the_nearest() {
+            let inc = rounding(1, Some(RoundMode::HalfEven));
+
+            assert_eq!(inc.inc(decimal("1.26")), Ok(decimal("2.3")));
+            assert_eq!(inc.inc(decimal("1.24")), Ok(decimal("2.2")));
+        }
+
+        #[test]
+        fn pads_values_with_fewer_places() {
+            assert_eq!(rounding(3, None).inc(decimal("1.5")), Ok(decimal("2.500")));
+        }
+
+        #[test]
+        fn rounds_decimal_strings_exactly() {
+            let mut inc = rounding(2, None);
+            inc.float = true;
+            assert_eq!(
+                inc.apply("1.005", Span::unknown()),
+                Ok(string("2.01").value)
+            );
+
+            inc.round_mode = Some(RoundMode::HalfEven);
+            assert_eq!(
+                inc.apply("1.005", Span::unknown()),
+                Ok(string("2.00").value)
+            );
+        }
+
+        #[test]
+        fn rejects_unknown_rounding_modes() {
+            let error = RoundMode::parse("ceiling", Span::new(2, 9)).unwrap_err();
+            let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+            assert_eq!(diagnostic.message, "'ceiling' is not a rounding mode");
+            assert_eq!(diagnostic.labels[0].range, 2..9);
+        }
+    }
+
+    mod overflow {
+        use crate::Inc;
+        use nu_test_support::value::int;
+
+        #[test]
+        fn errors_by_default() {
+            assert!(Inc::new().inc(int(i64::MAX)).is_err());
+
+            let mut inc = Inc::new();
+            inc.decrement = true;
+            assert!(inc.inc(int(i64::MIN)).is_err());
+        }
+
+        #[test]
+        fn wraps_around_with_wrapping() {
+            let mut inc = Inc::new();
+            inc.wrapping = true;
+            assert_eq!(inc.inc(int(i64::MAX)), Ok(int(i64::MIN)));
+
+            inc.step = Some(3);
+            inc.decrement = true;
+            assert_eq!(inc.inc(int(i64::MIN + 1)), Ok(int(i64::MAX - 1)));
+        }
+
+        #[test]
+        fn stops_at_the_bounds_with_saturating() {
+            let mut inc = Inc::new();
+            inc.saturating = true;
+            assert_eq!(inc.inc(int(i64::MAX)), Ok(int(i64::MAX)));
+
+            inc.decrement = true;
+            assert_eq!(inc.inc(int(i64::MIN)), Ok(int(i64::MIN)));
+        }
+
+        #[test]
+        fn leaves_results_in_range_alone() {
+            let mut inc = Inc::new();
+            inc.saturating = true;
+            assert_eq!(inc.inc(int(i64::MAX - 1)), Ok(int(i64::MAX)));
+        }
+    }
+
+    mod null_as {
+        use crate::inc::NullAs;
+        use crate::Inc;
+        use nu_protocol::{TaggedDictBuilder, Value};
+        use nu_source::Tag;
+        use nu_test_support::value::{column_path, int, string};
+        use nu_value_ext::{get_data, ValueExt};
+
+        fn counting(null_as: NullAs) -> Inc {
+            let mut inc = Inc::new();
+            inc.null_as = Some(null_as);
+            inc.fields = vec![column_path("count")
+                .as_column_path()
+                .expect("a column path")];
+            inc
+        }
+
+        fn uncounted() -> Value {
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("name", string("nu"));
+            })
+        }
+
+        #[test]
+        fn skip_passes_the_row_through_unchanged() {
+            assert_eq!(counting(NullAs::Skip).inc(uncounted()), Ok(uncounted()));
+        }
+
+        #[test]
+        fn error_fails_on_the_missing_field() {
+            let actual = format!(
+                "{:?}",
+                counting(NullAs::Error).inc(uncounted()).unwrap_err()
+            );
+
+            assert!(actual.contains("Unknown column 'count'"));
+        }
+
+        #[test]
+        fn zero_increments_the_missing_field_into_existence() {
+            let actual = counting(NullAs::Zero)
+                .inc(uncounted())
+                .expect("an incremented row");
+
+            assert_eq!(get_data(&actual, "count").borrow(), &int(1));
+            assert_eq!(get_data(&actual, "name").borrow(), &string("nu"));
+        }
+
+        #[test]
+        fn zero_leaves_present_fields_to_the_increment() {
+            let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("count", int(4));
+            });
+
+            let actual = counting(NullAs::Zero).inc(row).expect("an incremented row");
+
+            assert_eq!(get_data(&actual, "count").borrow(), &int(5));
+        }
+    }
+
+    mod list {
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+        use nu_protocol::{UntaggedValue, Value};
+        use nu_test_support::value::{int, string};
+
+        fn list(values: Vec<Value>) -> Value {
+            UntaggedValue::table(&values).into_untagged_value()
+        }
+
+        #[test]
+        fn increments_every_version_in_a_list() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+
+            let actual = inc.inc(list(vec![
+                string("0.1.3"),
+                string("1.0.0"),
+                string("2.7.9"),
+            ]));
+
+            assert_eq!(
+                actual,
+                Ok(list(vec![
+                    string("0.1.4"),
+                    string("1.0.1"),
+                    string("2.7.10"),
+                ]))
+            );
+        }
+
+        #[test]
+        fn increments_nested_lists() {
+            let inc = Inc::new();
+
+            let actual = inc.inc(list(vec![int(1), list(vec![int(2), int(3)])]));
+
+            assert_eq!(actual, Ok(list(vec![int(2), list(vec![int(3), int(4)])])));
+        }
+
+        #[test]
+        fn follows_the_strict_rules_for_each_element() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+
+            let versions = list(vec![string("0.1.3"), string("latest")]);
+
+            assert_eq!(
+                inc.inc(versions.clone()),
+                Ok(list(vec![string("0.1.4"), string("latest")]))
+            );
+
+            inc.strict = true;
+            assert!(inc.inc(versions).is_err());
+        }
+    }
+
+    mod affixes {
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+        use nu_test_support::value::{int, string};
+
+        #[test]
+        fn keeps_the_prefix_of_a_version() {
+            let mut inc = Inc::new();
+            inc.prefix = Some("v".to_string());
+            inc.for_semver(SemVerAction::Patch);
+
+            assert_eq!(inc.inc(string("v1.2.3")), Ok(string("v1.2.4")));
+        }
+
+        #[test]
+        fn keeps_the_suffix_of_a_number() {
+            let mut inc = Inc::new();
+            inc.suffix = Some("px".to_string());
+
+            assert_eq!(inc.inc(string("9px")), Ok(string("10px")));
+        }
+
+        #[test]
+        fn keeps_both_around_a_padded_number() {
+            let mut inc = Inc::new();
+            inc.prefix = Some("build-".to_string());
+            inc.suffix = Some(".log".to_string());
+            inc.pad = true;
+
+            assert_eq!(
+                inc.inc(string("build-007.log")),
+                Ok(string("build-008.log"))
+            );
+        }
+
+        #[test]
+        fn increments_strings_without_them_as_usual() {
+            let mut inc = Inc::new();
+            inc.prefix = Some("v".to_string());
+            inc.for_semver(SemVerAction::Patch);
+
+            assert_eq!(inc.inc(string("1.2.3")), Ok(string("1.2.4")));
+
+            let mut inc = Inc::new();
+            inc.suffix = Some("px".to_string());
+
+            assert_eq!(inc.inc(string("9")), Ok(int(10)));
+        }
+    }
+
+    mod dry_run {
+        use crate::inc::SemVerAction;
+        use crate::Inc;
+        use nu_protocol::{TaggedDictBuilder, Value};
+        use nu_source::Tag;
+        use nu_test_support::value::{column_path, int, string};
+        use nu_value_ext::{get_data, ValueExt};
+
+        fn manifest() -> Value {
+            TaggedDictBuilder::build(Tag::unknown(), |manifest| {
+                manifest.insert_value(
+                    "package",
+                    TaggedDictBuilder::build(Tag::unknown(), |package| {
+                        package.insert_value("version", string("0.27.2"));
+                    }),
+                );
+            })
+        }
+
+        #[test]
+        fn describes_the_change_to_a_field() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            inc.fields = vec![column_path("package.version")
+                .as_column_path()
+                .expect("a column path")];
+
+            let input = manifest();
+            let plan = inc.plan(&input).expect("a plan");
+
+            assert_eq!(plan.len(), 1);
+            assert_eq!(
+                get_data(&plan[0], "path").borrow(),
+                &string("package.version")
+            );
+            assert_eq!(get_data(&plan[0], "from").borrow(), &string("0.27.2"));
+            assert_eq!(get_data(&plan[0], "to").borrow(), &string("0.27.3"));
+            assert_eq!(input, manifest());
+        }
+
+        #[test]
+        fn describes_the_change_to_a_value() {
+            let plan = Inc::new().plan(&int(5)).expect("a plan");
+
+            assert_eq!(get_data(&plan[0], "path").borrow(), &string(""));
+            assert_eq!(get_data(&plan[0], "from").borrow(), &int(5));
+            assert_eq!(get_data(&plan[0], "to").borrow(), &int(6));
+        }
+    }
+
+    mod increment {
+        use crate::{increment, Action, Inc, SemVerAction};
+        use nu_protocol::TaggedDictBuilder;
+        use nu_source::{Span, Tag, TaggedItem};
+        use nu_test_support::value::{int, nothing, string};
+        use nu_value_ext::get_data;
+
+        #[test]
+        fn increments_a_value() {
+            let tag = Tag::from(Span::new(0, 6));
+
+            let actual = increment(int(5).tagged(&tag), Action::Default, None);
+
+            assert_eq!(actual, Ok(int(6).tagged(&tag)));
+        }
+
+        #[test]
+        fn increments_a_field() {
+            let manifest = TaggedDictBuilder::build(Tag::unknown(), |manifest| {
+                manifest.insert_value(
+                    "package",
+                    TaggedDictBuilder::build(Tag::unknown(), |package| {
+                        package.insert_value("version", string("0.27.2"));
+                    }),
+                );
+            });
+
+            let actual = increment(
+                manifest.tagged_unknown(),
+                Action::SemVerAction(SemVerAction::Minor),
+                Some("package.version"),
+            )
+            .expect("an incremented manifest");
+
+            let package = get_data(&actual.item, "package").borrow().clone();
+
+            assert_eq!(get_data(&package, "version").borrow(), &string("0.28.0"));
+        }
+
+        #[test]
+        fn transform_passes_values_through_when_ignoring_errors() {
+            let mut inc = Inc::new();
+            inc.ignore_errors = true;
+
+            let actual = inc.transform(nothing().tagged_unknown());
+
+            assert_eq!(actual, Ok(nothing().tagged_unknown()));
         }
     }
 }
@@ -1,7 +1,7 @@
 mod inc;
 mod nu;
 
-pub use inc::Inc;
+pub use inc::{increment, Action, Inc, SemVerAction};
 
 #[cfg(test)]
 mod tests {
@@ -28,10 +28,11 @@ mod tests {
                 )),
             };
 
-            match &self.field {
-                Some(column_path) if column_path == &field => {}
-                Some(other) => panic!(format!("\nExpected {:#?} \n\ngot {:#?}", field, other)),
-                None => panic!(format!("\nField {:#?} not found.", field)),
+            if !self.fields.iter().any(|column_path| column_path == &field) {
+                panic!(format!(
+                    "\nExpected {:#?} \n\ngot {:#?}",
+                    field, self.fields
+                ))
             }
         }
     }
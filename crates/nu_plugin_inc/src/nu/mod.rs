@@ -1,15 +1,15 @@
 #[cfg(test)]
 mod tests;
 
-use crate::inc::{Action, SemVerAction};
+use crate::inc::{Action, NullAs, OutputType, RoundMode, SemVerAction};
 use crate::Inc;
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
 use nu_protocol::{
-    CallInfo, Primitive, ReturnSuccess, ReturnValue, ShellTypeName, Signature, SyntaxShape,
-    UntaggedValue, Value,
+    CallInfo, ColumnPath, NamedType, Primitive, ReturnSuccess, ReturnValue, ShellTypeName,
+    Signature, SyntaxShape, TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
-use nu_source::{HasSpan, SpannedItem};
+use nu_source::{HasSpan, PrettyDebug, Span, SpannedItem, Tag, Tagged, TaggedItem};
 use nu_value_ext::ValueExt;
 
 impl Plugin for Inc {
@@ -31,23 +31,271 @@ impl Plugin for Inc {
                 "increment the patch version (eg 1.2.1 -> 1.2.2)",
                 Some('p'),
             )
-            .rest(SyntaxShape::ColumnPath, "the column(s) to update")
+            .switch(
+                "prerelease",
+                "increment the prerelease version (eg 1.2.1-alpha.1 -> 1.2.1-alpha.2)",
+                None,
+            )
+            .named(
+                "identifier",
+                SyntaxShape::String,
+                "increment the number after a prerelease identifier (eg 1.0.0-beta.3 -> 1.0.0-beta.4 with --identifier beta)",
+                None,
+            )
+            .switch(
+                "date",
+                "increment a date by one day (eg 2019-03-01 -> 2019-03-02)",
+                None,
+            )
+            .named(
+                "by",
+                SyntaxShape::Int,
+                "increment integers and filesizes by the given step (eg 5 -> 10 with --by 5)",
+                Some('b'),
+            )
+            .named(
+                "reset-to",
+                SyntaxShape::Int,
+                "reset the lower parts of a version to the given number instead of 0 (eg 1.2.3 -> 2.1.1 with --major --reset-to 1)",
+                None,
+            )
+            .switch(
+                "decrement",
+                "decrement the value instead of incrementing it (eg 5 -> 4)",
+                Some('d'),
+            )
+            .switch(
+                "float",
+                "increment decimal strings (eg 3.14 -> 4.14)",
+                Some('f'),
+            )
+            .named(
+                "round",
+                SyntaxShape::Int,
+                "round incremented decimals to the given number of places (eg 1.005 -> 2.01 with --round 2)",
+                None,
+            )
+            .named(
+                "round-mode",
+                SyntaxShape::String,
+                "how --round breaks ties, half-up (the default) or half-even (eg 1.005 -> 2.00 with --round 2 --round-mode half-even)",
+                None,
+            )
+            .switch(
+                "wrapping",
+                "wrap integers around past the 64-bit bounds instead of erroring (eg 9223372036854775807 -> -9223372036854775808)",
+                None,
+            )
+            .switch(
+                "saturating",
+                "stop integers at the 64-bit bounds instead of erroring (eg 9223372036854775807 -> 9223372036854775807)",
+                None,
+            )
+            .switch(
+                "pad",
+                "keep the zero-padding of numeric strings (eg 007 -> 008)",
+                None,
+            )
+            .named(
+                "output-type",
+                SyntaxShape::String,
+                "the type of incremented numeric strings, int (the default) or string (eg \"5\" -> \"6\" with --output-type string)",
+                Some('o'),
+            )
+            .named(
+                "prefix",
+                SyntaxShape::String,
+                "increment strings without this prefix and put it back after (eg v1.2.3 -> v1.2.4 with --prefix v)",
+                None,
+            )
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "increment strings without this suffix and put it back after (eg 9px -> 10px with --suffix px)",
+                None,
+            )
+            .named(
+                "null-as",
+                SyntaxShape::String,
+                "how to treat rows missing the field, error (the default), skip or zero (eg {name: nu} -> {name: nu, count: 1} with --null-as zero)",
+                None,
+            )
+            .switch(
+                "lenient-semver",
+                "also increment versions missing their patch or minor part (eg 1.2 -> 1.3 with --minor)",
+                None,
+            )
+            .switch(
+                "strict",
+                "error on versions that can not be parsed instead of passing them through",
+                Some('s'),
+            )
+            .switch(
+                "verbose",
+                "output the original and the incremented value as columns 'old' and 'new'",
+                Some('v'),
+            )
+            .switch(
+                "dry-run",
+                "output what would change as columns 'path', 'from' and 'to' instead of the changed value",
+                None,
+            )
+            .switch(
+                "auto",
+                "increment the one column of a row that holds a version when no column is given",
+                Some('a'),
+            )
+            .switch(
+                "ignore-errors",
+                "pass values that can not be incremented through unchanged instead of failing",
+                Some('i'),
+            )
+            .named(
+                "field",
+                SyntaxShape::ColumnPath,
+                "the column to update (alternative to passing it positionally)",
+                None,
+            )
+            .exclusive_group(&[
+                "major",
+                "minor",
+                "patch",
+                "prerelease",
+                "identifier",
+                "date",
+            ])
+            .rest(
+                SyntaxShape::ColumnPath,
+                "the column(s) to update, optionally after the action (major, minor or patch); quote a column named like an action",
+            )
+            .example("open Cargo.toml | inc --patch package.version")
+            .example("open Cargo.toml | inc patch package.version")
+            .example("echo 5 | inc --by 5")
             .filter())
     }
 
     fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
-        if call_info.args.has("major") {
+        let positional_action = positional_action(&call_info);
+        let skipped_positionals = if positional_action.is_some() { 1 } else { 0 };
+
+        if call_info.switch_present("major") {
             self.for_semver(SemVerAction::Major);
         }
-        if call_info.args.has("minor") {
+        if call_info.switch_present("minor") {
             self.for_semver(SemVerAction::Minor);
         }
-        if call_info.args.has("patch") {
+        if call_info.switch_present("patch") {
             self.for_semver(SemVerAction::Patch);
         }
+        if call_info.switch_present("prerelease") {
+            self.for_semver(SemVerAction::Prerelease);
+        }
+        if let Some(name) = call_info.args.get_string("identifier")? {
+            self.for_semver(SemVerAction::Identifier(name));
+        }
+        if call_info.switch_present("date") {
+            self.for_date();
+        }
+
+        if let Some(part) = positional_action {
+            self.for_semver(part.item);
+        }
+
+        if let Some(by) = call_info.args.get_int("by")? {
+            if self.is_semver() {
+                self.log_error("can not use --by with a semver increment");
+            }
+
+            self.step = Some(by);
+        }
+
+        if let Some(number) = call_info.args.get_int("reset-to")? {
+            if number < 0 {
+                return Err(ShellError::labeled_error(
+                    "inc can not reset a version to a negative number",
+                    "expected zero or more",
+                    named_tag(&call_info, "reset-to"),
+                ));
+            }
+
+            if !matches!(
+                self.action,
+                Some(Action::SemVerAction(SemVerAction::Major))
+                    | Some(Action::SemVerAction(SemVerAction::Minor))
+            ) {
+                self.log_error("can only use --reset-to with a major or minor increment");
+            }
+
+            self.reset_to = Some(number as u64);
+        }
+
+        if call_info.switch_present("decrement") {
+            if self.is_semver() {
+                self.log_error("can not decrement a semver version");
+            }
+
+            self.decrement = true;
+        }
+
+        self.float = call_info.switch_present("float");
+        self.pad = call_info.switch_present("pad");
+
+        self.wrapping = call_info.switch_present("wrapping");
+        self.saturating = call_info.switch_present("saturating");
+        if self.wrapping && self.saturating {
+            self.log_error("can not use --wrapping with --saturating");
+        }
+
+        if let Some(places) = call_info.args.get_int("round")? {
+            if places < 0 {
+                return Err(ShellError::labeled_error(
+                    "inc can not round to a negative number of places",
+                    "expected zero or more",
+                    named_tag(&call_info, "round"),
+                ));
+            }
+
+            self.round = Some(places);
+        }
+        if let Some(round_mode) = call_info.args.get("round-mode") {
+            if self.round.is_none() {
+                self.log_error("can not use --round-mode without --round");
+            }
+
+            self.round_mode = Some(RoundMode::parse(
+                &round_mode.as_string()?,
+                round_mode.tag.span,
+            )?);
+        }
+
+        if let Some(output_type) = call_info.args.get("output-type") {
+            self.output_type = Some(OutputType::parse(
+                &output_type.as_string()?,
+                output_type.tag.span,
+            )?);
+        }
+
+        if let Some(null_as) = call_info.args.get("null-as") {
+            self.null_as = Some(NullAs::parse(&null_as.as_string()?, null_as.tag.span)?);
+        }
+
+        self.prefix = call_info.args.get_string("prefix")?;
+        self.suffix = call_info.args.get_string("suffix")?;
+
+        self.strict = call_info.switch_present("strict");
+        self.lenient_semver = call_info.switch_present("lenient-semver");
+        self.verbose = call_info.switch_present("verbose");
+        self.dry_run = call_info.switch_present("dry-run");
+        self.auto = call_info.switch_present("auto");
+        self.ignore_errors = call_info.switch_present("ignore-errors");
+
+        let named_field = match call_info.args.get("field") {
+            Some(field) => Some(field.as_column_path()?),
+            None => None,
+        };
 
         if let Some(args) = call_info.args.positional {
-            for arg in args {
+            for arg in args.into_iter().skip(skipped_positionals) {
                 match arg {
                     table
                     @
@@ -55,7 +303,7 @@ impl Plugin for Inc {
                         value: UntaggedValue::Primitive(Primitive::ColumnPath(_)),
                         ..
                     } => {
-                        self.field = Some(table.as_column_path()?);
+                        self.fields.push(table.as_column_path()?);
                     }
                     value => {
                         return Err(ShellError::type_error(
@@ -67,21 +315,117 @@ impl Plugin for Inc {
             }
         }
 
+        if let Some(field) = named_field {
+            let same_path = |other: &Tagged<ColumnPath>| {
+                other
+                    .iter()
+                    .map(|member| &member.unspanned)
+                    .eq(field.iter().map(|member| &member.unspanned))
+            };
+
+            if !self.fields.iter().all(same_path) {
+                self.log_error("--field conflicts with the column path given positionally");
+            }
+
+            self.fields = vec![field];
+        }
+
         if self.action.is_none() {
             self.action = Some(Action::Default);
         }
 
-        match &self.error {
+        match self.error.clone() {
             Some(reason) => Err(ShellError::untagged_runtime_error(format!(
                 "{}: {}",
                 reason,
-                Inc::usage()
+                usage(&self.config()?)
             ))),
             None => Ok(vec![]),
         }
     }
 
     fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
-        Ok(vec![ReturnSuccess::value(self.inc(input)?)])
+        if self.dry_run {
+            return Ok(self
+                .plan(&input)?
+                .into_iter()
+                .map(ReturnSuccess::value)
+                .collect());
+        }
+
+        let tag = input.tag();
+        let incremented = self.transform(input.clone().tagged(tag))?.item;
+
+        if self.verbose {
+            let mut report = TaggedDictBuilder::new(input.tag());
+            report.insert_value("old", input);
+            report.insert_value("new", incremented);
+
+            return Ok(vec![ReturnSuccess::value(report.into_value())]);
+        }
+
+        Ok(vec![ReturnSuccess::value(incremented)])
     }
 }
+
+/// Lists the flags of the signature, so the usage never falls behind the flags there are
+fn usage(signature: &Signature) -> String {
+    let flags = signature
+        .named
+        .iter()
+        .filter(|(name, _)| name.as_str() != "help")
+        .map(|(name, (named_type, _))| match named_type {
+            NamedType::Switch(_) => format!("--{}", name),
+            NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape) => {
+                format!("--{} <{}>", name, shape.display())
+            }
+        })
+        .collect::<Vec<String>>();
+
+    format!("Usage: {} field [{}]", signature.name, flags.join("|"))
+}
+
+/// The tag of the named argument, for errors about its value
+fn named_tag(call_info: &CallInfo, name: &str) -> Tag {
+    call_info
+        .args
+        .get(name)
+        .map(|value| value.tag.clone())
+        .unwrap_or_else(Tag::unknown)
+}
+
+/// Reads a leading `major`, `minor` or `patch` positional as the action, like in
+/// `inc patch package.version`. It stays a column path when an action is also given as a flag,
+/// like in `inc --minor patch`, or when it is quoted, like in `inc "patch"`, so columns with
+/// those names can still be incremented.
+fn positional_action(call_info: &CallInfo) -> Option<Tagged<SemVerAction>> {
+    let action_switches = ["major", "minor", "patch", "prerelease", "date"];
+    if action_switches
+        .iter()
+        .any(|switch| call_info.switch_present(switch))
+        || call_info.args.has("identifier")
+    {
+        return None;
+    }
+
+    let first = call_info.args.nth(0)?;
+    let path = first.as_column_path().ok()?;
+
+    let part = match path.members().as_slice() {
+        [member] => match &member.unspanned {
+            UnspannedPathMember::String(name) if is_quoted(name, member.span) => return None,
+            UnspannedPathMember::String(name) if name == "major" => SemVerAction::Major,
+            UnspannedPathMember::String(name) if name == "minor" => SemVerAction::Minor,
+            UnspannedPathMember::String(name) if name == "patch" => SemVerAction::Patch,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(part.tagged(&first.tag))
+}
+
+/// Whether the member was written in quotes, which the parser trims from its name but not its span
+fn is_quoted(name: &str, span: Span) -> bool {
+    span.end() - span.start() == name.len() + 2
+}
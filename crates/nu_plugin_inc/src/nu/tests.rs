@@ -1,11 +1,13 @@
 mod integration {
-    use crate::inc::{Action, SemVerAction};
+    use crate::inc::{Action, NullAs, SemVerAction};
     use crate::Inc;
     use nu_errors::ShellError;
-    use nu_plugin::test_helpers::{plugin, CallStub};
-    use nu_protocol::{Primitive, UntaggedValue};
-    use nu_test_support::value::column_path;
-    use nu_value_ext::ValueExt;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_plugin::Plugin;
+    use nu_protocol::{ColumnPath, PathMember, Primitive, TaggedDictBuilder, UntaggedValue};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{column_path, decimal_from_float, error_callback, int, string};
+    use nu_value_ext::{get_data, ValueExt};
 
     #[test]
     fn picks_up_one_action_flag_only() {
@@ -24,6 +26,58 @@ mod integration {
             });
     }
 
+    #[test]
+    fn usage_lists_every_flag() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_long_flag("minor")
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("Usage: inc field [--major|--minor|"));
+                assert!(actual.contains("|--reset-to <integer>|"));
+                assert!(actual.contains("|--ignore-errors|"));
+            });
+    }
+
+    #[test]
+    fn prerelease_flag_conflicts_with_other_semver_flags() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("prerelease")
+                    .with_long_flag("major")
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can only apply one"));
+                assert_eq!(plugin.error, Some("can only apply one".to_string()));
+            });
+    }
+
+    #[test]
+    fn by_flag_can_not_be_combined_with_semver_flags() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_named_parameter("by", int(10))
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can not use --by with a semver increment"));
+                assert_eq!(plugin.step, Some(10));
+            });
+    }
+
     #[test]
     fn picks_up_major_flag() {
         plugin(&mut Inc::new())
@@ -54,6 +108,16 @@ mod integration {
             });
     }
 
+    #[test]
+    fn picks_up_prerelease_flag() {
+        plugin(&mut Inc::new())
+            .args(CallStub::new().with_long_flag("prerelease").create())
+            .setup(|plugin, _| {
+                let sem_version_part = SemVerAction::Prerelease;
+                plugin.expect_action(Action::SemVerAction(sem_version_part))
+            });
+    }
+
     #[test]
     fn picks_up_argument_for_field() -> Result<(), ShellError> {
         plugin(&mut Inc::new())
@@ -69,14 +133,530 @@ mod integration {
             });
         Ok(())
     }
+
+    #[test]
+    fn picks_up_the_action_given_positionally() -> Result<(), ShellError> {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_parameter("minor")?
+                    .with_parameter("package.version")?
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                assert!(returned_values.is_ok());
+                plugin.expect_action(Action::SemVerAction(SemVerAction::Minor));
+                plugin.expect_field(column_path("package.version"));
+                assert_eq!(plugin.fields.len(), 1);
+            });
+        Ok(())
+    }
+
+    #[test]
+    fn reads_an_action_word_as_a_column_when_a_flag_gives_the_action() -> Result<(), ShellError> {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_parameter("patch")?
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                assert!(returned_values.is_ok());
+                plugin.expect_action(Action::SemVerAction(SemVerAction::Major));
+                plugin.expect_field(column_path("patch"));
+            });
+        Ok(())
+    }
+
+    #[test]
+    fn reads_a_quoted_action_word_as_a_column() {
+        // the parser trims the quotes of `"minor"` from the name, but its span still covers them
+        let quoted = ColumnPath::new(vec![PathMember::string("minor", Span::new(4, 11))]);
+
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_positional(
+                        UntaggedValue::Primitive(Primitive::ColumnPath(quoted))
+                            .into_value(Span::new(4, 11)),
+                    )
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                assert!(returned_values.is_ok());
+                assert_eq!(plugin.action, None);
+                plugin.expect_field(column_path("minor"));
+            });
+    }
+
+    #[test]
+    fn resets_the_lower_parts_of_a_version_to_the_value_given() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_named_parameter("reset-to", int(1))
+                    .create(),
+            )
+            .input(string("1.2.3"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("2.1.1"));
+    }
+
+    #[test]
+    fn reset_to_needs_a_major_or_minor_increment() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("patch")
+                    .with_named_parameter("reset-to", int(1))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can only use --reset-to with a major or minor increment"));
+            });
+    }
+
+    #[test]
+    fn reset_to_rejects_negative_numbers() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_named_parameter("reset-to", int(-1))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("inc can not reset a version to a negative number"));
+            });
+    }
+
+    #[test]
+    fn reset_to_needs_an_integer() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("major")
+                    .with_named_parameter("reset-to", string("one"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("expected integer for --reset-to, found string"));
+            });
+    }
+
+    #[test]
+    fn increments_int_by_the_step_given() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().with_named_parameter("by", int(10)).create())
+            .input(int(5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(15));
+    }
+
+    #[test]
+    fn verbose_reports_old_and_new_value() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().with_long_flag("verbose").create())
+            .input(int(5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "old").borrow(), &int(5));
+        assert_eq!(get_data(&actual, "new").borrow(), &int(6));
+    }
+
+    #[test]
+    fn ignore_errors_passes_invalid_values_through_untouched() {
+        let mut inc = Inc::new();
+
+        plugin(&mut inc)
+            .args(
+                CallStub::new()
+                    .with_long_flag("ignore-errors")
+                    .with_long_flag("strict")
+                    .with_long_flag("patch")
+                    .create(),
+            )
+            .setup(|_, _| {});
+
+        let actual = vec![string("0.1.2"), string("1.x"), string("2.0.0")]
+            .into_iter()
+            .map(|version| {
+                let mut returned = inc.filter(version).expect("a value for every row");
+                returned.remove(0).ok().and_then(|value| value.raw_value())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                Some(string("0.1.3")),
+                Some(string("1.x")),
+                Some(string("2.0.1"))
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_values_fail_without_ignore_errors() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("strict")
+                    .with_long_flag("patch")
+                    .create(),
+            )
+            .input(string("1.x"))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+
+    #[test]
+    fn errors_on_integer_overflow() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().create())
+            .input(int(i64::MAX))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = format!("{}", run.unwrap_err());
+
+        assert!(actual.contains("overflows a 64-bit integer"));
+    }
+
+    #[test]
+    fn wrapping_conflicts_with_saturating() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("wrapping")
+                    .with_long_flag("saturating")
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can not use --wrapping with --saturating"));
+            });
+    }
+
+    #[test]
+    fn decrements_int() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().with_long_flag("decrement").create())
+            .input(int(5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(4));
+    }
+
+    #[test]
+    fn increments_decimal_exactly() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().create())
+            .input(decimal_from_float(1.25))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, decimal_from_float(2.25));
+    }
+
+    #[test]
+    fn decrements_decimal_by_the_step_given() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("decrement")
+                    .with_named_parameter("by", int(2))
+                    .create(),
+            )
+            .input(decimal_from_float(0.5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, decimal_from_float(-1.5));
+    }
+
+    #[test]
+    fn decrementing_filesize_saturates_at_zero() {
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().with_long_flag("decrement").create())
+            .input(UntaggedValue::filesize(0).into_untagged_value())
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, UntaggedValue::filesize(0).into_untagged_value());
+    }
+
+    #[test]
+    fn picks_up_named_argument_for_field() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("field", column_path("package.version"))
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_field(column_path("package.version")));
+    }
+
+    #[test]
+    fn named_field_reconciles_with_the_same_positional_field() -> Result<(), ShellError> {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("field", column_path("package.version"))
+                    .with_parameter("package.version")?
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                assert!(returned_values.is_ok());
+                assert_eq!(plugin.fields.len(), 1);
+                plugin.expect_field(column_path("package.version"))
+            });
+        Ok(())
+    }
+
+    #[test]
+    fn named_field_conflicts_with_a_different_positional_field() -> Result<(), ShellError> {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("field", column_path("package.version"))
+                    .with_parameter("package.name")?
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("--field conflicts with the column path given"));
+            });
+        Ok(())
+    }
+
+    #[test]
+    fn increments_a_field_inside_a_table_row() -> Result<(), ShellError> {
+        let first = column_path("items.0.count").as_column_path()?;
+        let second = column_path("items.1.count").as_column_path()?;
+
+        let items = TaggedDictBuilder::build(Tag::unknown(), |record| {
+            record.insert_value(
+                "items",
+                UntaggedValue::table(&[
+                    TaggedDictBuilder::build(Tag::unknown(), |row| {
+                        row.insert_value("count", int(1));
+                    }),
+                    TaggedDictBuilder::build(Tag::unknown(), |row| {
+                        row.insert_value("count", int(5));
+                    }),
+                ])
+                .into_untagged_value(),
+            );
+        });
+
+        let run = plugin(&mut Inc::new())
+            .args(CallStub::new().with_parameter("items.0.count")?.create())
+            .input(items)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(
+            actual.get_data_by_column_path(&first, Box::new(error_callback("items.0.count")))?,
+            int(2)
+        );
+        assert_eq!(
+            actual.get_data_by_column_path(&second, Box::new(error_callback("items.1.count")))?,
+            int(5)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn increments_numeric_strings_into_the_output_type_given() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("output-type", string("int"))
+                    .create(),
+            )
+            .input(string("5"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(6));
+    }
+
+    #[test]
+    fn rejects_an_unknown_output_type() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("output-type", string("float"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn picks_up_the_null_as_mode() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("null-as", string("zero"))
+                    .create(),
+            )
+            .setup(|plugin, _| assert_eq!(plugin.null_as, Some(NullAs::Zero)));
+    }
+
+    #[test]
+    fn rejects_an_unknown_null_as_mode() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("null-as", string("ignore"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn rounds_decimal_strings_to_the_places_given() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("float")
+                    .with_named_parameter("round", int(2))
+                    .with_named_parameter("round-mode", string("half-even"))
+                    .create(),
+            )
+            .input(string("1.005"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("2.00"));
+    }
+
+    #[test]
+    fn round_rejects_negative_places() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("float")
+                    .with_named_parameter("round", int(-2))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("inc can not round to a negative number of places"));
+            });
+    }
+
+    #[test]
+    fn round_needs_an_integer() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("float")
+                    .with_named_parameter("round", string("two"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("expected integer for --round, found string"));
+            });
+    }
+
+    #[test]
+    fn round_mode_needs_round() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("round-mode", string("half-even"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can not use --round-mode without --round"));
+            });
+    }
+
+    #[test]
+    fn increments_two_part_versions_with_lenient_semver() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("lenient-semver")
+                    .with_long_flag("minor")
+                    .create(),
+            )
+            .input(string("1.2"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("1.3"));
+    }
+
+    #[test]
+    fn increments_versions_behind_the_prefix_given() {
+        let run = plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("prefix", string("v"))
+                    .with_long_flag("patch")
+                    .create(),
+            )
+            .input(string("v1.2.3"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("v1.2.4"));
+    }
+
     mod sem_ver {
         use crate::Inc;
         use nu_errors::ShellError;
         use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
         use nu_protocol::TaggedDictBuilder;
         use nu_source::Tag;
-        use nu_test_support::value::string;
-        use nu_value_ext::get_data;
+        use nu_test_support::value::{column_path, error_callback, string};
+        use nu_value_ext::{get_data, ValueExt};
 
         fn cargo_sample_record(with_version: &str) -> nu_protocol::Value {
             TaggedDictBuilder::build(Tag::unknown(), |row| {
@@ -84,6 +664,29 @@ mod integration {
             })
         }
 
+        fn cargo_sample_record_with_dependency(
+            with_version: &str,
+            with_dependency_version: &str,
+        ) -> nu_protocol::Value {
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("version".to_string(), string(with_version));
+                row.insert_value(
+                    "deps".to_string(),
+                    TaggedDictBuilder::build(Tag::unknown(), |deps| {
+                        deps.insert_value(
+                            "foo".to_string(),
+                            TaggedDictBuilder::build(Tag::unknown(), |foo| {
+                                foo.insert_value(
+                                    "version".to_string(),
+                                    string(with_dependency_version),
+                                );
+                            }),
+                        );
+                    }),
+                );
+            })
+        }
+
         #[test]
         fn major_input_using_the_field_passed_as_parameter() -> Result<(), ShellError> {
             let run = plugin(&mut Inc::new())
@@ -140,5 +743,151 @@ mod integration {
             assert_eq!(get_data(&actual, "version").borrow(), &string("0.1.4"));
             Ok(())
         }
+
+        #[test]
+        fn patch_input_using_multiple_fields_passed_as_parameters() -> Result<(), ShellError> {
+            let run = plugin(&mut Inc::new())
+                .args(
+                    CallStub::new()
+                        .with_long_flag("patch")
+                        .with_parameter("version")?
+                        .with_parameter("deps.foo.version")?
+                        .create(),
+                )
+                .input(cargo_sample_record_with_dependency("0.1.3", "1.0.0"))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = expect_return_value_at(run, 0);
+            let dependency_version = column_path("deps.foo.version").as_column_path()?;
+
+            assert_eq!(get_data(&actual, "version").borrow(), &string("0.1.4"));
+            assert_eq!(
+                actual.get_data_by_column_path(
+                    &dependency_version,
+                    Box::new(error_callback("deps.foo.version"))
+                )?,
+                string("1.0.1")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn verbose_reports_old_and_new_row() -> Result<(), ShellError> {
+            let run = plugin(&mut Inc::new())
+                .args(
+                    CallStub::new()
+                        .with_long_flag("patch")
+                        .with_long_flag("verbose")
+                        .with_parameter("version")?
+                        .create(),
+                )
+                .input(cargo_sample_record("0.1.3"))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = expect_return_value_at(run, 0);
+
+            let old = get_data(&actual, "old");
+            let new = get_data(&actual, "new");
+
+            assert_eq!(get_data(old.borrow(), "version").borrow(), &string("0.1.3"));
+            assert_eq!(get_data(new.borrow(), "version").borrow(), &string("0.1.4"));
+            Ok(())
+        }
+
+        #[test]
+        fn dry_run_reports_the_change_without_making_it() -> Result<(), ShellError> {
+            let run = plugin(&mut Inc::new())
+                .args(
+                    CallStub::new()
+                        .with_long_flag("dry-run")
+                        .with_long_flag("patch")
+                        .with_parameter("version")?
+                        .create(),
+                )
+                .input(cargo_sample_record("0.1.3"))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = expect_return_value_at(run, 0);
+
+            assert_eq!(get_data(&actual, "path").borrow(), &string("version"));
+            assert_eq!(get_data(&actual, "from").borrow(), &string("0.1.3"));
+            assert_eq!(get_data(&actual, "to").borrow(), &string("0.1.4"));
+            Ok(())
+        }
+
+        #[test]
+        fn errors_naming_the_missing_field() -> Result<(), ShellError> {
+            let run = plugin(&mut Inc::new())
+                .args(
+                    CallStub::new()
+                        .with_long_flag("patch")
+                        .with_parameter("version")?
+                        .with_parameter("deps.bar.version")?
+                        .create(),
+                )
+                .input(cargo_sample_record_with_dependency("0.1.3", "1.0.0"))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = format!("{:?}", run.unwrap_err());
+
+            assert!(actual.contains("deps.bar.version"));
+            Ok(())
+        }
+    }
+
+    mod streaming {
+        use crate::Inc;
+        use nu_errors::ShellError;
+        use nu_plugin::jsonrpc::{JsonRpc, NuCommand};
+        use nu_plugin::serve_plugin_from;
+        use nu_plugin::test_helpers::CallStub;
+        use nu_protocol::ReturnValue;
+        use nu_test_support::value::int;
+
+        type Response = JsonRpc<Result<Vec<ReturnValue>, ShellError>>;
+
+        fn line(command: &NuCommand) -> String {
+            format!(
+                "{}\n",
+                serde_json::to_string(command).expect("serializable")
+            )
+        }
+
+        #[test]
+        fn answers_each_value_in_input_order() {
+            let mut input = line(&NuCommand::begin_filter {
+                params: CallStub::new().create(),
+            });
+            for n in 0..100 {
+                input.push_str(&line(&NuCommand::filter { params: int(n) }));
+            }
+            input.push_str(&line(&NuCommand::end_filter));
+
+            let mut output = vec![];
+            serve_plugin_from(&mut Inc::new(), &mut input.as_bytes(), &mut output);
+
+            let responses = String::from_utf8(output).expect("utf-8 output");
+            let responses: Vec<Response> = responses
+                .lines()
+                .map(|response| serde_json::from_str(response).expect("a response"))
+                .collect();
+
+            // one response for begin_filter, one per value, one for end_filter
+            assert_eq!(responses.len(), 102);
+
+            for (n, response) in responses[1..=100].iter().enumerate() {
+                let values = response.params.as_ref().expect("filtered values");
+                let value = values[0]
+                    .as_ref()
+                    .ok()
+                    .and_then(|returned| returned.raw_value());
+
+                assert_eq!(value, Some(int(n as i64 + 1)));
+            }
+        }
     }
 }
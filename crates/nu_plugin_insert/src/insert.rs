@@ -0,0 +1,135 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Value};
+use nu_source::Tagged;
+use nu_value_ext::{forgiving_insert_data_at_column_path, missing_path_members_by_column_path};
+
+#[derive(Default)]
+pub struct Insert {
+    pub field: Option<Tagged<ColumnPath>>,
+    pub value: Option<Value>,
+    pub force: bool,
+}
+
+impl Insert {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Inserts the value at the field, creating the rows along the way that do not exist yet.
+    /// A field that is already there is only overwritten with `--force`.
+    pub fn insert(&self, input: &Value) -> Result<Value, ShellError> {
+        let (field, value) = match (&self.field, &self.value) {
+            (Some(field), Some(value)) if !field.members().is_empty() => (field, value),
+            _ => {
+                return Err(ShellError::untagged_runtime_error(
+                    "insert-field needs a field and a value",
+                ))
+            }
+        };
+
+        if !self.force && missing_path_members_by_column_path(input, field).is_none() {
+            if let Some(last) = field.last() {
+                return Err(ShellError::labeled_error(
+                    format!(
+                        "insert-field would overwrite the existing field '{}'",
+                        last.as_string()
+                    ),
+                    "already exists, use --force to overwrite it",
+                    last.span,
+                ));
+            }
+        }
+
+        forgiving_insert_data_at_column_path(input, field, value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Insert;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+    use nu_value_ext::ValueExt;
+
+    fn insert(field: &str, value: Value) -> Insert {
+        let mut insert = Insert::new();
+        insert.field = Some(column_path(field).as_column_path().expect("a column path"));
+        insert.value = Some(value);
+        insert
+    }
+
+    fn service() -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        })
+    }
+
+    #[test]
+    fn inserts_a_field() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("status", string("active"));
+        });
+
+        assert_eq!(
+            insert("status", string("active"))
+                .insert(&service())
+                .expect("inserted"),
+            expected
+        );
+    }
+
+    #[test]
+    fn creates_the_rows_along_a_nested_path() {
+        let limits = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("memory", int(512));
+        });
+        let deploy = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("limits", limits);
+        });
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("deploy", deploy);
+        });
+
+        assert_eq!(
+            insert("deploy.limits.memory", int(512))
+                .insert(&service())
+                .expect("inserted"),
+            expected
+        );
+    }
+
+    #[test]
+    fn refuses_to_overwrite_an_existing_field() {
+        let error = insert("name", string("engine"))
+            .insert(&service())
+            .expect_err("name already exists");
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(
+            diagnostic.message,
+            "insert-field would overwrite the existing field 'name'"
+        );
+    }
+
+    #[test]
+    fn overwrites_an_existing_field_when_forced() {
+        let expected = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("engine"));
+        });
+
+        let mut insert = insert("name", string("engine"));
+        insert.force = true;
+
+        assert_eq!(insert.insert(&service()).expect("inserted"), expected);
+    }
+
+    #[test]
+    fn errors_on_a_value_that_is_not_a_row() {
+        assert!(insert("status", string("active"))
+            .insert(&string("nu"))
+            .is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod insert;
+mod nu;
+
+pub use insert::Insert;
@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_insert::Insert;
+
+fn main() {
+    serve_plugin(&mut Insert::new())
+}
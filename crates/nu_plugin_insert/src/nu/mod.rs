@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Insert;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Insert {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("insert-field")
+            .desc("Insert a field with the same value into each row.")
+            .required(
+                "field",
+                SyntaxShape::ColumnPath,
+                "the path of the field to insert",
+            )
+            .required("value", SyntaxShape::Any, "the value of the field")
+            .switch(
+                "force",
+                "overwrite the field if it already exists",
+                Some('f'),
+            )
+            .example("open services.json | insert-field status active")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match (call_info.args.nth(0), call_info.args.nth(1)) {
+            (Some(field), Some(value)) => {
+                self.field = Some(field.as_column_path()?);
+                self.value = Some(value.clone());
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "insert-field needs a field and a value",
+                    "requires a field and a value",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.force = call_info.switch_present("force");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.insert(&input)?)])
+    }
+}
@@ -0,0 +1,64 @@
+mod integration {
+    use crate::Insert;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, string};
+    use nu_value_ext::get_data;
+
+    fn service() -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        })
+    }
+
+    #[test]
+    fn requires_a_field_and_a_value() {
+        plugin(&mut Insert::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("status"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn inserts_the_field_into_each_row() {
+        let run = plugin(&mut Insert::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("status"))
+                    .with_positional(string("active"))
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(service())
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "status").borrow(), &string("active"));
+    }
+
+    #[test]
+    fn overwrites_when_forced() {
+        let run = plugin(&mut Insert::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("name"))
+                    .with_positional(string("engine"))
+                    .with_long_flag("force")
+                    .create(),
+            )
+            .setup(|_, _| {})
+            .input(service())
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "name").borrow(), &string("engine"));
+    }
+}
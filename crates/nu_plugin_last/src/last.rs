@@ -0,0 +1,96 @@
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use std::collections::VecDeque;
+
+pub struct Last {
+    pub rows: usize,
+    pub values: VecDeque<Value>,
+}
+
+impl Default for Last {
+    fn default() -> Self {
+        Last {
+            rows: 1,
+            values: VecDeque::new(),
+        }
+    }
+}
+
+impl Last {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets how many values to keep, which has to be at least one
+    pub fn set_rows(&mut self, rows: &Value) -> Result<(), ShellError> {
+        let count = rows.as_i64()?;
+
+        if count < 1 {
+            return Err(ShellError::labeled_error(
+                "keep-last needs to keep at least one value",
+                "expected a positive number",
+                &rows.tag,
+            ));
+        }
+
+        self.rows = count as usize;
+
+        Ok(())
+    }
+
+    /// Buffers the value, forgetting the oldest one once more than `rows` are held
+    pub fn push(&mut self, value: Value) {
+        self.values.push_back(value);
+
+        if self.values.len() > self.rows {
+            self.values.pop_front();
+        }
+    }
+
+    pub fn last(&mut self) -> Vec<Value> {
+        self.values.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Last;
+    use nu_protocol::Value;
+    use nu_test_support::value::int;
+
+    fn last(rows: i64, values: Vec<Value>) -> Vec<Value> {
+        let mut last = Last::new();
+        last.set_rows(&int(rows)).expect("a row count");
+
+        for value in values {
+            last.push(value);
+        }
+
+        last.last()
+    }
+
+    #[test]
+    fn keeps_the_last_values() {
+        assert_eq!(last(2, vec![int(1), int(2), int(3)]), vec![int(2), int(3)]);
+    }
+
+    #[test]
+    fn keeps_one_value_by_default() {
+        let mut last = Last::new();
+        last.push(int(1));
+        last.push(int(2));
+
+        assert_eq!(last.last(), vec![int(2)]);
+    }
+
+    #[test]
+    fn keeps_the_whole_stream_when_asked_for_more() {
+        assert_eq!(last(5, vec![int(1), int(2)]), vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn errors_on_zero_or_negative_counts() {
+        assert!(Last::new().set_rows(&int(0)).is_err());
+        assert!(Last::new().set_rows(&int(-3)).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod last;
+mod nu;
+
+pub use last::Last;
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Last;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Last {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("keep-last")
+            .desc("Keep the last values of the stream.")
+            .optional(
+                "rows",
+                SyntaxShape::Int,
+                "how many values to keep (defaults to 1)",
+            )
+            .example("ls | keep-last 3")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if let Some(rows) = call_info.args.nth(0) {
+            self.set_rows(rows)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.push(input);
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self.last().into_iter().map(ReturnSuccess::value).collect())
+    }
+}
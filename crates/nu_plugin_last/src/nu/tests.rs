@@ -0,0 +1,26 @@
+mod integration {
+    use crate::Last;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn passes_a_single_value_through() {
+        let run = plugin(&mut Last::new())
+            .args(CallStub::new().with_positional(int(3)).create())
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("nu"));
+    }
+
+    #[test]
+    fn rejects_a_zero_count() {
+        plugin(&mut Last::new())
+            .args(CallStub::new().with_positional(int(0)).create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+}
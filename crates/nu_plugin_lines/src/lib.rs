@@ -0,0 +1,4 @@
+mod lines;
+mod nu;
+
+pub use lines::Lines;
@@ -0,0 +1,120 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, UntaggedValue, Value};
+use nu_source::{Span, Tag};
+
+#[derive(Default)]
+pub struct Lines {
+    pub no_trailing: bool,
+}
+
+impl Lines {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Splits the string on `\n` or `\r\n`, tagging every line with where it sits in the original
+    pub fn lines(&self, value: &Value) -> Result<Vec<Value>, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let mut lines = vec![];
+        let mut start = 0;
+
+        for (newline, _) in input.match_indices('\n') {
+            lines.push(line(input, start, newline, &value.tag));
+            start = newline + 1;
+        }
+
+        if !(self.no_trailing && start == input.len()) {
+            lines.push(line(input, start, input.len(), &value.tag));
+        }
+
+        Ok(lines)
+    }
+}
+
+fn line(input: &str, start: usize, end: usize, tag: &Tag) -> Value {
+    let text = input[start..end].trim_end_matches('\r');
+
+    let span = if tag.span.is_unknown() {
+        Span::unknown()
+    } else {
+        let base = tag.span.start();
+        Span::new(base + start, base + start + text.len())
+    };
+
+    UntaggedValue::string(text).into_value(Tag {
+        anchor: tag.anchor.clone(),
+        span,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lines;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::string;
+
+    fn split(lines: &Lines, input: &str) -> Vec<Value> {
+        lines.lines(&string(input)).expect("a string")
+    }
+
+    #[test]
+    fn splits_on_line_feeds() {
+        assert_eq!(
+            split(&Lines::new(), "nu\nshell"),
+            vec![string("nu"), string("shell")]
+        );
+    }
+
+    #[test]
+    fn splits_on_carriage_return_line_feeds() {
+        assert_eq!(
+            split(&Lines::new(), "nu\r\nshell\r\n"),
+            vec![string("nu"), string("shell"), string("")]
+        );
+    }
+
+    #[test]
+    fn keeps_the_trailing_empty_line_by_default() {
+        assert_eq!(split(&Lines::new(), "nu\n"), vec![string("nu"), string("")]);
+    }
+
+    #[test]
+    fn drops_the_trailing_empty_line_when_asked() {
+        let mut lines = Lines::new();
+        lines.no_trailing = true;
+
+        assert_eq!(
+            split(&lines, "nu\r\nshell\r\n"),
+            vec![string("nu"), string("shell")]
+        );
+        assert_eq!(split(&lines, "nu\n\n"), vec![string("nu"), string("")]);
+        assert!(split(&lines, "").is_empty());
+    }
+
+    #[test]
+    fn tags_each_line_with_its_place_in_the_source() {
+        let input =
+            UntaggedValue::string("nu\r\nshell").into_value(Tag::unknown_anchor(Span::new(10, 19)));
+
+        let spans = Lines::new()
+            .lines(&input)
+            .expect("a string")
+            .iter()
+            .map(|line| line.tag.span)
+            .collect::<Vec<_>>();
+
+        assert_eq!(spans, vec![Span::new(10, 12), Span::new(14, 19)]);
+    }
+
+    #[test]
+    fn errors_on_anything_but_strings() {
+        let number = UntaggedValue::int(1).into_untagged_value();
+
+        assert!(Lines::new().lines(&number).is_err());
+    }
+}
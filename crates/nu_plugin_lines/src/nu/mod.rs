@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Lines;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for Lines {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("split-lines")
+            .desc("Split a string into a stream of its lines")
+            .switch(
+                "no-trailing",
+                "drop the empty line after a final newline",
+                Some('n'),
+            )
+            .example("open README.md --raw | split-lines --no-trailing")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.no_trailing = call_info.switch_present("no-trailing");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .lines(&input)?
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
@@ -0,0 +1,22 @@
+mod integration {
+    use crate::Lines;
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_test_support::value::string;
+
+    #[test]
+    fn emits_each_line_without_the_trailing_one() {
+        let run = plugin(&mut Lines::new())
+            .args(CallStub::new().with_long_flag("no-trailing").create())
+            .input(string("nu\r\nshell\r\n"))
+            .setup(|_, _| {})
+            .test()
+            .expect("lines");
+
+        let lines = run
+            .into_iter()
+            .filter_map(|returned| returned.ok().and_then(|success| success.raw_value()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines, vec![string("nu"), string("shell")]);
+    }
+}
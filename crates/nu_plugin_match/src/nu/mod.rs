@@ -1,7 +1,8 @@
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
 use nu_protocol::{
-    CallInfo, Primitive, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value,
+    CallInfo, Primitive, ReturnSuccess, ReturnValue, Signature, SpannedTypeName, SyntaxShape,
+    UntaggedValue, Value,
 };
 
 use crate::Match;
@@ -41,12 +42,8 @@ impl Plugin for Match {
                 } => {
                     self.column = s.clone();
                 }
-                Value { tag, .. } => {
-                    return Err(ShellError::labeled_error(
-                        "Unrecognized type in params",
-                        "value",
-                        tag,
-                    ));
+                value => {
+                    return Err(ShellError::type_error("string", value.spanned_type_name()));
                 }
             }
             let flags = match (insensitive, multiline, dotall) {
@@ -73,12 +70,8 @@ impl Plugin for Match {
                         )
                     })?;
                 }
-                Value { tag, .. } => {
-                    return Err(ShellError::labeled_error(
-                        "Unrecognized type in params",
-                        "unexpected value",
-                        tag,
-                    ));
+                value => {
+                    return Err(ShellError::type_error("string", value.spanned_type_name()));
                 }
             }
         }
@@ -97,22 +90,18 @@ impl Plugin for Match {
                     if let Ok(s) = val.as_string() {
                         flag = self.regex.is_match(&s);
                     } else {
-                        return Err(ShellError::labeled_error(
-                            "expected string",
-                            "value",
-                            val.tag(),
-                        ));
+                        return Err(ShellError::type_error("string", val.spanned_type_name()));
                     }
                 } else {
                     return Err(ShellError::labeled_error(
-                        format!("column not in row! {:?} {:?}", &self.column, dict),
+                        format!("column '{}' not in row", &self.column),
                         "row",
                         tag,
                     ));
                 }
             }
-            Value { tag, .. } => {
-                return Err(ShellError::labeled_error("Expected row", "value", tag));
+            value => {
+                return Err(ShellError::type_error("row", value.spanned_type_name()));
             }
         }
         if flag ^ self.invert {
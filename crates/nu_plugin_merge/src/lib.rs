@@ -0,0 +1,4 @@
+mod merge;
+mod nu;
+
+pub use merge::Merge;
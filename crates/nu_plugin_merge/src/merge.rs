@@ -0,0 +1,136 @@
+use nu_errors::ShellError;
+use nu_plugin_from_json::FromJSON;
+use nu_protocol::{Primitive, SpannedTypeName, UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct Merge {
+    pub overlay: Option<Value>,
+    pub concat_tables: bool,
+}
+
+impl Merge {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the row to merge, given either as a row or as a string holding a JSON object
+    pub fn set_overlay(&mut self, overlay: &Value) -> Result<(), ShellError> {
+        let overlay = match &overlay.value {
+            UntaggedValue::Primitive(Primitive::String(_)) => {
+                FromJSON::new().from_json(overlay.clone())?
+            }
+            _ => overlay.clone(),
+        };
+
+        if !overlay.is_row() {
+            return Err(ShellError::type_error(
+                "a row or a JSON object",
+                overlay.spanned_type_name(),
+            ));
+        }
+
+        self.overlay = Some(overlay);
+
+        Ok(())
+    }
+
+    /// Deep-merges the overlay into the row, the overlay winning on conflicts
+    pub fn merge(&self, value: &Value) -> Result<Value, ShellError> {
+        if !value.is_row() {
+            return Err(ShellError::type_error("row", value.spanned_type_name()));
+        }
+
+        Ok(match &self.overlay {
+            Some(overlay) => value.merge(overlay, self.concat_tables),
+            None => value.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Merge;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    fn merge(overlay: Value) -> Merge {
+        let mut merge = Merge::new();
+        merge.set_overlay(&overlay).expect("an overlay");
+        merge
+    }
+
+    #[test]
+    fn adds_the_overlaid_keys_to_rows_without_them() {
+        let merge = merge(row(vec![("env", string("prod"))]));
+
+        assert_eq!(
+            merge.merge(&row(vec![("name", string("web"))])),
+            Ok(row(vec![("name", string("web")), ("env", string("prod"))]))
+        );
+    }
+
+    #[test]
+    fn overrides_the_overlaid_keys_of_rows_with_them() {
+        let merge = merge(row(vec![("env", string("prod"))]));
+
+        assert_eq!(
+            merge.merge(&row(vec![("env", string("dev")), ("replicas", int(2))])),
+            Ok(row(vec![("env", string("prod")), ("replicas", int(2))]))
+        );
+    }
+
+    #[test]
+    fn reads_the_overlay_from_a_json_string() {
+        let merge = merge(string(r#"{"deploy": {"replicas": 3}}"#));
+        let base = row(vec![(
+            "deploy",
+            row(vec![("region", string("eu")), ("replicas", int(1))]),
+        )]);
+
+        assert_eq!(
+            merge.merge(&base),
+            Ok(row(vec![(
+                "deploy",
+                row(vec![("region", string("eu")), ("replicas", int(3))]),
+            )]))
+        );
+    }
+
+    #[test]
+    fn concatenates_tables_when_asked() {
+        let tags = |list: &[&str]| {
+            UntaggedValue::table(&list.iter().map(|tag| string(*tag)).collect::<Vec<_>>())
+                .into_untagged_value()
+        };
+
+        let mut merge = merge(row(vec![("tags", tags(&["prod"]))]));
+        merge.concat_tables = true;
+
+        assert_eq!(
+            merge.merge(&row(vec![("tags", tags(&["web"]))])),
+            Ok(row(vec![("tags", tags(&["web", "prod"]))]))
+        );
+    }
+
+    #[test]
+    fn errors_on_overlays_that_are_not_rows() {
+        assert!(Merge::new().set_overlay(&int(1)).is_err());
+        assert!(Merge::new().set_overlay(&string("[1, 2]")).is_err());
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_rows() {
+        let merge = merge(row(vec![("env", string("prod"))]));
+
+        assert!(merge.merge(&int(1)).is_err());
+    }
+}
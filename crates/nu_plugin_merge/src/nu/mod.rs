@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Merge;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Merge {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("merge-row")
+            .desc("Merge a row into every row of the stream, its values winning on conflicts.")
+            .required(
+                "overlay",
+                SyntaxShape::Any,
+                "the row to merge, or a string holding it as a JSON object",
+            )
+            .switch(
+                "concat",
+                "append the overlay's tables to existing ones instead of replacing them",
+                Some('c'),
+            )
+            .example("open services.yaml | merge-row '{\"env\": \"prod\"}'")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(overlay) => self.set_overlay(overlay)?,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "merge-row needs a row to merge",
+                    "requires a row or a JSON object",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.concat_tables = call_info.switch_present("concat");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.merge(&input)?)])
+    }
+}
@@ -0,0 +1,39 @@
+mod integration {
+    use crate::Merge;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn requires_an_overlay() {
+        plugin(&mut Merge::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn merges_the_overlay_into_each_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("web"));
+        });
+
+        let run = plugin(&mut Merge::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string(r#"{"env": "prod"}"#))
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "name").borrow(), &string("web"));
+        assert_eq!(get_data(&actual, "env").borrow(), &string("prod"));
+    }
+}
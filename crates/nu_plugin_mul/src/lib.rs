@@ -0,0 +1,4 @@
+mod mul;
+mod nu;
+
+pub use mul::Mul;
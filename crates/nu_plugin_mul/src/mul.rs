@@ -0,0 +1,125 @@
+use bigdecimal::BigDecimal;
+use nu_errors::{CoerceInto, ShellError};
+use nu_protocol::{ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::{HasSpan, SpannedItem, Tag, Tagged, TaggedItem};
+use nu_value_ext::update_data_at_column_path;
+
+#[derive(Clone, Default)]
+pub struct Mul {
+    pub fields: Vec<Tagged<ColumnPath>>,
+    pub factor: i64,
+}
+
+impl Mul {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn overflow(&self, input: impl std::fmt::Display, tag: impl Into<Tag>) -> ShellError {
+        ShellError::labeled_error(
+            "Multiplication overflow",
+            format!(
+                "{} multiplied by {} does not fit in a 64-bit integer",
+                input, self.factor
+            ),
+            tag.into(),
+        )
+    }
+
+    fn apply(&self, input: &str, tag: &Tag) -> Result<UntaggedValue, ShellError> {
+        match input.parse::<i64>() {
+            Ok(v) => match v.checked_mul(self.factor) {
+                Some(product) => Ok(UntaggedValue::string(format!("{}", product))),
+                None => Err(self.overflow(v, tag)),
+            },
+            Err(_) => Ok(UntaggedValue::string(input)),
+        }
+    }
+
+    pub fn mul(&self, value: Value) -> Result<Value, ShellError> {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::Int(i)) => {
+                let i: i64 = i.tagged(&value.tag).coerce_into("multiplying an integer")?;
+
+                match i.checked_mul(self.factor) {
+                    Some(product) => Ok(UntaggedValue::int(product).into_value(value.tag())),
+                    None => Err(self.overflow(i, value.tag())),
+                }
+            }
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+                let product = d * BigDecimal::from(self.factor);
+                Ok(UntaggedValue::decimal(product).into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::Filesize(b)) => {
+                if self.factor < 0 {
+                    return Err(ShellError::labeled_error(
+                        "Can not multiply a filesize by a negative factor",
+                        format!("multiplied by {}", self.factor),
+                        value.tag(),
+                    ));
+                }
+
+                let b: u64 = b.tagged(&value.tag).coerce_into("multiplying a filesize")?;
+
+                // filesizes clamp instead of erroring out
+                let product = b.saturating_mul(self.factor as u64);
+                Ok(UntaggedValue::filesize(product).into_value(value.tag()))
+            }
+            UntaggedValue::Primitive(Primitive::String(ref s)) => {
+                Ok(self.apply(&s, &value.tag)?.into_value(value.tag()))
+            }
+            UntaggedValue::Table(values) => {
+                if values.len() == 1 {
+                    Ok(UntaggedValue::Table(vec![self.mul(values[0].clone())?])
+                        .into_value(value.tag()))
+                } else {
+                    Err(ShellError::type_error(
+                        "multipliable value",
+                        value.type_name().spanned(value.span()),
+                    ))
+                }
+            }
+
+            UntaggedValue::Row(_) => {
+                if self.fields.is_empty() {
+                    return Err(ShellError::untagged_runtime_error(
+                        "mul needs a field when multiplying a column in a table",
+                    ));
+                }
+
+                let mut result = value.clone();
+
+                for field in &self.fields {
+                    result = update_data_at_column_path(&result, field, |found| self.mul(found))?;
+                }
+
+                Ok(result)
+            }
+            _ => Err(ShellError::type_error(
+                "multipliable value",
+                value.type_name().spanned(value.span()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Mul;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn numeric_string() {
+        let mut mul = Mul::new();
+        mul.factor = 3;
+        assert_eq!(mul.apply("5", &Tag::unknown()), Ok(string("15").value));
+    }
+
+    #[test]
+    fn non_numeric_string_passes_through() {
+        let mut mul = Mul::new();
+        mul.factor = 3;
+        assert_eq!(mul.apply("abc", &Tag::unknown()), Ok(string("abc").value));
+    }
+}
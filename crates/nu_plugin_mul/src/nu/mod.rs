@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Mul;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{
+    CallInfo, Primitive, ReturnSuccess, ReturnValue, ShellTypeName, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::{HasSpan, SpannedItem};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Mul {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("mul")
+            .desc("Multiply a value by a factor. Optionally use the column of a table.")
+            .required_named(
+                "by",
+                SyntaxShape::Int,
+                "the factor to multiply by (eg 5 -> 15 with --by 3)",
+                Some('b'),
+            )
+            .rest(SyntaxShape::ColumnPath, "the column(s) to update")
+            .filter()
+            .stateless())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.get_int("by")? {
+            Some(by) => self.factor = by,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "mul needs a factor",
+                    "requires --by",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        if let Some(args) = call_info.args.positional {
+            for arg in args {
+                match arg {
+                    table
+                    @
+                    Value {
+                        value: UntaggedValue::Primitive(Primitive::ColumnPath(_)),
+                        ..
+                    } => {
+                        self.fields.push(table.as_column_path()?);
+                    }
+                    value => {
+                        return Err(ShellError::type_error(
+                            "table",
+                            value.type_name().spanned(value.span()),
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.mul(input)?)])
+    }
+
+    fn worker(&self) -> Option<Box<dyn Plugin + Send>> {
+        Some(Box::new(self.clone()))
+    }
+}
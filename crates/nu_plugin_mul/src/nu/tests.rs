@@ -0,0 +1,130 @@
+mod integration {
+    use crate::Mul;
+    use nu_errors::ShellError;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_plugin::Plugin;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, decimal_from_float, error_callback, int};
+    use nu_value_ext::ValueExt;
+
+    #[test]
+    fn requires_a_factor() {
+        plugin(&mut Mul::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn multiplies_int() {
+        let run = plugin(&mut Mul::new())
+            .args(CallStub::new().with_named_parameter("by", int(3)).create())
+            .input(int(5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(15));
+    }
+
+    #[test]
+    fn workers_multiply_by_the_same_factor() {
+        let mut mul = Mul::new();
+
+        plugin(&mut mul)
+            .args(CallStub::new().with_named_parameter("by", int(3)).create())
+            .setup(|_, _| {});
+
+        let mut worker = mul.worker().expect("a worker");
+        let actual = expect_return_value_at(worker.filter(int(5)), 0);
+
+        assert_eq!(actual, int(15));
+    }
+
+    #[test]
+    fn errors_on_int_overflow() {
+        let run = plugin(&mut Mul::new())
+            .args(CallStub::new().with_named_parameter("by", int(2)).create())
+            .input(int(i64::MAX))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+
+    #[test]
+    fn multiplies_decimal() {
+        let run = plugin(&mut Mul::new())
+            .args(CallStub::new().with_named_parameter("by", int(3)).create())
+            .input(decimal_from_float(1.5))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, decimal_from_float(4.5));
+    }
+
+    #[test]
+    fn multiplies_filesize() {
+        let run = plugin(&mut Mul::new())
+            .args(CallStub::new().with_named_parameter("by", int(4)).create())
+            .input(UntaggedValue::filesize(256).into_untagged_value())
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, UntaggedValue::filesize(1024).into_untagged_value());
+    }
+
+    #[test]
+    fn filesize_clamps_to_max() {
+        let run = plugin(&mut Mul::new())
+            .args(CallStub::new().with_named_parameter("by", int(2)).create())
+            .input(UntaggedValue::filesize(u64::MAX).into_untagged_value())
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(
+            actual,
+            UntaggedValue::filesize(u64::MAX).into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn multiplies_nested_field() -> Result<(), ShellError> {
+        let price = column_path("item.price").as_column_path()?;
+
+        let run = plugin(&mut Mul::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("by", int(100))
+                    .with_parameter("item.price")?
+                    .create(),
+            )
+            .input(TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value(
+                    "item",
+                    TaggedDictBuilder::build(Tag::unknown(), |item| {
+                        item.insert_value("price", int(12));
+                    }),
+                );
+            }))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(
+            actual.get_data_by_column_path(&price, Box::new(error_callback("item.price")))?,
+            int(1200)
+        );
+        Ok(())
+    }
+}
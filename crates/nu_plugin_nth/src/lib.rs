@@ -0,0 +1,4 @@
+mod nth;
+mod nu;
+
+pub use nth::Nth;
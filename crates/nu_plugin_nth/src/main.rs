@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_nth::Nth;
+
+fn main() {
+    serve_plugin(&mut Nth::new())
+}
@@ -0,0 +1,143 @@
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use nu_source::{Tagged, TaggedItem};
+
+#[derive(Default)]
+pub struct Nth {
+    pub indices: Vec<Tagged<usize>>,
+    pub strict: bool,
+    pub position: usize,
+}
+
+impl Nth {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a zero-based position to select, which can not be negative
+    pub fn add_index(&mut self, index: &Value) -> Result<(), ShellError> {
+        let position = index.as_i64()?;
+
+        if position < 0 {
+            return Err(ShellError::labeled_error(
+                "keep-nth can not select a negative position",
+                "expected zero or more",
+                &index.tag,
+            ));
+        }
+
+        self.indices.push((position as usize).tagged(&index.tag));
+
+        Ok(())
+    }
+
+    /// Emits the value once for every time its position was asked for
+    pub fn select(&mut self, value: Value) -> Vec<Value> {
+        let position = self.position;
+        self.position += 1;
+
+        let times = self
+            .indices
+            .iter()
+            .filter(|index| index.item == position)
+            .count();
+
+        vec![value; times]
+    }
+
+    /// When strict, errors on the first position that the stream never reached
+    pub fn finish(&self) -> Result<(), ShellError> {
+        if !self.strict {
+            return Ok(());
+        }
+
+        match self
+            .indices
+            .iter()
+            .find(|index| index.item >= self.position)
+        {
+            Some(index) => Err(ShellError::labeled_error(
+                format!(
+                    "keep-nth position {} is past the end of the stream of {} values",
+                    index.item, self.position
+                ),
+                "past the end",
+                &index.tag,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Nth;
+    use nu_protocol::Value;
+    use nu_test_support::value::{int, string};
+
+    fn nth(indices: &[i64]) -> Nth {
+        let mut nth = Nth::new();
+        for index in indices {
+            nth.add_index(&int(*index)).expect("a position");
+        }
+        nth
+    }
+
+    fn letters() -> Vec<Value> {
+        vec![string("a"), string("b"), string("c"), string("d")]
+    }
+
+    fn select(nth: &mut Nth, values: Vec<Value>) -> Vec<Value> {
+        values
+            .into_iter()
+            .flat_map(|value| nth.select(value))
+            .collect()
+    }
+
+    #[test]
+    fn selects_the_values_at_the_positions() {
+        assert_eq!(
+            select(&mut nth(&[3, 0, 2]), letters()),
+            vec![string("a"), string("c"), string("d")]
+        );
+    }
+
+    #[test]
+    fn repeats_values_asked_for_more_than_once() {
+        assert_eq!(
+            select(&mut nth(&[1, 0, 1]), letters()),
+            vec![string("a"), string("b"), string("b")]
+        );
+    }
+
+    #[test]
+    fn skips_positions_past_the_end() {
+        let mut nth = nth(&[1, 9]);
+
+        assert_eq!(select(&mut nth, letters()), vec![string("b")]);
+        assert!(nth.finish().is_ok());
+    }
+
+    #[test]
+    fn errors_on_positions_past_the_end_when_strict() {
+        let mut nth = nth(&[1, 9]);
+        nth.strict = true;
+        select(&mut nth, letters());
+
+        let error = nth
+            .finish()
+            .expect_err("a position past the end")
+            .into_diagnostic()
+            .expect("a diagnostic");
+
+        assert_eq!(
+            error.message,
+            "keep-nth position 9 is past the end of the stream of 4 values"
+        );
+    }
+
+    #[test]
+    fn errors_on_negative_positions() {
+        assert!(Nth::new().add_index(&int(-1)).is_err());
+    }
+}
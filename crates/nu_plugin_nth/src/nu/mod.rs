@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Nth;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Nth {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("keep-nth")
+            .desc("Keep the values at the given zero-based positions of the stream.")
+            .required(
+                "position",
+                SyntaxShape::Int,
+                "the position of a value to keep",
+            )
+            .rest(SyntaxShape::Int, "more positions of values to keep")
+            .switch(
+                "strict",
+                "error on positions past the end of the stream",
+                Some('s'),
+            )
+            .example("ls | keep-nth 0 2 4")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if call_info.args.is_empty() {
+            return Err(ShellError::labeled_error(
+                "keep-nth needs a position to keep",
+                "requires a position",
+                &call_info.name_tag,
+            ));
+        }
+
+        for index in call_info.args.positional_iter() {
+            self.add_index(index)?;
+        }
+
+        self.strict = call_info.switch_present("strict");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .select(input)
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        self.finish()?;
+        Ok(vec![])
+    }
+}
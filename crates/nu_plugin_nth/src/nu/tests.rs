@@ -0,0 +1,42 @@
+mod integration {
+    use crate::Nth;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn requires_a_position() {
+        plugin(&mut Nth::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn keeps_the_first_value() {
+        let run = plugin(&mut Nth::new())
+            .args(CallStub::new().with_positional(int(0)).create())
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("nu"));
+    }
+
+    #[test]
+    fn errors_past_the_end_when_strict() {
+        let run = plugin(&mut Nth::new())
+            .args(
+                CallStub::new()
+                    .with_positional(int(3))
+                    .with_long_flag("strict")
+                    .create(),
+            )
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        assert!(run.is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod reject;
+
+pub use reject::Reject;
@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_reject::Reject;
+
+fn main() {
+    serve_plugin(&mut Reject::new())
+}
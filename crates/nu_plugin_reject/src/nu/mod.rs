@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Reject;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Reject {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("reject-columns")
+            .desc("Drop columns from every row of the stream.")
+            .required("field", SyntaxShape::ColumnPath, "the column to drop")
+            .rest(SyntaxShape::ColumnPath, "more columns to drop")
+            .example("open services.json | reject-columns port meta.secret")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if call_info.args.is_empty() {
+            return Err(ShellError::labeled_error(
+                "reject-columns needs a column to drop",
+                "requires a column",
+                &call_info.name_tag,
+            ));
+        }
+
+        for field in call_info.args.positional_iter() {
+            self.add_field(field)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.reject(input)?)])
+    }
+}
@@ -0,0 +1,39 @@
+mod integration {
+    use crate::Reject;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn requires_a_column() {
+        plugin(&mut Reject::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn drops_the_column_of_each_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("web"));
+            row.insert_value("port", int(80));
+        });
+
+        let run = plugin(&mut Reject::new())
+            .args(
+                CallStub::new()
+                    .with_parameter("port")
+                    .expect("the column")
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual.data_descriptors(), vec!["name".to_string()]);
+    }
+}
@@ -0,0 +1,147 @@
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, PathMember, SpannedTypeName, UnspannedPathMember, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+
+#[derive(Default)]
+pub struct Reject {
+    pub fields: Vec<Tagged<ColumnPath>>,
+}
+
+impl Reject {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a column to drop, which may be nested like `meta.secret`
+    pub fn add_field(&mut self, field: &Value) -> Result<(), ShellError> {
+        self.fields.push(field.as_column_path()?);
+
+        Ok(())
+    }
+
+    /// Drops the rejected columns from the row, skipping the ones it does not have
+    pub fn reject(&self, mut value: Value) -> Result<Value, ShellError> {
+        if !value.is_row() {
+            return Err(ShellError::type_error("row", value.spanned_type_name()));
+        }
+
+        for field in &self.fields {
+            remove_at(&mut value, field.item.members());
+        }
+
+        Ok(value)
+    }
+}
+
+fn remove_at(value: &mut Value, members: &[PathMember]) {
+    let (last, parents) = match members.split_last() {
+        Some(split) => split,
+        None => return,
+    };
+
+    let mut current = value;
+
+    for member in parents {
+        current = match (&mut current.value, &member.unspanned) {
+            (UntaggedValue::Row(dict), UnspannedPathMember::String(column)) => {
+                match dict.get_mut_data_by_key(column) {
+                    Some(nested) => nested,
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+    }
+
+    if let (UntaggedValue::Row(dict), UnspannedPathMember::String(column)) =
+        (&mut current.value, &last.unspanned)
+    {
+        dict.entries.shift_remove(column);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reject;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    fn reject(fields: &[&str]) -> Reject {
+        let mut reject = Reject::new();
+
+        for field in fields {
+            reject
+                .add_field(&column_path(field))
+                .expect("a column to drop");
+        }
+
+        reject
+    }
+
+    fn service() -> Value {
+        row(vec![
+            ("name", string("web")),
+            ("port", int(80)),
+            (
+                "meta",
+                row(vec![
+                    ("owner", string("ops")),
+                    ("secret", string("hunter2")),
+                ]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn drops_a_column() {
+        assert_eq!(
+            reject(&["port"]).reject(service()),
+            Ok(row(vec![
+                ("name", string("web")),
+                (
+                    "meta",
+                    row(vec![
+                        ("owner", string("ops")),
+                        ("secret", string("hunter2"))
+                    ]),
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn drops_a_nested_column() {
+        assert_eq!(
+            reject(&["meta.secret", "name"]).reject(service()),
+            Ok(row(vec![
+                ("port", int(80)),
+                ("meta", row(vec![("owner", string("ops"))])),
+            ]))
+        );
+    }
+
+    #[test]
+    fn skips_missing_columns() {
+        assert_eq!(
+            reject(&["host", "meta.token", "port.number"]).reject(service()),
+            Ok(service())
+        );
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_rows() {
+        assert!(reject(&["name"]).reject(int(1)).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod rename;
+
+pub use rename::Rename;
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Rename;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Rename {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("rename-columns")
+            .desc("Rename columns of every row of the stream.")
+            .required("from", SyntaxShape::String, "the column to rename")
+            .required("to", SyntaxShape::String, "the new name of the column")
+            .rest(SyntaxShape::String, "more pairs of columns and new names")
+            .switch(
+                "ignore-missing",
+                "leave rows without a column to rename alone instead of erroring",
+                Some('i'),
+            )
+            .example("open services.json | rename-columns name host port number")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let args: Vec<&Value> = call_info.args.positional_iter().collect();
+
+        if args.is_empty() {
+            return Err(ShellError::labeled_error(
+                "rename-columns needs a column to rename",
+                "requires a column and a new name",
+                &call_info.name_tag,
+            ));
+        }
+
+        for pair in args.chunks(2) {
+            match pair {
+                [from, to] => self.add_pair(from, to)?,
+                [from] => {
+                    return Err(ShellError::labeled_error(
+                        "rename-columns needs a new name for every column",
+                        "no new name for this column",
+                        &from.tag,
+                    ))
+                }
+                _ => unreachable!("chunks of two"),
+            }
+        }
+
+        self.ignore_missing = call_info.switch_present("ignore-missing");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.rename(input)?)])
+    }
+}
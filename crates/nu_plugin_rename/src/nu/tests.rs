@@ -0,0 +1,40 @@
+mod integration {
+    use crate::Rename;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+    use nu_value_ext::ValueExt;
+
+    #[test]
+    fn requires_a_new_name_for_every_column() {
+        plugin(&mut Rename::new())
+            .args(CallStub::new().with_positional(string("name")).create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn renames_the_column_of_each_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("web"));
+        });
+
+        let run = plugin(&mut Rename::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string("name"))
+                    .with_positional(string("host"))
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual.get_data("host").borrow(), &string("web"));
+        assert_eq!(actual.data_descriptors(), vec!["host".to_string()]);
+    }
+}
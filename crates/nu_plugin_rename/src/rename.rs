@@ -0,0 +1,178 @@
+use nu_errors::ShellError;
+use nu_protocol::{SpannedTypeName, UntaggedValue, Value};
+use nu_source::{Tagged, TaggedItem};
+
+#[derive(Default)]
+pub struct Rename {
+    pub pairs: Vec<(Tagged<String>, Tagged<String>)>,
+    pub ignore_missing: bool,
+}
+
+impl Rename {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a column to rename, applied after the ones added before it
+    pub fn add_pair(&mut self, from: &Value, to: &Value) -> Result<(), ShellError> {
+        let from = from.as_string()?.tagged(&from.tag);
+        let to = to.as_string()?.tagged(&to.tag);
+
+        self.pairs.push((from, to));
+
+        Ok(())
+    }
+
+    /// Moves the values of the renamed columns to their new names, keeping their tags and
+    /// their place in the row. Renaming onto a column the row already has is an error, since
+    /// one of the two values would be lost.
+    pub fn rename(&self, value: Value) -> Result<Value, ShellError> {
+        let tag = value.tag;
+
+        let mut dict = match value.value {
+            UntaggedValue::Row(dict) => dict,
+            other => {
+                return Err(ShellError::type_error(
+                    "row",
+                    other.into_value(tag).spanned_type_name(),
+                ))
+            }
+        };
+
+        for (from, to) in &self.pairs {
+            if !dict.contains_key(&from.item) {
+                if self.ignore_missing {
+                    continue;
+                }
+
+                return Err(ShellError::labeled_error(
+                    format!("no column named '{}' to rename", from.item),
+                    "not found in the row",
+                    &from.tag,
+                ));
+            }
+
+            if from.item != to.item && dict.contains_key(&to.item) {
+                return Err(ShellError::labeled_error(
+                    format!(
+                        "can not rename '{}' to '{}', which is already a column",
+                        from.item, to.item
+                    ),
+                    "already a column of the row",
+                    &to.tag,
+                ));
+            }
+
+            dict.entries = dict
+                .entries
+                .into_iter()
+                .map(|(column, value)| {
+                    if column == from.item {
+                        (to.item.clone(), value)
+                    } else {
+                        (column, value)
+                    }
+                })
+                .collect();
+        }
+
+        Ok(UntaggedValue::Row(dict).into_value(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rename;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{int, string};
+    use nu_value_ext::ValueExt;
+
+    fn row(columns: Vec<(&str, Value)>) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(column, value);
+            }
+        })
+    }
+
+    fn rename(pairs: &[(&str, &str)]) -> Rename {
+        let mut rename = Rename::new();
+
+        for (from, to) in pairs {
+            rename
+                .add_pair(&string(*from), &string(*to))
+                .expect("a column to rename");
+        }
+
+        rename
+    }
+
+    #[test]
+    fn renames_a_column() {
+        assert_eq!(
+            rename(&[("name", "host")])
+                .rename(row(vec![("name", string("web")), ("port", int(80))])),
+            Ok(row(vec![("host", string("web")), ("port", int(80))]))
+        );
+    }
+
+    #[test]
+    fn renames_several_columns_in_place() {
+        assert_eq!(
+            rename(&[("a", "x"), ("b", "y")]).rename(row(vec![
+                ("a", int(1)),
+                ("c", int(3)),
+                ("b", int(2)),
+            ])),
+            Ok(row(vec![("x", int(1)), ("c", int(3)), ("y", int(2))]))
+        );
+    }
+
+    #[test]
+    fn keeps_the_tag_of_the_renamed_value() {
+        let tag = Tag::from(Span::new(4, 7));
+        let value = string("web").value.into_value(&tag);
+
+        let renamed = rename(&[("name", "host")])
+            .rename(row(vec![("name", value)]))
+            .expect("a renamed row");
+
+        assert_eq!(renamed.get_data("host").borrow().tag, tag);
+    }
+
+    #[test]
+    fn errors_on_a_missing_column() {
+        let row = row(vec![("name", string("web"))]);
+
+        assert!(rename(&[("port", "number")]).rename(row).is_err());
+    }
+
+    #[test]
+    fn skips_a_missing_column_when_asked() {
+        let mut rename = rename(&[("port", "number"), ("name", "host")]);
+        rename.ignore_missing = true;
+
+        assert_eq!(
+            rename.rename(row(vec![("name", string("web"))])),
+            Ok(row(vec![("host", string("web"))]))
+        );
+    }
+
+    #[test]
+    fn errors_on_renaming_onto_an_existing_column() {
+        let row = row(vec![("a", int(1)), ("b", int(2))]);
+
+        let actual = format!("{:?}", rename(&[("a", "b")]).rename(row).unwrap_err());
+
+        assert!(actual.contains("can not rename 'a' to 'b', which is already a column"));
+    }
+
+    #[test]
+    fn renames_onto_a_column_renamed_before() {
+        assert_eq!(
+            rename(&[("b", "c"), ("a", "b")]).rename(row(vec![("a", int(1)), ("b", int(2))])),
+            Ok(row(vec![("b", int(1)), ("c", int(2))]))
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod reverse;
+
+pub use reverse::Reverse;
@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_reverse::Reverse;
+
+fn main() {
+    serve_plugin(&mut Reverse::new())
+}
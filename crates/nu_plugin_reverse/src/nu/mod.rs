@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Reverse;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for Reverse {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("reverse-stream")
+            .desc("Emit the stream in reverse order, once it has ended.")
+            .example("ls | reverse-stream")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.values.push(input);
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .reversed()
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
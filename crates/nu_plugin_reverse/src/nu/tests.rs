@@ -0,0 +1,23 @@
+mod integration {
+    use crate::Reverse;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::UntaggedValue;
+    use nu_source::{Span, Tag};
+
+    #[test]
+    fn emits_the_buffered_values_at_the_end() {
+        let input = UntaggedValue::string("nu").into_value(Tag::unknown_anchor(Span::new(4, 6)));
+
+        let run = plugin(&mut Reverse::new())
+            .args(CallStub::new().create())
+            .input(input.clone())
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, input);
+        assert_eq!(actual.tag, input.tag);
+    }
+}
@@ -0,0 +1,55 @@
+use nu_protocol::Value;
+
+/// Holds the whole stream in memory until it ends, so very large streams are better
+/// reversed where they come from.
+#[derive(Default)]
+pub struct Reverse {
+    pub values: Vec<Value>,
+}
+
+impl Reverse {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn reversed(&mut self) -> Vec<Value> {
+        let mut values = std::mem::take(&mut self.values);
+        values.reverse();
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reverse;
+    use nu_protocol::UntaggedValue;
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::int;
+
+    #[test]
+    fn reverses_the_stream() {
+        let mut reverse = Reverse::new();
+        reverse.values = vec![int(1), int(2), int(3)];
+
+        assert_eq!(reverse.reversed(), vec![int(3), int(2), int(1)]);
+        assert!(reverse.values.is_empty());
+    }
+
+    #[test]
+    fn keeps_the_tag_of_each_value() {
+        let tagged = |value: i64, start: usize| {
+            UntaggedValue::int(value).into_value(Tag::unknown_anchor(Span::new(start, start + 1)))
+        };
+
+        let mut reverse = Reverse::new();
+        reverse.values = vec![tagged(1, 0), tagged(2, 2)];
+
+        let spans = reverse
+            .reversed()
+            .iter()
+            .map(|value| value.tag.span)
+            .collect::<Vec<_>>();
+
+        assert_eq!(spans, vec![Span::new(2, 3), Span::new(0, 1)]);
+    }
+}
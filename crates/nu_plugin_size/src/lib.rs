@@ -0,0 +1,4 @@
+mod nu;
+mod size;
+
+pub use size::Size;
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Size;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for Size {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("text-size")
+            .desc("Count the lines, words, chars and bytes of each string")
+            .example("open README.md --raw | text-size")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.size(&input)?)])
+    }
+}
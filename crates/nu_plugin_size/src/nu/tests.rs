@@ -0,0 +1,20 @@
+mod integration {
+    use crate::Size;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn emits_the_counts_of_each_string() {
+        let run = plugin(&mut Size::new())
+            .args(CallStub::new().create())
+            .input(string("héllo"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(get_data(&actual, "chars").borrow(), &int(5));
+        assert_eq!(get_data(&actual, "bytes").borrow(), &int(6));
+    }
+}
@@ -0,0 +1,75 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct Size;
+
+impl Size {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Counts the lines, whitespace separated words, chars and UTF-8 bytes of the string
+    pub fn size(&self, value: &Value) -> Result<Value, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let mut row = TaggedDictBuilder::new(&value.tag);
+        row.insert_untagged("lines", UntaggedValue::int(input.lines().count()));
+        row.insert_untagged(
+            "words",
+            UntaggedValue::int(input.split_whitespace().count()),
+        );
+        row.insert_untagged("chars", UntaggedValue::int(input.chars().count()));
+        row.insert_untagged("bytes", UntaggedValue::int(input.len()));
+
+        Ok(row.into_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Size;
+    use nu_protocol::UntaggedValue;
+    use nu_test_support::value::{int, string};
+    use nu_value_ext::get_data;
+
+    fn counts(input: &str) -> Vec<i64> {
+        let size = Size::new().size(&string(input)).expect("a string");
+
+        assert_eq!(
+            size.data_descriptors(),
+            vec!["lines", "words", "chars", "bytes"]
+        );
+
+        ["lines", "words", "chars", "bytes"]
+            .iter()
+            .map(|column| get_data(&size, column).borrow().as_i64().expect("an int"))
+            .collect()
+    }
+
+    #[test]
+    fn counts_ascii_text() {
+        assert_eq!(counts("nu is a shell\nfor data\n"), vec![2, 6, 23, 23]);
+    }
+
+    #[test]
+    fn counts_multibyte_chars_once_and_their_bytes_apart() {
+        assert_eq!(counts("año 🦀\n"), vec![1, 2, 6, 10]);
+    }
+
+    #[test]
+    fn counts_an_empty_string_as_zeros() {
+        assert_eq!(counts(""), vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn errors_on_anything_but_strings() {
+        assert!(Size::new().size(&int(1)).is_err());
+        assert!(Size::new()
+            .size(&UntaggedValue::nothing().into_untagged_value())
+            .is_err());
+    }
+}
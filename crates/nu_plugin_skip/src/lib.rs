@@ -0,0 +1,4 @@
+mod nu;
+mod skip;
+
+pub use skip::Skip;
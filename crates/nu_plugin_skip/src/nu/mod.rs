@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Skip;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for Skip {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("drop-first")
+            .desc("Drop the first values of the stream.")
+            .optional(
+                "rows",
+                SyntaxShape::Int,
+                "how many values to drop (defaults to 1)",
+            )
+            .named(
+                "while",
+                SyntaxShape::String,
+                "drop values while a predicate like 'size < 100' holds instead",
+                Some('w'),
+            )
+            .example("ls | drop-first --while \"size < 100\"")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if let Some(rows) = call_info.args.nth(0) {
+            self.set_rows(rows)?;
+        }
+
+        if let Some(predicate) = call_info.args.get("while") {
+            self.set_while(&predicate.as_string()?, &predicate.tag)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .skip(input)
+            .map(ReturnSuccess::value)
+            .into_iter()
+            .collect())
+    }
+}
@@ -0,0 +1,28 @@
+mod integration {
+    use crate::Skip;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn drops_a_single_value() {
+        let run = plugin(&mut Skip::new())
+            .args(CallStub::new().create())
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .test()
+            .expect("skipped");
+
+        assert!(run.is_empty());
+    }
+
+    #[test]
+    fn passes_values_through_once_skipped() {
+        let run = plugin(&mut Skip::new())
+            .args(CallStub::new().with_positional(int(0)).create())
+            .input(string("nu"))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("nu"));
+    }
+}
@@ -0,0 +1,211 @@
+use nu_errors::ShellError;
+use nu_plugin_where::{Comparison, Where};
+use nu_protocol::{ColumnPath, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tag, TaggedItem};
+
+pub struct Skip {
+    pub rows: usize,
+    pub skip_while: Option<Where>,
+    pub skipping: bool,
+}
+
+impl Default for Skip {
+    fn default() -> Self {
+        Skip {
+            rows: 1,
+            skip_while: None,
+            skipping: true,
+        }
+    }
+}
+
+impl Skip {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets how many values to drop, which can not be negative
+    pub fn set_rows(&mut self, rows: &Value) -> Result<(), ShellError> {
+        let count = rows.as_i64()?;
+
+        if count < 0 {
+            return Err(ShellError::labeled_error(
+                "drop-first can not drop a negative number of values",
+                "expected zero or more",
+                &rows.tag,
+            ));
+        }
+
+        self.rows = count as usize;
+
+        Ok(())
+    }
+
+    /// Parses a predicate such as `size < 100` into the condition to skip while,
+    /// which replaces the number of values to drop
+    pub fn set_while(&mut self, predicate: &str, tag: impl Into<Tag>) -> Result<(), ShellError> {
+        let tag = tag.into();
+        let mut parts = predicate.split_whitespace();
+
+        let (field, operator, expected) = match (parts.next(), parts.next()) {
+            (Some(field), Some(operator)) => (field, operator, parts.collect::<Vec<_>>().join(" ")),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "drop-first needs a predicate like 'field < value' to skip while",
+                    "expected a field, an operator and a value",
+                    &tag,
+                ))
+            }
+        };
+
+        if expected.is_empty() {
+            return Err(ShellError::labeled_error(
+                format!(
+                    "the predicate '{}' has nothing to compare against",
+                    predicate
+                ),
+                "expected a value after the operator",
+                &tag,
+            ));
+        }
+
+        let mut condition = Where::new();
+        condition.field =
+            Some(ColumnPath::build(&field.to_string().spanned(tag.span)).tagged(&tag));
+        condition.comparison = Comparison::parse(operator, tag.span)?;
+        condition.value = Some(literal(&expected, &tag));
+
+        self.skip_while = Some(condition);
+
+        Ok(())
+    }
+
+    /// Drops the value while skipping, and passes it through once skipping is over
+    pub fn skip(&mut self, value: Value) -> Option<Value> {
+        if self.skipping {
+            self.skipping = match &self.skip_while {
+                Some(condition) => condition.matches(&value),
+                None if self.rows > 0 => {
+                    self.rows -= 1;
+                    return None;
+                }
+                None => false,
+            };
+        }
+
+        if self.skipping {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// Reads the compared value as an integer or a decimal when it looks like one, and as a
+/// string otherwise
+fn literal(text: &str, tag: &Tag) -> Value {
+    let unquoted = text.trim_matches(|c| c == '"' || c == '\'');
+
+    if unquoted.len() == text.len() {
+        if let Ok(int) = text.parse::<i64>() {
+            return UntaggedValue::int(int).into_value(tag);
+        }
+
+        if let Ok(float) = text.parse::<f64>() {
+            return UntaggedValue::decimal_from_float(float, tag.span).into_value(tag);
+        }
+    }
+
+    UntaggedValue::string(unquoted).into_value(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Skip;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn skip(skip: &mut Skip, values: Vec<Value>) -> Vec<Value> {
+        values
+            .into_iter()
+            .filter_map(|value| skip.skip(value))
+            .collect()
+    }
+
+    fn skip_rows(rows: i64, values: Vec<Value>) -> Vec<Value> {
+        let mut skipper = Skip::new();
+        skipper.set_rows(&int(rows)).expect("a row count");
+        skip(&mut skipper, values)
+    }
+
+    fn package(name: &str, downloads: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string(name));
+            row.insert_value("downloads", int(downloads));
+        })
+    }
+
+    #[test]
+    fn drops_the_first_values() {
+        assert_eq!(
+            skip_rows(2, vec![int(1), int(2), int(3), int(4)]),
+            vec![int(3), int(4)]
+        );
+    }
+
+    #[test]
+    fn drops_one_value_by_default() {
+        assert_eq!(skip(&mut Skip::new(), vec![int(1), int(2)]), vec![int(2)]);
+    }
+
+    #[test]
+    fn drops_everything_when_skipping_past_the_end() {
+        assert!(skip_rows(5, vec![int(1), int(2)]).is_empty());
+    }
+
+    #[test]
+    fn errors_on_negative_counts() {
+        assert!(Skip::new().set_rows(&int(-1)).is_err());
+    }
+
+    #[test]
+    fn drops_values_while_the_predicate_holds() {
+        let mut skipper = Skip::new();
+        skipper
+            .set_while("downloads < 20", Tag::unknown())
+            .expect("a predicate");
+
+        assert_eq!(
+            skip(
+                &mut skipper,
+                vec![package("nu", 10), package("serde", 20), package("rayon", 5)]
+            ),
+            vec![package("serde", 20), package("rayon", 5)]
+        );
+    }
+
+    #[test]
+    fn compares_quoted_values_as_strings() {
+        let mut skipper = Skip::new();
+        skipper
+            .set_while("name == 'nu'", Tag::unknown())
+            .expect("a predicate");
+
+        assert_eq!(
+            skip(&mut skipper, vec![package("nu", 10), package("serde", 20)]),
+            vec![package("serde", 20)]
+        );
+    }
+
+    #[test]
+    fn errors_on_incomplete_predicates() {
+        assert!(Skip::new()
+            .set_while("downloads <", Tag::unknown())
+            .is_err());
+        assert!(Skip::new().set_while("downloads", Tag::unknown()).is_err());
+        assert!(Skip::new()
+            .set_while("downloads ~ 3", Tag::unknown())
+            .is_err());
+    }
+}
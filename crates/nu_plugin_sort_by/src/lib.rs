@@ -0,0 +1,4 @@
+mod nu;
+mod sort_by;
+
+pub use sort_by::SortBy;
@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_sort_by::SortBy;
+
+fn main() {
+    serve_plugin(&mut SortBy::new())
+}
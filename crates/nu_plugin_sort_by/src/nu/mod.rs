@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests;
+
+use crate::SortBy;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for SortBy {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("sort-by-field")
+            .desc("Sort the values of the stream by a column.")
+            .required("field", SyntaxShape::ColumnPath, "the column to sort by")
+            .switch(
+                "reverse",
+                "sort from the greatest to the least value",
+                Some('r'),
+            )
+            .example("ls | sort-by-field size --reverse")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(field) => self.field = Some(field.as_column_path()?),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "sort-by-field needs a column to sort by",
+                    "requires a column path",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.reverse = call_info.switch_present("reverse");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.values.push(input);
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .sorted()
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
@@ -0,0 +1,65 @@
+mod integration {
+    use crate::SortBy;
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_plugin::Plugin;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int};
+
+    fn sized(size: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("size", int(size));
+        })
+    }
+
+    #[test]
+    fn requires_a_column() {
+        plugin(&mut SortBy::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn picks_up_the_column_and_reverse_flag() {
+        plugin(&mut SortBy::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .with_long_flag("reverse")
+                    .create(),
+            )
+            .setup(|plugin, _| {
+                assert!(plugin.field.is_some());
+                assert!(plugin.reverse);
+            });
+    }
+
+    #[test]
+    fn emits_the_sorted_stream_at_the_end() {
+        let mut sort_by = SortBy::new();
+
+        plugin(&mut sort_by)
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .create(),
+            )
+            .setup(|_, _| {});
+
+        for size in &[3, 1, 2] {
+            let returned = sort_by.filter(sized(*size)).expect("buffered");
+            assert!(returned.is_empty());
+        }
+
+        let actual = sort_by
+            .end_filter()
+            .expect("sorted values")
+            .into_iter()
+            .filter_map(|returned| returned.ok().and_then(|value| value.raw_value()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec![sized(1), sized(2), sized(3)]);
+    }
+}
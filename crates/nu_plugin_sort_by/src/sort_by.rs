@@ -0,0 +1,211 @@
+use nu_protocol::{ColumnPath, Primitive, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::get_data_by_column_path;
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct SortBy {
+    pub field: Option<Tagged<ColumnPath>>,
+    pub reverse: bool,
+    pub values: Vec<Value>,
+}
+
+impl SortBy {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn key(&self, value: &Value) -> Option<Value> {
+        let field = self.field.as_ref()?;
+        get_data_by_column_path(value, field, |_, _, error| error).ok()
+    }
+
+    /// Sorts the buffered values by the field. Values missing the field sort before every
+    /// value that has it, fields of different types sort by their type (see `type_rank`),
+    /// and values that can not be compared keep their order.
+    pub fn sorted(&mut self) -> Vec<Value> {
+        let values = std::mem::take(&mut self.values);
+
+        let mut keyed = values
+            .into_iter()
+            .map(|value| (self.key(&value), value))
+            .collect::<Vec<_>>();
+
+        keyed.sort_by(|(left, _), (right, _)| match (left, right) {
+            (Some(left), Some(right)) => compare_keys(left, right),
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        if self.reverse {
+            keyed.reverse();
+        }
+
+        keyed.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+/// Where the values of a type sort among the values of other types. Ints and decimals share a
+/// rank since they compare with each other.
+fn type_rank(value: &Value) -> u8 {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => 0,
+        UntaggedValue::Primitive(Primitive::Boolean(_)) => 1,
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_)) => 2,
+        UntaggedValue::Primitive(Primitive::Filesize(_)) => 3,
+        UntaggedValue::Primitive(Primitive::Duration(_)) => 4,
+        UntaggedValue::Primitive(Primitive::Date(_)) => 5,
+        UntaggedValue::Primitive(Primitive::String(_)) => 6,
+        UntaggedValue::Primitive(Primitive::FilePath(_)) => 7,
+        UntaggedValue::Primitive(_) => 8,
+        UntaggedValue::Row(_) => 9,
+        UntaggedValue::Table(_) => 10,
+        UntaggedValue::Error(_) | UntaggedValue::Block(_) => 11,
+    }
+}
+
+/// A total order over the keys: by type first, then by value for primitives of the same type.
+/// Rows and tables are left in their order, since comparing them can fail on nested values.
+fn compare_keys(left: &Value, right: &Value) -> Ordering {
+    type_rank(left)
+        .cmp(&type_rank(right))
+        .then_with(|| match (&left.value, &right.value) {
+            (UntaggedValue::Primitive(_), UntaggedValue::Primitive(_)) => {
+                left.compare(right).unwrap_or(Ordering::Equal)
+            }
+            _ => Ordering::Equal,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortBy;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+    use nu_value_ext::ValueExt;
+
+    fn crate_row(name: &str, stars: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string(name));
+            row.insert_value("stars", int(stars));
+        })
+    }
+
+    fn sort_by(field: &str, values: Vec<Value>) -> SortBy {
+        let mut sort_by = SortBy::new();
+        sort_by.field = Some(column_path(field).as_column_path().expect("a column path"));
+        sort_by.values = values;
+        sort_by
+    }
+
+    #[test]
+    fn sorts_by_a_numeric_field() {
+        let mut sort_by = sort_by(
+            "stars",
+            vec![
+                crate_row("nu", 20),
+                crate_row("serde", 5),
+                crate_row("rayon", 10),
+            ],
+        );
+
+        assert_eq!(
+            sort_by.sorted(),
+            vec![
+                crate_row("serde", 5),
+                crate_row("rayon", 10),
+                crate_row("nu", 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn sorts_by_a_string_field() {
+        let mut sort_by = sort_by(
+            "name",
+            vec![
+                crate_row("serde", 5),
+                crate_row("nu", 20),
+                crate_row("rayon", 10),
+            ],
+        );
+
+        assert_eq!(
+            sort_by.sorted(),
+            vec![
+                crate_row("nu", 20),
+                crate_row("rayon", 10),
+                crate_row("serde", 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn reverses_the_order() {
+        let mut sort_by = sort_by(
+            "stars",
+            vec![
+                crate_row("serde", 5),
+                crate_row("nu", 20),
+                crate_row("rayon", 10),
+            ],
+        );
+        sort_by.reverse = true;
+
+        assert_eq!(
+            sort_by.sorted(),
+            vec![
+                crate_row("nu", 20),
+                crate_row("rayon", 10),
+                crate_row("serde", 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn values_missing_the_field_sort_first() {
+        let unnamed = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("stars", int(1));
+        });
+
+        let mut sort_by = sort_by("name", vec![crate_row("nu", 20), unnamed.clone()]);
+
+        assert_eq!(sort_by.sorted(), vec![unnamed, crate_row("nu", 20)]);
+    }
+
+    #[test]
+    fn sorts_mixed_types_by_type_then_value() {
+        let keyed = |key: Value| {
+            TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("key", key);
+            })
+        };
+
+        let mut sort_by = sort_by(
+            "key",
+            vec![
+                keyed(string("b")),
+                keyed(int(3)),
+                keyed(string("a")),
+                keyed(int(1)),
+                keyed(string("c")),
+                keyed(int(2)),
+            ],
+        );
+
+        assert_eq!(
+            sort_by.sorted(),
+            vec![
+                keyed(int(1)),
+                keyed(int(2)),
+                keyed(int(3)),
+                keyed(string("a")),
+                keyed(string("b")),
+                keyed(string("c")),
+            ]
+        );
+    }
+}
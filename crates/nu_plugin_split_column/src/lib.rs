@@ -0,0 +1,4 @@
+mod nu;
+mod split_column;
+
+pub use split_column::SplitColumn;
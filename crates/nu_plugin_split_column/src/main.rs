@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_split_column::SplitColumn;
+
+fn main() {
+    serve_plugin(&mut SplitColumn::new())
+}
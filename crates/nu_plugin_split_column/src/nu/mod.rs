@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests;
+
+use crate::SplitColumn;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+
+impl Plugin for SplitColumn {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("split-column")
+            .desc("Split each string into the columns of a row.")
+            .required(
+                "separator",
+                SyntaxShape::String,
+                "the text that separates the columns",
+            )
+            .switch("collapse-empty", "skip empty tokens", Some('c'))
+            .switch(
+                "discard-extra",
+                "drop tokens beyond the named columns instead of keeping them in rest",
+                Some('d'),
+            )
+            .rest(SyntaxShape::String, "the names of the columns")
+            .example("open people.txt | lines | split-column \" \" name age city")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        match call_info.args.nth(0) {
+            Some(separator) => self.separator = separator.as_string()?,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "split-column needs a separator",
+                    "requires a separator",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.columns = call_info
+            .args
+            .slice_from(1)
+            .iter()
+            .map(|column| column.as_string())
+            .collect::<Result<Vec<_>, ShellError>>()?;
+
+        self.collapse_empty = call_info.switch_present("collapse-empty");
+        self.discard_extra = call_info.switch_present("discard-extra");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.split(&input)?)])
+    }
+}
@@ -0,0 +1,27 @@
+mod integration {
+    use crate::SplitColumn;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    #[test]
+    fn splits_into_the_named_columns() {
+        let run = plugin(&mut SplitColumn::new())
+            .args(
+                CallStub::new()
+                    .with_positional(string(" "))
+                    .with_positional(string("name"))
+                    .with_positional(string("age"))
+                    .with_long_flag("discard-extra")
+                    .create(),
+            )
+            .input(string("andres 31 Guayaquil"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual.data_descriptors(), vec!["name", "age"]);
+        assert_eq!(get_data(&actual, "age").borrow(), &string("31"));
+    }
+}
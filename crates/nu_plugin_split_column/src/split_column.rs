@@ -0,0 +1,142 @@
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, SpannedTypeName, TaggedDictBuilder, UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct SplitColumn {
+    pub separator: String,
+    pub columns: Vec<String>,
+    pub collapse_empty: bool,
+    pub discard_extra: bool,
+}
+
+impl SplitColumn {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Splits the string on the separator and assigns the tokens to the named columns in order.
+    ///
+    /// Columns without a token are left empty. Tokens without a column are gathered into
+    /// `rest`, unless `discard_extra` is set. Without any column names, they are made up as
+    /// `Column1`, `Column2` and so on.
+    pub fn split(&self, value: &Value) -> Result<Value, ShellError> {
+        let input = match &value.value {
+            UntaggedValue::Primitive(Primitive::String(s)) => s,
+            _ => return Err(ShellError::type_error("string", value.spanned_type_name())),
+        };
+
+        let separator = self.separator.replace("\\n", "\n");
+        let mut tokens = input
+            .split(separator.as_str())
+            .filter(|token| !(self.collapse_empty && token.is_empty()));
+
+        let mut row = TaggedDictBuilder::new(&value.tag);
+
+        if self.columns.is_empty() {
+            for (index, token) in tokens.enumerate() {
+                row.insert_untagged(format!("Column{}", index + 1), UntaggedValue::string(token));
+            }
+
+            return Ok(row.into_value());
+        }
+
+        for column in &self.columns {
+            let token = tokens.next().unwrap_or("");
+            row.insert_untagged(column, UntaggedValue::string(token));
+        }
+
+        let extra = tokens
+            .map(|token| UntaggedValue::string(token).into_value(&value.tag))
+            .collect::<Vec<_>>();
+
+        if !extra.is_empty() && !self.discard_extra {
+            row.insert_untagged("rest", UntaggedValue::Table(extra));
+        }
+
+        Ok(row.into_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitColumn;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_test_support::value::string;
+    use nu_value_ext::get_data;
+
+    fn split_column(columns: &[&str]) -> SplitColumn {
+        let mut split_column = SplitColumn::new();
+        split_column.separator = " ".to_string();
+        split_column.columns = columns.iter().map(|column| column.to_string()).collect();
+        split_column
+    }
+
+    fn column(row: &Value, name: &str) -> Value {
+        get_data(row, name).borrow().clone()
+    }
+
+    #[test]
+    fn assigns_each_token_to_its_column() {
+        let actual = split_column(&["name", "age", "city"])
+            .split(&string("andres 31 Guayaquil"))
+            .expect("a row");
+
+        assert_eq!(actual.data_descriptors(), vec!["name", "age", "city"]);
+        assert_eq!(column(&actual, "name"), string("andres"));
+        assert_eq!(column(&actual, "age"), string("31"));
+        assert_eq!(column(&actual, "city"), string("Guayaquil"));
+    }
+
+    #[test]
+    fn gathers_extra_tokens_into_rest() {
+        let actual = split_column(&["name", "age"])
+            .split(&string("andres 31 Guayaquil Ecuador"))
+            .expect("a row");
+
+        assert_eq!(actual.data_descriptors(), vec!["name", "age", "rest"]);
+        assert_eq!(
+            column(&actual, "rest"),
+            UntaggedValue::table(&[string("Guayaquil"), string("Ecuador")]).into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn discards_extra_tokens_when_asked() {
+        let mut split_column = split_column(&["name", "age"]);
+        split_column.discard_extra = true;
+
+        let actual = split_column
+            .split(&string("andres 31 Guayaquil Ecuador"))
+            .expect("a row");
+
+        assert_eq!(actual.data_descriptors(), vec!["name", "age"]);
+    }
+
+    #[test]
+    fn leaves_columns_without_tokens_empty() {
+        let actual = split_column(&["name", "age", "city"])
+            .split(&string("andres"))
+            .expect("a row");
+
+        assert_eq!(actual.data_descriptors(), vec!["name", "age", "city"]);
+        assert_eq!(column(&actual, "age"), string(""));
+        assert_eq!(column(&actual, "city"), string(""));
+    }
+
+    #[test]
+    fn collapses_empty_tokens_when_asked() {
+        let mut split_column = split_column(&["name", "age"]);
+        split_column.collapse_empty = true;
+
+        let actual = split_column.split(&string("andres   31")).expect("a row");
+
+        assert_eq!(column(&actual, "age"), string("31"));
+    }
+
+    #[test]
+    fn makes_up_column_names_when_none_are_given() {
+        let actual = split_column(&[]).split(&string("a b")).expect("a row");
+
+        assert_eq!(actual.data_descriptors(), vec!["Column1", "Column2"]);
+    }
+}
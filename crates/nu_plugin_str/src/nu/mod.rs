@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests;
+
+use crate::strutils::Action;
+use crate::Str;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{
+    CallInfo, Primitive, ReturnSuccess, ReturnValue, ShellTypeName, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::{HasSpan, SpannedItem};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Str {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("str-convert")
+            .desc("Apply string function. Optionally use the column of a table.")
+            .switch("upcase", "convert string to uppercase", Some('U'))
+            .switch("downcase", "convert string to lowercase", Some('d'))
+            .switch("trim", "trim leading and trailing whitespace", Some('t'))
+            .switch(
+                "capitalize",
+                "capitalize the first letter and lowercase the rest",
+                Some('c'),
+            )
+            .switch("strict", "fail on values that are not strings", Some('s'))
+            .exclusive_group(&["upcase", "downcase", "trim", "capitalize"])
+            .rest(SyntaxShape::ColumnPath, "the column(s) to convert")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if call_info.switch_present("upcase") {
+            self.for_action(Action::Upcase);
+        }
+        if call_info.switch_present("downcase") {
+            self.for_action(Action::Downcase);
+        }
+        if call_info.switch_present("trim") {
+            self.for_action(Action::Trim);
+        }
+        if call_info.switch_present("capitalize") {
+            self.for_action(Action::Capitalize);
+        }
+        self.strict = call_info.switch_present("strict");
+
+        if let Some(args) = call_info.args.positional {
+            for arg in args {
+                match arg {
+                    table
+                    @
+                    Value {
+                        value: UntaggedValue::Primitive(Primitive::ColumnPath(_)),
+                        ..
+                    } => {
+                        self.fields.push(table.as_column_path()?);
+                    }
+                    value => {
+                        return Err(ShellError::type_error(
+                            "table",
+                            value.type_name().spanned(value.span()),
+                        ))
+                    }
+                }
+            }
+        }
+
+        if self.action.is_none() {
+            self.log_error("requires a transformation");
+        }
+
+        match &self.error {
+            Some(reason) => Err(ShellError::untagged_runtime_error(format!(
+                "{}: {}",
+                reason,
+                Str::usage()
+            ))),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.strutils(input)?)])
+    }
+}
@@ -0,0 +1,190 @@
+mod integration {
+    use crate::strutils::Action;
+    use crate::Str;
+    use nu_errors::ShellError;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, error_callback, int, string};
+    use nu_value_ext::ValueExt;
+
+    fn structured_sample_record(key: &str, value: &str) -> nu_protocol::Value {
+        TaggedDictBuilder::build(Tag::unknown(), |record| {
+            record.insert_value(key, string(value));
+        })
+    }
+
+    #[test]
+    fn picks_up_one_action_flag_only() {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("upcase")
+                    .with_long_flag("downcase")
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("can only apply one"));
+                assert_eq!(plugin.error, Some("can only apply one".to_string()));
+            });
+    }
+
+    #[test]
+    fn requires_a_transformation() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().create())
+            .setup(|_, returned_values| {
+                let actual = format!("{}", returned_values.unwrap_err());
+
+                assert!(actual.contains("requires a transformation"));
+            });
+    }
+
+    #[test]
+    fn picks_up_upcase_flag() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("upcase").create())
+            .setup(|plugin, _| plugin.expect_action(Action::Upcase));
+    }
+
+    #[test]
+    fn picks_up_downcase_flag() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("downcase").create())
+            .setup(|plugin, _| plugin.expect_action(Action::Downcase));
+    }
+
+    #[test]
+    fn picks_up_trim_flag() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("trim").create())
+            .setup(|plugin, _| plugin.expect_action(Action::Trim));
+    }
+
+    #[test]
+    fn picks_up_capitalize_flag() {
+        plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("capitalize").create())
+            .setup(|plugin, _| plugin.expect_action(Action::Capitalize));
+    }
+
+    #[test]
+    fn picks_up_argument_for_field() -> Result<(), ShellError> {
+        plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("upcase")
+                    .with_parameter("package.description")?
+                    .create(),
+            )
+            .setup(|plugin, _| plugin.expect_field(column_path("package.description")));
+        Ok(())
+    }
+
+    #[test]
+    fn upcases_the_input() {
+        let run = plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("upcase").create())
+            .input(string("andres"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("ANDRES"));
+    }
+
+    #[test]
+    fn downcases_the_input() {
+        let run = plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("downcase").create())
+            .input(string("ANDRES"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("andres"));
+    }
+
+    #[test]
+    fn trims_the_input() {
+        let run = plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("trim").create())
+            .input(string("  andres  "))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("andres"));
+    }
+
+    #[test]
+    fn capitalizes_the_input() {
+        let run = plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("capitalize").create())
+            .input(string("andres"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("Andres"));
+    }
+
+    #[test]
+    fn upcases_the_field_given() -> Result<(), ShellError> {
+        let name = column_path("name").as_column_path()?;
+
+        let run = plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("upcase")
+                    .with_parameter("name")?
+                    .create(),
+            )
+            .input(structured_sample_record("name", "jotandrehuda"))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(
+            actual.get_data_by_column_path(&name, Box::new(error_callback("name")))?,
+            string("JOTANDREHUDA")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn passes_through_non_strings() {
+        let run = plugin(&mut Str::new())
+            .args(CallStub::new().with_long_flag("upcase").create())
+            .input(int(10))
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(10));
+    }
+
+    #[test]
+    fn rejects_non_strings_when_strict() {
+        let run = plugin(&mut Str::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("upcase")
+                    .with_long_flag("strict")
+                    .create(),
+            )
+            .input(int(10))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+}
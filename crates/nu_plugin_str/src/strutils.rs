@@ -0,0 +1,139 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::{HasSpan, SpannedItem, Tagged};
+use nu_value_ext::update_data_at_column_path;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Action {
+    Upcase,
+    Downcase,
+    Trim,
+    Capitalize,
+}
+
+#[derive(Default)]
+pub struct Str {
+    pub fields: Vec<Tagged<ColumnPath>>,
+    pub strict: bool,
+    pub error: Option<String>,
+    pub action: Option<Action>,
+}
+
+impl Str {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply(&self, input: &str) -> UntaggedValue {
+        match &self.action {
+            Some(Action::Upcase) => UntaggedValue::string(input.to_uppercase()),
+            Some(Action::Downcase) => UntaggedValue::string(input.to_lowercase()),
+            Some(Action::Trim) => UntaggedValue::string(input.trim()),
+            Some(Action::Capitalize) => {
+                let mut chars = input.chars();
+
+                match chars.next() {
+                    Some(first) => UntaggedValue::string(format!(
+                        "{}{}",
+                        first.to_uppercase(),
+                        chars.as_str().to_lowercase()
+                    )),
+                    None => UntaggedValue::string(input),
+                }
+            }
+            None => UntaggedValue::string(input),
+        }
+    }
+
+    pub fn for_action(&mut self, action: Action) {
+        if self.permit() {
+            self.action = Some(action);
+        } else {
+            self.log_error("can only apply one");
+        }
+    }
+
+    fn permit(&mut self) -> bool {
+        self.action.is_none()
+    }
+
+    pub fn log_error(&mut self, message: &str) {
+        self.error = Some(message.to_string());
+    }
+
+    pub fn usage() -> &'static str {
+        "Usage: str-convert field [--upcase|--downcase|--trim|--capitalize|--strict]"
+    }
+
+    pub fn strutils(&self, value: Value) -> Result<Value, ShellError> {
+        match &value.value {
+            UntaggedValue::Primitive(Primitive::String(ref s)) => {
+                Ok(self.apply(&s).into_value(value.tag()))
+            }
+            UntaggedValue::Row(_) if !self.fields.is_empty() => {
+                let mut result = value.clone();
+
+                for field in &self.fields {
+                    result =
+                        update_data_at_column_path(&result, field, |found| self.strutils(found))?;
+                }
+
+                Ok(result)
+            }
+            _ if self.strict => Err(ShellError::type_error(
+                "string",
+                value.type_name().spanned(value.span()),
+            )),
+            _ => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Str};
+    use nu_test_support::value::string;
+
+    fn str_with(action: Action) -> Str {
+        let mut strutils = Str::new();
+        strutils.for_action(action);
+        strutils
+    }
+
+    #[test]
+    fn upcases() {
+        assert_eq!(
+            str_with(Action::Upcase).apply("andres"),
+            string("ANDRES").value
+        );
+    }
+
+    #[test]
+    fn downcases() {
+        assert_eq!(
+            str_with(Action::Downcase).apply("ANDRES"),
+            string("andres").value
+        );
+    }
+
+    #[test]
+    fn trims() {
+        assert_eq!(
+            str_with(Action::Trim).apply("  andres \n"),
+            string("andres").value
+        );
+    }
+
+    #[test]
+    fn capitalizes() {
+        assert_eq!(
+            str_with(Action::Capitalize).apply("aNDRES"),
+            string("Andres").value
+        );
+    }
+
+    #[test]
+    fn capitalizes_empty_string() {
+        assert_eq!(str_with(Action::Capitalize).apply(""), string("").value);
+    }
+}
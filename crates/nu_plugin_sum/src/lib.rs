@@ -0,0 +1,4 @@
+mod nu;
+mod sum;
+
+pub use sum::Sum;
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Sum;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Sum {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("sum")
+            .desc("Total the numbers of the stream, or a column of its rows.")
+            .optional("field", SyntaxShape::ColumnPath, "the column to total")
+            .switch(
+                "skip-non-numeric",
+                "leave out values that are not numbers instead of erroring",
+                Some('s'),
+            )
+            .example("ls | sum size")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        if let Some(field) = call_info.args.nth(0) {
+            self.field = Some(field.as_column_path()?);
+        }
+
+        self.skip_non_numeric = call_info.switch_present("skip-non-numeric");
+        self.tag = call_info.name_tag;
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.add(&input)?;
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.sum())])
+    }
+}
@@ -0,0 +1,30 @@
+mod integration {
+    use crate::Sum;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::int;
+
+    #[test]
+    fn emits_the_total_of_a_field_at_the_end() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("stars", int(20));
+        });
+
+        let run = plugin(&mut Sum::new())
+            .args(
+                CallStub::new()
+                    .with_parameter("stars")
+                    .expect("a column path")
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, int(20));
+    }
+}
@@ -0,0 +1,230 @@
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, SpannedTypeName, UntaggedValue, Value};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::get_data_by_column_path;
+use num_bigint::BigInt;
+
+#[derive(Default)]
+pub struct Sum {
+    pub field: Option<Tagged<ColumnPath>>,
+    pub skip_non_numeric: bool,
+    pub total: BigDecimal,
+    pub any_decimal: bool,
+    pub count: usize,
+    pub filesizes: usize,
+    pub tag: Tag,
+}
+
+impl Sum {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the value, or its field when one is set, to the total.
+    ///
+    /// Ints, filesizes, decimals and numeric strings count, and are added up exactly. Anything
+    /// else is an error, unless `skip_non_numeric` is set.
+    pub fn add(&mut self, value: &Value) -> Result<(), ShellError> {
+        let number = match &self.field {
+            Some(field) => get_data_by_column_path(value, field, |_, _, error| error)
+                .and_then(|found| number_of(&found).map(|number| (number, is_filesize(&found)))),
+            None => number_of(value).map(|number| (number, is_filesize(value))),
+        };
+
+        let ((number, is_decimal), is_filesize) = match number {
+            Ok(number) => number,
+            Err(_) if self.skip_non_numeric => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        self.total += number;
+        self.any_decimal |= is_decimal;
+        self.count += 1;
+
+        if is_filesize {
+            self.filesizes += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Whether at least one value was added, and every value added was a filesize
+    pub fn all_filesizes(&self) -> bool {
+        self.count > 0 && self.filesizes == self.count
+    }
+
+    /// The total, as a filesize when every value added was one, as a decimal when any of them
+    /// was one and as an int otherwise
+    pub fn sum(&self) -> Value {
+        if self.all_filesizes() {
+            let (total, _) = self.total.with_scale(0).as_bigint_and_exponent();
+            UntaggedValue::filesize(total).into_value(&self.tag)
+        } else if self.any_decimal {
+            UntaggedValue::decimal(self.total.clone()).into_value(&self.tag)
+        } else {
+            let (total, _) = self.total.with_scale(0).as_bigint_and_exponent();
+            UntaggedValue::int(total).into_value(&self.tag)
+        }
+    }
+}
+
+fn is_filesize(value: &Value) -> bool {
+    matches!(
+        value.value,
+        UntaggedValue::Primitive(Primitive::Filesize(_))
+    )
+}
+
+/// The number the value holds, and whether it is a decimal
+fn number_of(value: &Value) -> Result<(BigDecimal, bool), ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(int))
+        | UntaggedValue::Primitive(Primitive::Filesize(int)) => {
+            Ok((BigDecimal::new(int.clone(), 0), false))
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(decimal)) => Ok((decimal.clone(), true)),
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            let s = s.trim();
+
+            if let Ok(int) = s.parse::<BigInt>() {
+                Ok((BigDecimal::new(int, 0), false))
+            } else if let Ok(decimal) = s.parse::<BigDecimal>() {
+                Ok((decimal, true))
+            } else {
+                Err(ShellError::labeled_error(
+                    format!("'{}' is not a number", s),
+                    "expected a number",
+                    &value.tag,
+                ))
+            }
+        }
+        _ => Err(ShellError::type_error("number", value.spanned_type_name())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sum;
+    use bigdecimal::BigDecimal;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, decimal, decimal_from_float, int, string};
+    use nu_value_ext::ValueExt;
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+
+    fn exactly(number: &str) -> Value {
+        decimal(BigDecimal::from_str(number).expect("a decimal"))
+    }
+
+    fn crate_row(name: &str, stars: Value) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string(name));
+            row.insert_value("stars", stars);
+        })
+    }
+
+    fn filesize(bytes: i64) -> Value {
+        UntaggedValue::filesize(bytes).into_untagged_value()
+    }
+
+    fn sum_of(sum: &mut Sum, values: &[Value]) -> Result<Value, nu_errors::ShellError> {
+        for value in values {
+            sum.add(value)?;
+        }
+
+        Ok(sum.sum())
+    }
+
+    #[test]
+    fn totals_a_stream_of_ints() {
+        assert_eq!(
+            sum_of(&mut Sum::new(), &[int(1), int(2), int(39)]),
+            Ok(int(42))
+        );
+    }
+
+    #[test]
+    fn totals_a_field_across_rows() {
+        let mut sum = Sum::new();
+        sum.field = Some(
+            column_path("stars")
+                .as_column_path()
+                .expect("a column path"),
+        );
+
+        assert_eq!(
+            sum_of(
+                &mut sum,
+                &[crate_row("nu", int(20)), crate_row("nu-cli", string("22"))]
+            ),
+            Ok(int(42))
+        );
+    }
+
+    #[test]
+    fn totals_decimals_exactly() {
+        assert_eq!(
+            sum_of(&mut Sum::new(), &[exactly("1.5"), exactly("1.5")]),
+            Ok(exactly("3"))
+        );
+    }
+
+    #[test]
+    fn totals_as_a_decimal_when_any_value_is_one() {
+        assert_eq!(
+            sum_of(
+                &mut Sum::new(),
+                &[int(1), decimal_from_float(0.5), string("2.25")]
+            ),
+            Ok(exactly("3.75"))
+        );
+    }
+
+    #[test]
+    fn totals_filesizes_as_a_filesize() {
+        assert_eq!(
+            sum_of(&mut Sum::new(), &[filesize(1024), filesize(512)]),
+            Ok(filesize(1536))
+        );
+    }
+
+    #[test]
+    fn totals_filesizes_mixed_with_ints_as_an_int() {
+        assert_eq!(
+            sum_of(&mut Sum::new(), &[filesize(1024), int(512)]),
+            Ok(int(1536))
+        );
+    }
+
+    #[test]
+    fn totals_an_empty_stream_as_zero() {
+        assert_eq!(Sum::new().sum(), int(0));
+    }
+
+    #[test]
+    fn errors_on_non_numeric_values() {
+        assert!(sum_of(&mut Sum::new(), &[int(1), string("nu")]).is_err());
+    }
+
+    #[test]
+    fn skips_non_numeric_values_when_asked() {
+        let mut sum = Sum::new();
+        sum.skip_non_numeric = true;
+
+        assert_eq!(
+            sum_of(&mut sum, &[int(1), string("nu"), int(2)]),
+            Ok(int(3))
+        );
+        assert_eq!(sum.count, 2);
+    }
+
+    #[test]
+    fn totals_past_the_64_bit_range() {
+        assert_eq!(
+            sum_of(&mut Sum::new(), &[int(i64::MAX), int(1)]),
+            Ok(int(BigInt::from(i64::MAX) + 1))
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod to_csv;
+
+pub use to_csv::ToCSV;
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ToCSV;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_source::Tag;
+
+impl Plugin for ToCSV {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("to csv-doc")
+            .desc("Serialize a table into a CSV string")
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the character separating the fields (eg ;)",
+                Some('s'),
+            )
+            .switch(
+                "no-headers",
+                "leave the line with the column names out",
+                Some('n'),
+            )
+            .example("open Cargo.lock | get package | to csv-doc")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.headers = !call_info.switch_present("no-headers");
+
+        if let Some(separator) = call_info.args.get("separator") {
+            self.separator(&separator.as_string()?, separator.tag.span)?;
+        }
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        self.push(input)?;
+        Ok(vec![])
+    }
+
+    fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.to_csv(Tag::unknown())?)])
+    }
+}
@@ -0,0 +1,31 @@
+mod integration {
+    use crate::ToCSV;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn emits_the_table_once_the_stream_ends() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("stars", int(20));
+        });
+
+        let run = plugin(&mut ToCSV::new())
+            .args(
+                CallStub::new()
+                    .with_long_flag("no-headers")
+                    .with_named_parameter("separator", string("|"))
+                    .create(),
+            )
+            .input(row)
+            .setup(|_, _| {})
+            .finish()
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("nu|20\n"));
+    }
+}
@@ -0,0 +1,203 @@
+use nu_errors::ShellError;
+use nu_protocol::{SpannedTypeName, UntaggedValue, Value};
+use nu_source::{Span, Tag};
+
+pub struct ToCSV {
+    pub headers: bool,
+    pub separator: u8,
+    pub rows: Vec<Value>,
+}
+
+impl Default for ToCSV {
+    fn default() -> Self {
+        ToCSV {
+            headers: true,
+            separator: b',',
+            rows: vec![],
+        }
+    }
+}
+
+impl ToCSV {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn separator(&mut self, separator: &str, span: Span) -> Result<(), ShellError> {
+        match separator.as_bytes() {
+            [byte] => {
+                self.separator = *byte;
+                Ok(())
+            }
+            _ => Err(ShellError::labeled_error(
+                format!("'{}' can not separate CSV fields", separator),
+                "expected a single character",
+                span,
+            )),
+        }
+    }
+
+    pub fn push(&mut self, value: Value) -> Result<(), ShellError> {
+        match &value.value {
+            UntaggedValue::Row(_) => {
+                self.rows.push(value);
+                Ok(())
+            }
+            _ => Err(ShellError::type_error("row", value.spanned_type_name())),
+        }
+    }
+
+    /// Writes the rows collected so far. The columns are every key found in the rows, in
+    /// the order they were first seen, and rows without a key get an empty field for it.
+    pub fn to_csv(&self, tag: Tag) -> Result<Value, ShellError> {
+        let mut columns: Vec<&String> = vec![];
+        for row in &self.rows {
+            for (key, _) in row.row_entries() {
+                if !columns.contains(&key) {
+                    columns.push(key);
+                }
+            }
+        }
+
+        let failed = |error: csv::Error| {
+            ShellError::labeled_error(
+                format!("could not write CSV: {}", error),
+                "could not write CSV",
+                &tag,
+            )
+        };
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.separator)
+            .from_writer(vec![]);
+
+        if self.headers {
+            writer.write_record(&columns).map_err(failed)?;
+        }
+
+        for row in &self.rows {
+            let mut record = vec![];
+
+            for column in &columns {
+                let cell = match row.row_entries().find(|(key, _)| key == column) {
+                    Some((_, value)) => field(value)?,
+                    None => String::new(),
+                };
+                record.push(cell);
+            }
+
+            writer.write_record(&record).map_err(failed)?;
+        }
+
+        let bytes = writer.into_inner().map_err(|error| {
+            ShellError::labeled_error(
+                format!("could not write CSV: {}", error),
+                "could not write CSV",
+                &tag,
+            )
+        })?;
+
+        Ok(UntaggedValue::string(String::from_utf8_lossy(&bytes)).into_value(tag))
+    }
+}
+
+fn field(value: &Value) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(_) => Ok(value.convert_to_string()),
+        _ => Err(ShellError::type_error(
+            "a value that fits into a CSV field",
+            value.spanned_type_name(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToCSV;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{int, string};
+
+    fn row(columns: &[(&str, Value)]) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (column, value) in columns {
+                row.insert_value(*column, value.clone());
+            }
+        })
+    }
+
+    fn to_csv(writer: &mut ToCSV, rows: Vec<Value>) -> Value {
+        for row in rows {
+            writer.push(row).expect("a row");
+        }
+
+        writer.to_csv(Tag::unknown()).expect("written")
+    }
+
+    #[test]
+    fn fills_in_the_columns_missing_from_ragged_rows() {
+        let rows = vec![
+            row(&[("name", string("nu")), ("stars", int(20))]),
+            row(&[("name", string("serde")), ("license", string("MIT"))]),
+        ];
+
+        assert_eq!(
+            to_csv(&mut ToCSV::new(), rows),
+            string("name,stars,license\nnu,20,\nserde,,MIT\n")
+        );
+    }
+
+    #[test]
+    fn separates_fields_with_a_custom_separator() {
+        let mut writer = ToCSV::new();
+        writer.separator(";", Span::unknown()).expect("a separator");
+
+        let rows = vec![row(&[
+            ("name", string("nu")),
+            ("description", string("a, b")),
+        ])];
+
+        assert_eq!(
+            to_csv(&mut writer, rows),
+            string("name;description\nnu;a, b\n")
+        );
+    }
+
+    #[test]
+    fn leaves_the_header_out_when_asked() {
+        let mut writer = ToCSV::new();
+        writer.headers = false;
+
+        let rows = vec![row(&[("name", string("nu")), ("stars", int(20))])];
+
+        assert_eq!(to_csv(&mut writer, rows), string("nu,20\n"));
+    }
+
+    #[test]
+    fn quotes_fields_holding_the_separator() {
+        let rows = vec![row(&[("description", string("a, b"))])];
+
+        assert_eq!(
+            to_csv(&mut ToCSV::new(), rows),
+            string("description\n\"a, b\"\n")
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_rows() {
+        assert!(ToCSV::new().push(string("nu")).is_err());
+    }
+
+    #[test]
+    fn rejects_nested_tables() {
+        let mut writer = ToCSV::new();
+        writer
+            .push(row(&[(
+                "tags",
+                UntaggedValue::table(&[string("shell")]).into_untagged_value(),
+            )]))
+            .expect("a row");
+
+        assert!(writer.to_csv(Tag::unknown()).is_err());
+    }
+}
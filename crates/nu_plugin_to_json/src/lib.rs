@@ -0,0 +1,4 @@
+mod nu;
+mod to_json;
+
+pub use to_json::ToJSON;
@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_to_json::ToJSON;
+
+fn main() {
+    serve_plugin(&mut ToJSON::new())
+}
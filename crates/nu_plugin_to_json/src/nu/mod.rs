@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ToJSON;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for ToJSON {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("to json-doc")
+            .desc("Serialize each value into a JSON string")
+            .switch("pretty", "indent the JSON over several lines", Some('p'))
+            .example("open Cargo.toml | to json-doc --pretty")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.pretty = call_info.switch_present("pretty");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.to_json(&input)?)])
+    }
+}
@@ -0,0 +1,24 @@
+mod integration {
+    use crate::ToJSON;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn emits_pretty_json_when_asked() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("stars", int(20));
+        });
+
+        let run = plugin(&mut ToJSON::new())
+            .args(CallStub::new().with_long_flag("pretty").create())
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("{\n  \"stars\": 20\n}"));
+    }
+}
@@ -0,0 +1,182 @@
+use nu_errors::ShellError;
+use nu_protocol::{format_duration, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::SpannedItem;
+
+#[derive(Default)]
+pub struct ToJSON {
+    pub pretty: bool,
+}
+
+impl ToJSON {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn to_json(&self, value: &Value) -> Result<Value, ShellError> {
+        let json = to_json_value(value)?;
+
+        let serialized = if self.pretty {
+            serde_json::to_string_pretty(&json)
+        } else {
+            serde_json::to_string(&json)
+        };
+
+        let serialized = serialized.map_err(|error| {
+            ShellError::labeled_error(
+                format!("could not serialize to JSON: {}", error),
+                "could not serialize",
+                &value.tag,
+            )
+        })?;
+
+        Ok(UntaggedValue::string(serialized).into_value(&value.tag))
+    }
+}
+
+/// Converts the value into JSON. Dates become RFC 3339 strings, durations their
+/// human-readable form, and binary data an array of its bytes.
+fn to_json_value(value: &Value) -> Result<serde_json::Value, ShellError> {
+    let span = value.tag.span;
+
+    Ok(match &value.value {
+        UntaggedValue::Primitive(primitive) => match primitive {
+            Primitive::Nothing | Primitive::BeginningOfStream | Primitive::EndOfStream => {
+                serde_json::Value::Null
+            }
+            Primitive::Boolean(b) => serde_json::Value::Bool(*b),
+            Primitive::Int(_) => match primitive.as_i64(span) {
+                Ok(i) => serde_json::Value::from(i),
+                Err(_) => serde_json::Value::from(primitive.as_u64(span)?),
+            },
+            Primitive::Filesize(size) => {
+                serde_json::Value::from(Primitive::Int(size.clone()).as_u64(span)?)
+            }
+            Primitive::Decimal(decimal) => decimal
+                .to_string()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(serde_json::Value::Number)
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        format!("{} can not be represented as a JSON number", decimal),
+                        "not representable",
+                        span,
+                    )
+                })?,
+            Primitive::String(s) | Primitive::GlobPattern(s) => serde_json::Value::from(s.clone()),
+            Primitive::FilePath(path) => serde_json::Value::from(path.display().to_string()),
+            Primitive::ColumnPath(path) => serde_json::Value::from(
+                path.iter()
+                    .map(|member| member.as_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            Primitive::Date(date) => serde_json::Value::from(date.to_rfc3339()),
+            Primitive::Duration(duration) => serde_json::Value::from(format_duration(duration)),
+            Primitive::Binary(bytes) => serde_json::Value::Array(
+                bytes.iter().map(|b| serde_json::Value::from(*b)).collect(),
+            ),
+            Primitive::Range(_) => return Err(unsupported(value)),
+        },
+        UntaggedValue::Row(row) => {
+            let mut object = serde_json::Map::new();
+            for (key, item) in row.entries.iter() {
+                object.insert(key.clone(), to_json_value(item)?);
+            }
+            serde_json::Value::Object(object)
+        }
+        UntaggedValue::Table(list) => serde_json::Value::Array(
+            list.iter()
+                .map(to_json_value)
+                .collect::<Result<Vec<_>, ShellError>>()?,
+        ),
+        UntaggedValue::Error(error) => return Err(error.clone()),
+        UntaggedValue::Block(_) => return Err(unsupported(value)),
+    })
+}
+
+fn unsupported(value: &Value) -> ShellError {
+    ShellError::type_error(
+        "a value that can be represented as JSON",
+        value.type_name().spanned(value.tag.span),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToJSON;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn package() -> Value {
+        let version = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("major", int(0));
+            row.insert_value("minor", int(27));
+        });
+
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("version", version);
+            row.insert_value(
+                "tags",
+                UntaggedValue::table(&[string("shell")]).into_untagged_value(),
+            );
+        })
+    }
+
+    #[test]
+    fn serializes_a_nested_object_compactly() {
+        assert_eq!(
+            ToJSON::new().to_json(&package()),
+            Ok(string(
+                r#"{"name":"nu","tags":["shell"],"version":{"major":0,"minor":27}}"#
+            ))
+        );
+    }
+
+    #[test]
+    fn serializes_a_nested_object_pretty() {
+        let mut to_json = ToJSON::new();
+        to_json.pretty = true;
+
+        let expected = [
+            "{",
+            r#"  "name": "nu","#,
+            r#"  "tags": ["#,
+            r#"    "shell""#,
+            "  ],",
+            r#"  "version": {"#,
+            r#"    "major": 0,"#,
+            r#"    "minor": 27"#,
+            "  }",
+            "}",
+        ]
+        .join("\n");
+
+        assert_eq!(to_json.to_json(&package()), Ok(string(&expected)));
+    }
+
+    #[test]
+    fn serializes_binary_as_an_array_of_bytes() {
+        let binary = UntaggedValue::binary(vec![0, 255]).into_untagged_value();
+
+        assert_eq!(ToJSON::new().to_json(&binary), Ok(string("[0,255]")));
+    }
+
+    #[test]
+    fn serializes_deeply_nested_values() {
+        let mut nested = int(1);
+        for _ in 0..500 {
+            nested = UntaggedValue::table(&[nested]).into_untagged_value();
+        }
+
+        let actual = ToJSON::new().to_json(&nested).expect("serialized");
+
+        assert_eq!(
+            actual,
+            string(&format!("{}1{}", "[".repeat(500), "]".repeat(500)))
+        );
+    }
+}
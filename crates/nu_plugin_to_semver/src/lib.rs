@@ -0,0 +1,4 @@
+mod nu;
+mod to_semver;
+
+pub use to_semver::ToSemVer;
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ToSemVer;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for ToSemVer {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("to semver")
+            .desc("Assemble a row of major, minor, patch and optional prerelease and build parts into a semantic version")
+            .example("echo 1.2.3 | from semver | update minor 5 | to semver")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.to_semver(input)?)])
+    }
+}
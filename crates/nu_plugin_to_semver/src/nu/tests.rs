@@ -0,0 +1,36 @@
+mod integration {
+    use crate::ToSemVer;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn emits_the_assembled_version() {
+        let run = plugin(&mut ToSemVer::new())
+            .args(CallStub::new().create())
+            .input(TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("major", int(2));
+                row.insert_value("minor", int(0));
+                row.insert_value("patch", int(1));
+                row.insert_value("prerelease", string("rc.1"));
+            }))
+            .setup(|_, _| {})
+            .test();
+
+        assert_eq!(expect_return_value_at(run, 0), string("2.0.1-rc.1"));
+    }
+
+    #[test]
+    fn reports_rows_without_a_version_as_errors() {
+        let run = plugin(&mut ToSemVer::new())
+            .args(CallStub::new().create())
+            .input(TaggedDictBuilder::build(Tag::unknown(), |row| {
+                row.insert_value("name", string("nu"));
+            }))
+            .setup(|_, _| {})
+            .test();
+
+        assert!(run.is_err());
+    }
+}
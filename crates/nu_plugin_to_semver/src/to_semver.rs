@@ -0,0 +1,134 @@
+use nu_errors::ShellError;
+use nu_protocol::{SpannedTypeName, UntaggedValue, Value};
+
+#[derive(Default)]
+pub struct ToSemVer;
+
+impl ToSemVer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn to_semver(&self, value: Value) -> Result<Value, ShellError> {
+        let row = match &value.value {
+            UntaggedValue::Row(row) => row,
+            _ => return Err(ShellError::type_error("row", value.spanned_type_name())),
+        };
+
+        let required = |name: &str| match row.entries.get(name) {
+            Some(part) => part.as_u64(),
+            None => Err(ShellError::labeled_error(
+                format!("to semver needs a '{}' column", name),
+                format!("row is missing '{}'", name),
+                value.tag.span,
+            )),
+        };
+
+        let optional = |name: &str| match row.entries.get(name) {
+            Some(part) => part.as_string(),
+            None => Ok(String::new()),
+        };
+
+        let mut version = format!(
+            "{}.{}.{}",
+            required("major")?,
+            required("minor")?,
+            required("patch")?
+        );
+
+        let prerelease = optional("prerelease")?;
+        if !prerelease.is_empty() {
+            version.push('-');
+            version.push_str(&prerelease);
+        }
+
+        let build = optional("build")?;
+        if !build.is_empty() {
+            version.push('+');
+            version.push_str(&build);
+        }
+
+        let version = semver::Version::parse(&version).map_err(|_| {
+            ShellError::labeled_error(
+                format!("'{}' is not a valid semantic version", version),
+                "could not assemble a semantic version from this row",
+                value.tag.span,
+            )
+        })?;
+
+        Ok(UntaggedValue::string(version.to_string()).into_value(value.tag()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToSemVer;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn version(parts: &[(&str, Value)]) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            for (name, value) in parts {
+                row.insert_value(*name, value.clone());
+            }
+        })
+    }
+
+    #[test]
+    fn assembles_major_minor_and_patch() {
+        let parts = version(&[("major", int(1)), ("minor", int(2)), ("patch", int(3))]);
+
+        assert_eq!(ToSemVer::new().to_semver(parts), Ok(string("1.2.3")));
+    }
+
+    #[test]
+    fn assembles_prerelease_and_build_metadata() {
+        let parts = version(&[
+            ("major", int(1)),
+            ("minor", int(0)),
+            ("patch", int(0)),
+            ("prerelease", string("alpha.1")),
+            ("build", string("sha.5114f85")),
+        ]);
+
+        assert_eq!(
+            ToSemVer::new().to_semver(parts),
+            Ok(string("1.0.0-alpha.1+sha.5114f85"))
+        );
+    }
+
+    #[test]
+    fn treats_empty_prerelease_and_build_as_absent() {
+        let parts = version(&[
+            ("major", int(0)),
+            ("minor", int(27)),
+            ("patch", int(2)),
+            ("prerelease", string("")),
+            ("build", string("")),
+        ]);
+
+        assert_eq!(ToSemVer::new().to_semver(parts), Ok(string("0.27.2")));
+    }
+
+    #[test]
+    fn errors_on_a_missing_component() {
+        let parts = version(&[("major", int(1)), ("minor", int(2))]);
+
+        let actual = format!("{:?}", ToSemVer::new().to_semver(parts).unwrap_err());
+
+        assert!(actual.contains("to semver needs a 'patch' column"));
+    }
+
+    #[test]
+    fn errors_on_an_invalid_prerelease() {
+        let parts = version(&[
+            ("major", int(1)),
+            ("minor", int(2)),
+            ("patch", int(3)),
+            ("prerelease", string("not valid!")),
+        ]);
+
+        assert!(ToSemVer::new().to_semver(parts).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod to_toml;
+
+pub use to_toml::ToTOML;
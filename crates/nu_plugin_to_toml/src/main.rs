@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_to_toml::ToTOML;
+
+fn main() {
+    serve_plugin(&mut ToTOML::new())
+}
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ToTOML;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for ToTOML {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("to toml-doc")
+            .desc("Serialize each row into a TOML string")
+            .example("open Cargo.toml | inc --patch package.version | to toml-doc")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.to_toml(&input)?)])
+    }
+}
@@ -0,0 +1,24 @@
+mod integration {
+    use crate::ToTOML;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::string;
+
+    #[test]
+    fn emits_the_serialized_row() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("version", string("0.27.3"));
+        });
+
+        let run = plugin(&mut ToTOML::new())
+            .args(CallStub::new().create())
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("version = \"0.27.3\"\n"));
+    }
+}
@@ -0,0 +1,162 @@
+use nu_errors::ShellError;
+use nu_protocol::{
+    format_duration, Primitive, ShellTypeName, SpannedTypeName, UntaggedValue, Value,
+};
+use nu_source::SpannedItem;
+
+#[derive(Default)]
+pub struct ToTOML;
+
+impl ToTOML {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Serializes a row into a TOML document, with the keys of each table sorted
+    pub fn to_toml(&self, value: &Value) -> Result<Value, ShellError> {
+        if !matches!(value.value, UntaggedValue::Row(_)) {
+            return Err(ShellError::type_error("row", value.spanned_type_name()));
+        }
+
+        let serialized = toml::to_string(&to_toml_value(value)?).map_err(|error| {
+            ShellError::labeled_error(
+                format!("could not serialize to TOML: {}", error),
+                "could not serialize",
+                &value.tag,
+            )
+        })?;
+
+        Ok(UntaggedValue::string(serialized).into_value(&value.tag))
+    }
+}
+
+fn to_toml_value(value: &Value) -> Result<toml::Value, ShellError> {
+    let span = value.tag.span;
+
+    Ok(match &value.value {
+        UntaggedValue::Primitive(primitive) => match primitive {
+            Primitive::Boolean(b) => toml::Value::Boolean(*b),
+            Primitive::Int(_) => toml::Value::Integer(primitive.as_i64(span)?),
+            Primitive::Filesize(size) => {
+                toml::Value::Integer(Primitive::Int(size.clone()).as_i64(span)?)
+            }
+            Primitive::Decimal(decimal) => match decimal.to_string().parse::<f64>() {
+                Ok(f) => toml::Value::Float(f),
+                Err(_) => return Err(unsupported(value)),
+            },
+            Primitive::String(s) | Primitive::GlobPattern(s) => toml::Value::String(s.clone()),
+            Primitive::FilePath(path) => toml::Value::String(path.display().to_string()),
+            Primitive::ColumnPath(path) => toml::Value::String(
+                path.iter()
+                    .map(|member| member.as_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            Primitive::Date(date) => match date.to_rfc3339().parse() {
+                Ok(datetime) => toml::Value::Datetime(datetime),
+                Err(_) => return Err(unsupported(value)),
+            },
+            Primitive::Duration(duration) => toml::Value::String(format_duration(duration)),
+            // TOML has no way to write nothing, binary data or ranges
+            Primitive::Nothing
+            | Primitive::Binary(_)
+            | Primitive::Range(_)
+            | Primitive::BeginningOfStream
+            | Primitive::EndOfStream => return Err(unsupported(value)),
+        },
+        UntaggedValue::Row(row) => {
+            let mut table = toml::value::Table::new();
+            for (key, item) in row.entries.iter() {
+                table.insert(key.clone(), to_toml_value(item)?);
+            }
+            toml::Value::Table(table)
+        }
+        UntaggedValue::Table(list) => toml::Value::Array(
+            list.iter()
+                .map(to_toml_value)
+                .collect::<Result<Vec<_>, ShellError>>()?,
+        ),
+        UntaggedValue::Error(error) => return Err(error.clone()),
+        UntaggedValue::Block(_) => return Err(unsupported(value)),
+    })
+}
+
+fn unsupported(value: &Value) -> ShellError {
+    ShellError::type_error(
+        "a value that can be represented as TOML",
+        value.type_name().spanned(value.tag.span),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToTOML;
+    use nu_plugin_from_toml::FromTOML;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, string};
+    use nu_value_ext::ValueExt;
+
+    const MANIFEST: &str = r#"[dependencies.serde]
+features = ["derive"]
+version = "1.0"
+
+[package]
+name = "nu"
+version = "0.27.2"
+"#;
+
+    #[test]
+    fn round_trips_a_manifest_with_a_bumped_version() {
+        let parsed = FromTOML::new()
+            .from_toml(string(MANIFEST))
+            .expect("valid TOML");
+
+        let version = column_path("package.version")
+            .as_column_path()
+            .expect("a column path");
+        let bumped = parsed
+            .replace_data_at_column_path(&version, string("0.27.3"))
+            .expect("a version to replace");
+
+        assert_eq!(
+            ToTOML::new().to_toml(&bumped),
+            Ok(string(MANIFEST.replace("0.27.2", "0.27.3")))
+        );
+    }
+
+    #[test]
+    fn keeps_plain_values_ahead_of_nested_tables() {
+        let package = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+        });
+        let manifest = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("package", package);
+            row.insert_value("cargo-features", string("edition2021"));
+        });
+
+        assert_eq!(
+            ToTOML::new().to_toml(&manifest),
+            Ok(string(
+                "cargo-features = \"edition2021\"\n\n[package]\nname = \"nu\"\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn errors_on_binary_data() {
+        let manifest = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value(
+                "icon",
+                UntaggedValue::binary(vec![0, 1]).into_untagged_value(),
+            );
+        });
+
+        assert!(ToTOML::new().to_toml(&manifest).is_err());
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_rows() {
+        assert!(ToTOML::new().to_toml(&string("nu")).is_err());
+    }
+}
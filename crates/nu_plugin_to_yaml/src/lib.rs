@@ -0,0 +1,4 @@
+mod nu;
+mod to_yaml;
+
+pub use to_yaml::ToYAML;
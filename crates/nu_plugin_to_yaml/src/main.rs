@@ -0,0 +1,6 @@
+use nu_plugin::serve_plugin;
+use nu_plugin_to_yaml::ToYAML;
+
+fn main() {
+    serve_plugin(&mut ToYAML::new())
+}
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ToYAML;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for ToYAML {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("to yaml-doc")
+            .desc("Serialize each value into a YAML document")
+            .example("open deployment.yaml | to yaml-doc")
+            .filter())
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(vec![ReturnSuccess::value(self.to_yaml(&input)?)])
+    }
+}
@@ -0,0 +1,24 @@
+mod integration {
+    use crate::ToYAML;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    #[test]
+    fn emits_a_yaml_document() {
+        let row = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("stars", int(20));
+        });
+
+        let run = plugin(&mut ToYAML::new())
+            .args(CallStub::new().create())
+            .input(row)
+            .setup(|_, _| {})
+            .test();
+
+        let actual = expect_return_value_at(run, 0);
+
+        assert_eq!(actual, string("---\nstars: 20\n"));
+    }
+}
@@ -0,0 +1,146 @@
+use nu_errors::ShellError;
+use nu_protocol::{format_duration, Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::SpannedItem;
+
+#[derive(Default)]
+pub struct ToYAML;
+
+impl ToYAML {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Serializes the value into a YAML document, keeping the order of its keys
+    pub fn to_yaml(&self, value: &Value) -> Result<Value, ShellError> {
+        let serialized = serde_yaml::to_string(&to_yaml_value(value)?).map_err(|error| {
+            ShellError::labeled_error(
+                format!("could not serialize to YAML: {}", error),
+                "could not serialize",
+                &value.tag,
+            )
+        })?;
+
+        Ok(UntaggedValue::string(format!("{}\n", serialized.trim_end())).into_value(&value.tag))
+    }
+}
+
+fn to_yaml_value(value: &Value) -> Result<serde_yaml::Value, ShellError> {
+    let span = value.tag.span;
+
+    Ok(match &value.value {
+        UntaggedValue::Primitive(primitive) => match primitive {
+            Primitive::Nothing | Primitive::BeginningOfStream | Primitive::EndOfStream => {
+                serde_yaml::Value::Null
+            }
+            Primitive::Boolean(b) => serde_yaml::Value::Bool(*b),
+            Primitive::Int(_) => match primitive.as_i64(span) {
+                Ok(i) => serde_yaml::Value::from(i),
+                Err(_) => serde_yaml::Value::from(primitive.as_u64(span)?),
+            },
+            Primitive::Filesize(size) => {
+                serde_yaml::Value::from(Primitive::Int(size.clone()).as_u64(span)?)
+            }
+            Primitive::Decimal(decimal) => decimal
+                .to_string()
+                .parse::<f64>()
+                .map(serde_yaml::Value::from)
+                .map_err(|_| {
+                    ShellError::labeled_error(
+                        format!("{} can not be represented as a YAML number", decimal),
+                        "not representable",
+                        span,
+                    )
+                })?,
+            Primitive::String(s) | Primitive::GlobPattern(s) => serde_yaml::Value::from(s.clone()),
+            Primitive::FilePath(path) => serde_yaml::Value::from(path.display().to_string()),
+            Primitive::ColumnPath(path) => serde_yaml::Value::from(
+                path.iter()
+                    .map(|member| member.as_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            ),
+            Primitive::Date(date) => serde_yaml::Value::from(date.to_rfc3339()),
+            Primitive::Duration(duration) => serde_yaml::Value::from(format_duration(duration)),
+            Primitive::Binary(_) | Primitive::Range(_) => return Err(unsupported(value)),
+        },
+        UntaggedValue::Row(row) => {
+            let mut mapping = serde_yaml::Mapping::new();
+            for (key, item) in row.entries.iter() {
+                mapping.insert(serde_yaml::Value::from(key.clone()), to_yaml_value(item)?);
+            }
+            serde_yaml::Value::Mapping(mapping)
+        }
+        UntaggedValue::Table(list) => serde_yaml::Value::Sequence(
+            list.iter()
+                .map(to_yaml_value)
+                .collect::<Result<Vec<_>, ShellError>>()?,
+        ),
+        UntaggedValue::Error(error) => return Err(error.clone()),
+        UntaggedValue::Block(_) => return Err(unsupported(value)),
+    })
+}
+
+fn unsupported(value: &Value) -> ShellError {
+    ShellError::type_error(
+        "a value that can be represented as YAML",
+        value.type_name().spanned(value.tag.span),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToYAML;
+    use nu_plugin_from_yaml::FromYAML;
+    use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{int, string};
+
+    fn deployment() -> Value {
+        let metadata = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("nu"));
+            row.insert_value("replicas", int(3));
+        });
+
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("kind", string("Deployment"));
+            row.insert_value("metadata", metadata);
+        })
+    }
+
+    #[test]
+    fn serializes_a_nested_mapping() {
+        let expected = [
+            "---",
+            "kind: Deployment",
+            "metadata:",
+            "  name: nu",
+            "  replicas: 3",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(ToYAML::new().to_yaml(&deployment()), Ok(string(&expected)));
+    }
+
+    #[test]
+    fn round_trips_through_from_yaml() {
+        let mut document = deployment();
+        if let UntaggedValue::Row(row) = &mut document.value {
+            row.entries.insert(
+                "ports".to_string(),
+                UntaggedValue::table(&[int(80), int(443)]).into_untagged_value(),
+            );
+        }
+
+        let serialized = ToYAML::new().to_yaml(&document).expect("serialized");
+
+        assert_eq!(FromYAML::new().from_yaml(&serialized), Ok(vec![document]));
+    }
+
+    #[test]
+    fn errors_on_binary_data() {
+        let binary = UntaggedValue::binary(vec![0, 255]).into_untagged_value();
+
+        assert!(ToYAML::new().to_yaml(&binary).is_err());
+    }
+}
@@ -7,7 +7,9 @@ use crate::TreeViewer;
 
 impl Plugin for TreeViewer {
     fn config(&mut self) -> Result<Signature, ShellError> {
-        Ok(Signature::build("tree").desc("View the contents of the pipeline as a tree."))
+        Ok(Signature::build("tree")
+            .desc("View the contents of the pipeline as a tree.")
+            .sink())
     }
 
     fn sink(&mut self, _call_info: CallInfo, input: Vec<Value>) {
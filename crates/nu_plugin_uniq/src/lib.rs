@@ -0,0 +1,4 @@
+mod nu;
+mod uniq;
+
+pub use uniq::Uniq;
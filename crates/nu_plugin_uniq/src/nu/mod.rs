@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Uniq;
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, Value};
+
+impl Plugin for Uniq {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("uniq-values")
+            .desc("Drop duplicate values from the stream.")
+            .switch(
+                "global",
+                "drop every duplicate instead of only the adjacent ones",
+                Some('g'),
+            )
+            .example("open versions.txt | lines | uniq-values --global")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        self.global = call_info.switch_present("global");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        Ok(self
+            .unique(input)
+            .into_iter()
+            .map(ReturnSuccess::value)
+            .collect())
+    }
+}
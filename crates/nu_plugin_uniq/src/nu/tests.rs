@@ -0,0 +1,52 @@
+mod integration {
+    use crate::Uniq;
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_plugin::Plugin;
+    use nu_protocol::Value;
+    use nu_test_support::value::{int, string};
+
+    fn returned(uniq: &mut Uniq, values: &[Value]) -> Vec<Value> {
+        values
+            .iter()
+            .flat_map(|value| uniq.filter(value.clone()).expect("filtered"))
+            .filter_map(|returned| returned.ok().and_then(|value| value.raw_value()))
+            .collect()
+    }
+
+    #[test]
+    fn picks_up_the_global_flag() {
+        plugin(&mut Uniq::new())
+            .args(CallStub::new().with_long_flag("global").create())
+            .setup(|plugin, _| {
+                assert!(plugin.global);
+            });
+    }
+
+    #[test]
+    fn streams_values_dropping_adjacent_duplicates() {
+        let mut uniq = Uniq::new();
+
+        plugin(&mut uniq)
+            .args(CallStub::new().create())
+            .setup(|_, _| {});
+
+        assert_eq!(
+            returned(&mut uniq, &[string("a"), string("a"), int(1), string("a")]),
+            vec![string("a"), int(1), string("a")]
+        );
+    }
+
+    #[test]
+    fn streams_values_dropping_every_duplicate_when_global() {
+        let mut uniq = Uniq::new();
+
+        plugin(&mut uniq)
+            .args(CallStub::new().with_long_flag("global").create())
+            .setup(|_, _| {});
+
+        assert_eq!(
+            returned(&mut uniq, &[string("a"), string("a"), int(1), string("a")]),
+            vec![string("a"), int(1)]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use nu_protocol::Value;
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct Uniq {
+    pub global: bool,
+    pub last: Option<Value>,
+    pub seen: HashSet<Value>,
+}
+
+impl Uniq {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the value when it is not a duplicate. Without `--global` only the previous
+    /// value is checked, otherwise every value seen so far is. Tags are not compared.
+    pub fn unique(&mut self, value: Value) -> Option<Value> {
+        if self.global {
+            if !self.seen.insert(value.clone()) {
+                return None;
+            }
+
+            return Some(value);
+        }
+
+        if self.last.as_ref() == Some(&value) {
+            return None;
+        }
+
+        self.last = Some(value.clone());
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Uniq;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Span;
+    use nu_test_support::value::{int, string};
+
+    fn stream() -> Vec<Value> {
+        vec![
+            int(1),
+            int(1),
+            string("nu"),
+            int(1),
+            string("nu"),
+            string("nu"),
+            int(2),
+        ]
+    }
+
+    fn run(uniq: &mut Uniq, values: Vec<Value>) -> Vec<Value> {
+        values
+            .into_iter()
+            .filter_map(|value| uniq.unique(value))
+            .collect()
+    }
+
+    #[test]
+    fn drops_adjacent_duplicates() {
+        let mut uniq = Uniq::new();
+
+        assert_eq!(
+            run(&mut uniq, stream()),
+            vec![int(1), string("nu"), int(1), string("nu"), int(2)]
+        );
+    }
+
+    #[test]
+    fn drops_every_duplicate_when_global() {
+        let mut uniq = Uniq::new();
+        uniq.global = true;
+
+        assert_eq!(run(&mut uniq, stream()), vec![int(1), string("nu"), int(2)]);
+    }
+
+    #[test]
+    fn ignores_tags_when_comparing() {
+        let mut uniq = Uniq::new();
+
+        let tagged = UntaggedValue::int(1).into_value(Span::new(3, 4));
+
+        assert_eq!(run(&mut uniq, vec![int(1), tagged]), vec![int(1)]);
+    }
+}
@@ -0,0 +1,4 @@
+mod nu;
+mod where_;
+
+pub use where_::{Comparison, Where};
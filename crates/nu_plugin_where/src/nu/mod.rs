@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{Comparison, Where};
+use nu_errors::ShellError;
+use nu_plugin::Plugin;
+use nu_protocol::{CallInfo, ReturnSuccess, ReturnValue, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
+
+impl Plugin for Where {
+    fn config(&mut self) -> Result<Signature, ShellError> {
+        Ok(Signature::build("where-field")
+            .desc("Keep the rows whose field compares to the value.")
+            .required("field", SyntaxShape::ColumnPath, "the column to compare")
+            .required(
+                "operator",
+                SyntaxShape::String,
+                "one of ==, !=, <, <=, >, >=",
+            )
+            .required("value", SyntaxShape::Any, "the value to compare against")
+            .switch(
+                "keep-missing",
+                "keep the rows that do not have the field",
+                Some('k'),
+            )
+            .example("ls | where-field size > 1024")
+            .filter())
+    }
+
+    fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
+        let args = &call_info.args;
+
+        match (args.nth(0), args.nth(1), args.nth(2)) {
+            (Some(field), Some(operator), Some(value)) => {
+                self.field = Some(field.as_column_path()?);
+                self.comparison = Comparison::parse(&operator.as_string()?, operator.tag.span)?;
+                self.value = Some(value.clone());
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "where-field needs a field, an operator and a value",
+                    "requires a field, an operator and a value",
+                    &call_info.name_tag,
+                ))
+            }
+        }
+
+        self.keep_missing = call_info.switch_present("keep-missing");
+
+        Ok(vec![])
+    }
+
+    fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
+        if self.matches(&input) {
+            Ok(vec![ReturnSuccess::value(input)])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
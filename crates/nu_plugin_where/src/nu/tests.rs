@@ -0,0 +1,176 @@
+mod integration {
+    use crate::{Comparison, Where};
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_plugin::Plugin;
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::Tag;
+    use nu_test_support::value::{column_path, int, string};
+
+    fn sized(size: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("size", int(size));
+        })
+    }
+
+    #[test]
+    fn requires_a_field_an_operator_and_a_value() {
+        plugin(&mut Where::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn picks_up_the_comparison() {
+        plugin(&mut Where::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .with_positional(string(">="))
+                    .with_positional(int(10))
+                    .with_long_flag("keep-missing")
+                    .create(),
+            )
+            .setup(|plugin, returned_values| {
+                assert!(returned_values.is_ok());
+                assert_eq!(plugin.comparison, Comparison::GreaterThanOrEqual);
+                assert_eq!(plugin.value, Some(int(10)));
+                assert!(plugin.keep_missing);
+            });
+    }
+
+    #[test]
+    fn errors_on_an_unknown_operator() {
+        plugin(&mut Where::new())
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .with_positional(string("=~"))
+                    .with_positional(int(10))
+                    .create(),
+            )
+            .setup(|_, returned_values| {
+                assert!(returned_values.is_err());
+            });
+    }
+
+    #[test]
+    fn passes_through_only_matching_rows() {
+        let mut filter = Where::new();
+
+        plugin(&mut filter)
+            .args(
+                CallStub::new()
+                    .with_positional(column_path("size"))
+                    .with_positional(string(">"))
+                    .with_positional(int(10))
+                    .create(),
+            )
+            .setup(|_, _| {});
+
+        assert!(filter.filter(sized(5)).expect("filtered").is_empty());
+        assert_eq!(filter.filter(sized(15)).expect("filtered").len(), 1);
+    }
+}
+
+mod parsed {
+    use crate::Where;
+    use futures::executor::block_on;
+    use nu_engine::plugin::PluginCommandBuilder;
+    use nu_engine::{basic_evaluation_context, evaluate_baseline_expr};
+    use nu_errors::ShellError;
+    use nu_plugin::Plugin;
+    use nu_protocol::hir::ClassifiedCommand;
+    use nu_protocol::{
+        CallInfo, EvaluatedArgs, ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value,
+    };
+    use nu_source::Tag;
+    use nu_test_support::value::int;
+
+    fn sized(size: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("size", int(size));
+        })
+    }
+
+    /// A row like the ones `ls` outputs, with the size as a filesize
+    fn file(bytes: u64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_untagged("size", UntaggedValue::filesize(bytes));
+        })
+    }
+
+    /// Parses and evaluates the arguments of a `where-field` call the way the shell does
+    fn call_info(line: &str) -> Result<CallInfo, ShellError> {
+        let ctx = basic_evaluation_context()?;
+        let signature = Where::new().config()?;
+        ctx.add_commands(vec![PluginCommandBuilder::new(
+            "where-field",
+            "nu_plugin_where",
+            signature,
+        )
+        .build()]);
+
+        let (block, error) = nu_parser::parse(line, 0, &ctx.scope);
+        if let Some(error) = error {
+            return Err(error.into());
+        }
+
+        let call = match &block.block[0].pipelines[0].list[0] {
+            ClassifiedCommand::Internal(command) => command.args.clone(),
+            other => panic!("expected a call to where-field, got {:?}", other),
+        };
+
+        let positional = call
+            .positional
+            .unwrap_or_default()
+            .iter()
+            .map(|expr| block_on(evaluate_baseline_expr(expr, &ctx)))
+            .collect::<Result<Vec<Value>, ShellError>>()?;
+
+        Ok(CallInfo {
+            args: EvaluatedArgs::new(Some(positional), None),
+            name_tag: Tag::unknown(),
+            env: Default::default(),
+        })
+    }
+
+    #[test]
+    fn runs_the_example_through_the_parser_and_evaluator() -> Result<(), ShellError> {
+        let mut plugin = Where::new();
+        plugin.begin_filter(call_info("where-field size > 1024")?)?;
+
+        let kept = plugin.filter(sized(2048))?;
+        let dropped = plugin.filter(sized(512))?;
+
+        match kept.as_slice() {
+            [Ok(ReturnSuccess::Value(value))] => assert_eq!(value, &sized(2048)),
+            other => panic!("expected the row to be kept, got {:?}", other),
+        }
+        assert!(dropped.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compares_filesizes_of_listed_files_against_numbers() -> Result<(), ShellError> {
+        let mut plugin = Where::new();
+        plugin.begin_filter(call_info("where-field size > 1024")?)?;
+
+        let kept = plugin.filter(file(2048))?;
+        let dropped = plugin.filter(file(512))?;
+
+        match kept.as_slice() {
+            [Ok(ReturnSuccess::Value(value))] => assert_eq!(value, &file(2048)),
+            other => panic!("expected the row to be kept, got {:?}", other),
+        }
+        assert!(dropped.is_empty());
+
+        Ok(())
+    }
+}
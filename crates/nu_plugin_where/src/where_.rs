@@ -0,0 +1,189 @@
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Value};
+use nu_source::{Span, Tagged};
+use nu_value_ext::get_data_by_column_path;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl Comparison {
+    pub fn parse(operator: &str, span: Span) -> Result<Comparison, ShellError> {
+        Ok(match operator {
+            "==" => Comparison::Equal,
+            "!=" => Comparison::NotEqual,
+            "<" => Comparison::LessThan,
+            "<=" => Comparison::LessThanOrEqual,
+            ">" => Comparison::GreaterThan,
+            ">=" => Comparison::GreaterThanOrEqual,
+            other => {
+                return Err(ShellError::labeled_error(
+                    format!("'{}' is not a comparison operator", other),
+                    "expected one of ==, !=, <, <=, >, >=",
+                    span,
+                ))
+            }
+        })
+    }
+
+    /// Values that can not be compared are only ever different from each other.
+    pub fn holds(self, ordering: Option<Ordering>) -> bool {
+        match (self, ordering) {
+            (Comparison::NotEqual, ordering) => ordering != Some(Ordering::Equal),
+            (_, None) => false,
+            (Comparison::Equal, Some(ordering)) => ordering == Ordering::Equal,
+            (Comparison::LessThan, Some(ordering)) => ordering == Ordering::Less,
+            (Comparison::LessThanOrEqual, Some(ordering)) => ordering != Ordering::Greater,
+            (Comparison::GreaterThan, Some(ordering)) => ordering == Ordering::Greater,
+            (Comparison::GreaterThanOrEqual, Some(ordering)) => ordering != Ordering::Less,
+        }
+    }
+}
+
+pub struct Where {
+    pub field: Option<Tagged<ColumnPath>>,
+    pub comparison: Comparison,
+    pub value: Option<Value>,
+    pub keep_missing: bool,
+}
+
+impl Default for Where {
+    fn default() -> Self {
+        Where {
+            field: None,
+            comparison: Comparison::Equal,
+            value: None,
+            keep_missing: false,
+        }
+    }
+}
+
+impl Where {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn matches(&self, row: &Value) -> bool {
+        let (field, expected) = match (&self.field, &self.value) {
+            (Some(field), Some(expected)) => (field, expected),
+            _ => return false,
+        };
+
+        match get_data_by_column_path(row, field, |_, _, error| error) {
+            Ok(actual) => self.comparison.holds(actual.compare(expected)),
+            Err(_) => self.keep_missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparison, Where};
+    use nu_protocol::{TaggedDictBuilder, Value};
+    use nu_source::{Span, Tag};
+    use nu_test_support::value::{column_path, int, string};
+    use nu_value_ext::ValueExt;
+
+    fn package(name: &str, downloads: i64) -> Value {
+        TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string(name));
+            row.insert_value("downloads", int(downloads));
+        })
+    }
+
+    fn packages() -> Vec<Value> {
+        vec![
+            package("nu", 10),
+            package("serde", 20),
+            package("rayon", 30),
+        ]
+    }
+
+    fn where_downloads(operator: &str, downloads: i64) -> Where {
+        let mut filter = Where::new();
+        filter.field = Some(
+            column_path("downloads")
+                .as_column_path()
+                .expect("a column path"),
+        );
+        filter.comparison = Comparison::parse(operator, Span::unknown()).expect("an operator");
+        filter.value = Some(int(downloads));
+        filter
+    }
+
+    fn names(filter: &Where) -> Vec<Value> {
+        packages()
+            .into_iter()
+            .filter(|row| filter.matches(row))
+            .map(|row| nu_value_ext::get_data(&row, "name").borrow().clone())
+            .collect()
+    }
+
+    #[test]
+    fn equal() {
+        assert_eq!(names(&where_downloads("==", 20)), vec![string("serde")]);
+    }
+
+    #[test]
+    fn not_equal() {
+        assert_eq!(
+            names(&where_downloads("!=", 20)),
+            vec![string("nu"), string("rayon")]
+        );
+    }
+
+    #[test]
+    fn less_than() {
+        assert_eq!(names(&where_downloads("<", 20)), vec![string("nu")]);
+    }
+
+    #[test]
+    fn less_than_or_equal() {
+        assert_eq!(
+            names(&where_downloads("<=", 20)),
+            vec![string("nu"), string("serde")]
+        );
+    }
+
+    #[test]
+    fn greater_than() {
+        assert_eq!(names(&where_downloads(">", 20)), vec![string("rayon")]);
+    }
+
+    #[test]
+    fn greater_than_or_equal() {
+        assert_eq!(
+            names(&where_downloads(">=", 20)),
+            vec![string("serde"), string("rayon")]
+        );
+    }
+
+    #[test]
+    fn rows_missing_the_field_are_dropped_unless_kept() {
+        let unnamed = TaggedDictBuilder::build(Tag::unknown(), |row| {
+            row.insert_value("name", string("unknown"));
+        });
+
+        let mut filter = where_downloads(">", 0);
+        assert!(!filter.matches(&unnamed));
+
+        filter.keep_missing = true;
+        assert!(filter.matches(&unnamed));
+    }
+
+    #[test]
+    fn rejects_unknown_operators() {
+        let error = Comparison::parse("=~", Span::new(3, 5)).expect_err("not an operator");
+        let diagnostic = error.into_diagnostic().expect("a diagnostic");
+
+        assert_eq!(diagnostic.message, "'=~' is not a comparison operator");
+        assert_eq!(diagnostic.labels[0].range, 3..5);
+    }
+}